@@ -111,7 +111,7 @@ fn main() {
     let tie_breaking_policy =
         ClosestToIdealPolicy::new(possible_settings[2].clone(), &supported_constraints);
 
-    let actual = tie_breaking_policy.select_candidate(candidates);
+    let actual = tie_breaking_policy.break_tie(&candidates);
 
     let expected = &possible_settings[2];
 
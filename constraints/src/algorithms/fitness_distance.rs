@@ -22,6 +22,9 @@ mod empty_constraint;
 mod setting;
 mod settings;
 mod value_constraint;
+mod value_exclusion_constraint;
+#[cfg(feature = "regex")]
+mod value_pattern_constraint;
 mod value_range_constraint;
 mod value_sequence_constraint;
 
@@ -66,7 +69,7 @@ fn is_nearly_equal_to(actual: f64, exact: f64) -> bool {
     nearly_cmp(actual, exact) == Ordering::Equal
 }
 
-fn relative_fitness_distance(actual: f64, ideal: f64) -> f64 {
+pub(crate) fn relative_fitness_distance(actual: f64, ideal: f64) -> f64 {
     // As specified in step 7 of the `fitness distance` algorithm:
     // <https://www.w3.org/TR/mediacapture-streams/#dfn-fitness-distance>
     //
@@ -76,7 +79,11 @@ fn relative_fitness_distance(actual: f64, ideal: f64) -> f64 {
     // > ```
     // > (actual == ideal) ? 0 : |actual - ideal| / max(|actual|, |ideal|)
     // > ```
-    if (actual - ideal).abs() < f64::EPSILON {
+    if !actual.is_finite() || !ideal.is_finite() {
+        // Non-finite values would result in a `NaN` fitness distance,
+        // which would make comparing candidates non-deterministic:
+        1.0
+    } else if (actual - ideal).abs() < f64::EPSILON {
         0.0
     } else {
         let numerator = (actual - ideal).abs();
@@ -128,5 +135,16 @@ mod tests {
             assert_eq!(super::relative_fitness_distance(0.0, 2.0), 1.0);
             assert_eq!(super::relative_fitness_distance(2.0, 0.0), 1.0);
         }
+
+        #[test]
+        fn non_finite_distance() {
+            assert_eq!(super::relative_fitness_distance(f64::NAN, 1.0), 1.0);
+            assert_eq!(super::relative_fitness_distance(f64::INFINITY, 1.0), 1.0);
+            assert_eq!(
+                super::relative_fitness_distance(f64::NEG_INFINITY, 1.0),
+                1.0
+            );
+            assert_eq!(super::relative_fitness_distance(1.0, f64::NAN), 1.0);
+        }
     }
 }
@@ -35,6 +35,8 @@ impl<'a> FitnessDistance<Option<&'a MediaTrackSetting>> for ResolvedMediaTrackCo
 
         let setting = match setting {
             Some(setting) => setting,
+            // A missing value never matches an excluded value:
+            None if matches!(self, Constraint::Exclusion(_)) => return Ok(0.0),
             None => {
                 return if self.is_required() {
                     Err(Self::Error {
@@ -96,6 +98,26 @@ impl<'a> FitnessDistance<Option<&'a MediaTrackSetting>> for ResolvedMediaTrackCo
                 constraint.fitness_distance(Some(setting))
             }
 
+            // Pattern constraint:
+            #[cfg(feature = "regex")]
+            (Constraint::Pattern(_constraint), Setting::Bool(_setting)) => Ok(0.0),
+            #[cfg(feature = "regex")]
+            (Constraint::Pattern(_constraint), Setting::Integer(_setting)) => Ok(0.0),
+            #[cfg(feature = "regex")]
+            (Constraint::Pattern(_constraint), Setting::Float(_setting)) => Ok(0.0),
+            #[cfg(feature = "regex")]
+            (Constraint::Pattern(constraint), Setting::String(setting)) => {
+                constraint.fitness_distance(Some(setting))
+            }
+
+            // Exclusion constraint:
+            (Constraint::Exclusion(_constraint), Setting::Bool(_setting)) => Ok(0.0),
+            (Constraint::Exclusion(_constraint), Setting::Integer(_setting)) => Ok(0.0),
+            (Constraint::Exclusion(_constraint), Setting::Float(_setting)) => Ok(0.0),
+            (Constraint::Exclusion(constraint), Setting::String(setting)) => {
+                constraint.fitness_distance(Some(setting))
+            }
+
             // String sequence constraint:
             (Constraint::StringSequence(_constraint), Setting::Bool(_setting)) => Ok(0.0),
             (Constraint::StringSequence(_constraint), Setting::Integer(_setting)) => Ok(0.0),
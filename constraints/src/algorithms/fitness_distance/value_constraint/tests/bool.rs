@@ -281,3 +281,97 @@ mod required {
         }
     }
 }
+
+// Boolean toggles (e.g. `echoCancellation`) can be required on/off,
+// preferred on/off, or left unconstrained:
+mod tri_state {
+    use super::*;
+
+    #[test]
+    fn exact_true() {
+        test_value_constraint!(checks: [
+            {
+                setting: bool => Some(true),
+                constraint: bool => ResolvedValueConstraint::default().exact(true),
+                expected: Ok(0.0),
+            },
+            {
+                setting: bool => Some(false),
+                constraint: bool => ResolvedValueConstraint::default().exact(true),
+                expected: Err(SettingFitnessDistanceError {
+                    kind: SettingFitnessDistanceErrorKind::Mismatch,
+                    constraint: "(x == true)".to_owned(),
+                    setting: Some("false".to_owned()),
+                }),
+            },
+        ]);
+    }
+
+    #[test]
+    fn exact_false() {
+        test_value_constraint!(checks: [
+            {
+                setting: bool => Some(true),
+                constraint: bool => ResolvedValueConstraint::default().exact(false),
+                expected: Err(SettingFitnessDistanceError {
+                    kind: SettingFitnessDistanceErrorKind::Mismatch,
+                    constraint: "(x == false)".to_owned(),
+                    setting: Some("true".to_owned()),
+                }),
+            },
+            {
+                setting: bool => Some(false),
+                constraint: bool => ResolvedValueConstraint::default().exact(false),
+                expected: Ok(0.0),
+            },
+        ]);
+    }
+
+    #[test]
+    fn ideal_true() {
+        test_value_constraint!(checks: [
+            {
+                setting: bool => Some(true),
+                constraint: bool => ResolvedValueConstraint::default().ideal(true),
+                expected: Ok(0.0),
+            },
+            {
+                setting: bool => Some(false),
+                constraint: bool => ResolvedValueConstraint::default().ideal(true),
+                expected: Ok(1.0),
+            },
+        ]);
+    }
+
+    #[test]
+    fn ideal_false() {
+        test_value_constraint!(checks: [
+            {
+                setting: bool => Some(true),
+                constraint: bool => ResolvedValueConstraint::default().ideal(false),
+                expected: Ok(1.0),
+            },
+            {
+                setting: bool => Some(false),
+                constraint: bool => ResolvedValueConstraint::default().ideal(false),
+                expected: Ok(0.0),
+            },
+        ]);
+    }
+
+    #[test]
+    fn unconstrained() {
+        test_value_constraint!(checks: [
+            {
+                setting: bool => Some(true),
+                constraint: bool => ResolvedValueConstraint::<bool>::default(),
+                expected: Ok(0.0),
+            },
+            {
+                setting: bool => Some(false),
+                constraint: bool => ResolvedValueConstraint::<bool>::default(),
+                expected: Ok(0.0),
+            },
+        ]);
+    }
+}
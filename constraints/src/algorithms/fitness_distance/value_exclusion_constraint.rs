@@ -0,0 +1,54 @@
+use super::setting::SettingFitnessDistanceError;
+use super::{FitnessDistance, SettingFitnessDistanceErrorKind};
+use crate::constraint::ValueExclusionConstraint;
+
+impl<'a> FitnessDistance<Option<&'a String>> for ValueExclusionConstraint {
+    type Error = SettingFitnessDistanceError;
+
+    fn fitness_distance(&self, setting: Option<&'a String>) -> Result<f64, Self::Error> {
+        // Analogous to step 2 of the `fitness distance` algorithm:
+        // <https://www.w3.org/TR/mediacapture-streams/#dfn-fitness-distance>
+        //
+        // > If the constraint is required […], and the settings
+        // > dictionary's constraintName member's value does not satisfy the
+        // > constraint […], the fitness distance is positive infinity.
+        //
+        // A missing value never matches an excluded value, hence satisfies the constraint.
+        match setting {
+            Some(actual) if self.excludes(actual) => Err(SettingFitnessDistanceError {
+                kind: SettingFitnessDistanceErrorKind::Mismatch,
+                constraint: format!("{}", self.to_required_only()),
+                setting: Some(format!("{:?}", actual)),
+            }),
+            _ => Ok(0.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn excluded() {
+        let constraint = ValueExclusionConstraint::default().not(vec!["a".to_owned()]);
+
+        let actual = constraint.fitness_distance(Some(&"a".to_owned()));
+
+        let expected = Err(SettingFitnessDistanceError {
+            kind: SettingFitnessDistanceErrorKind::Mismatch,
+            constraint: "(x != \"a\")".to_owned(),
+            setting: Some("\"a\"".to_owned()),
+        });
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn not_excluded() {
+        let constraint = ValueExclusionConstraint::default().not(vec!["a".to_owned()]);
+
+        assert_eq!(constraint.fitness_distance(Some(&"b".to_owned())), Ok(0.0));
+        assert_eq!(constraint.fitness_distance(None), Ok(0.0));
+    }
+}
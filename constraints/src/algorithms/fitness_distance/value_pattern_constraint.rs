@@ -0,0 +1,107 @@
+use super::setting::SettingFitnessDistanceError;
+use super::{FitnessDistance, SettingFitnessDistanceErrorKind};
+use crate::constraint::ValuePatternConstraint;
+
+impl<'a> FitnessDistance<Option<&'a String>> for ValuePatternConstraint {
+    type Error = SettingFitnessDistanceError;
+
+    fn fitness_distance(&self, setting: Option<&'a String>) -> Result<f64, Self::Error> {
+        if let Some(exact) = self.exact_pattern.as_ref() {
+            // As specified in step 2 of the `fitness distance` algorithm:
+            // <https://www.w3.org/TR/mediacapture-streams/#dfn-fitness-distance>
+            //
+            // > If the constraint is required (constraintValue either contains
+            // > one or more members named […] 'exact' […]), and the settings
+            // > dictionary's constraintName member's value does not satisfy the
+            // > constraint or doesn't exist, the fitness distance is positive infinity.
+            match setting {
+                Some(actual) if exact.is_match(actual) => {}
+                Some(setting) => {
+                    return Err(SettingFitnessDistanceError {
+                        kind: SettingFitnessDistanceErrorKind::Mismatch,
+                        constraint: format!("{}", self.to_required_only()),
+                        setting: Some(format!("{:?}", setting)),
+                    })
+                }
+                None => {
+                    return Err(SettingFitnessDistanceError {
+                        kind: SettingFitnessDistanceErrorKind::Missing,
+                        constraint: format!("{}", self.to_required_only()),
+                        setting: None,
+                    })
+                }
+            };
+        }
+
+        if let Some(ideal) = self.ideal_pattern.as_ref() {
+            // Analogous to step 8 of the `fitness distance` algorithm:
+            // <https://www.w3.org/TR/mediacapture-streams/#dfn-fitness-distance>
+            //
+            // > For all string, enum and boolean constraints […],
+            // > the fitness distance is the result of the formula:
+            // >
+            // > ```
+            // > (actual == ideal) ? 0 : 1
+            // > ```
+            match setting {
+                Some(actual) if ideal.is_match(actual) => Ok(0.0),
+                _ => Ok(1.0),
+            }
+        } else {
+            // As specified in step 6 of the `fitness distance` algorithm:
+            // <https://www.w3.org/TR/mediacapture-streams/#dfn-fitness-distance>
+            //
+            // > If no ideal value is specified […], the fitness distance is 0.
+            Ok(0.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::Regex;
+
+    use super::*;
+
+    type Subject = ValuePatternConstraint;
+
+    #[test]
+    fn exact() {
+        let subject = Subject::default().exact(Regex::new("^usb-").unwrap());
+
+        let matching = "usb-0001".to_owned();
+        assert_eq!(subject.fitness_distance(Some(&matching)), Ok(0.0));
+
+        let mismatching = "pci-0001".to_owned();
+        assert_eq!(
+            subject.fitness_distance(Some(&mismatching)),
+            Err(SettingFitnessDistanceError {
+                kind: SettingFitnessDistanceErrorKind::Mismatch,
+                constraint: "(x =~ /^usb-/)".to_owned(),
+                setting: Some("\"pci-0001\"".to_owned()),
+            })
+        );
+
+        assert_eq!(
+            subject.fitness_distance(None),
+            Err(SettingFitnessDistanceError {
+                kind: SettingFitnessDistanceErrorKind::Missing,
+                constraint: "(x =~ /^usb-/)".to_owned(),
+                setting: None,
+            })
+        );
+    }
+
+    #[test]
+    fn ideal() {
+        let subject = Subject::default().ideal(Regex::new("^usb-").unwrap());
+
+        let matching = "usb-0001".to_owned();
+        assert_eq!(subject.fitness_distance(Some(&matching)), Ok(0.0));
+
+        let mismatching = "pci-0001".to_owned();
+        assert_eq!(subject.fitness_distance(Some(&mismatching)), Ok(1.0));
+
+        assert_eq!(subject.fitness_distance(None), Ok(1.0));
+    }
+}
@@ -8,6 +8,20 @@ macro_rules! impl_value_range_constraint {
             type Error = SettingFitnessDistanceError;
 
             fn fitness_distance(&self, setting: Option<&'a $s>) -> Result<f64, Self::Error> {
+                // Non-finite settings (e.g. a `NaN` reported by a buggy driver) would otherwise
+                // satisfy some bounds (as `NaN` compares neither less nor greater than them),
+                // so they fail required constraints regardless of their bounds:
+                match setting {
+                    Some(&actual) if self.is_required() && !(actual as f64).is_finite() => {
+                        return Err(SettingFitnessDistanceError {
+                            kind: SettingFitnessDistanceErrorKind::Mismatch,
+                            constraint: format!("{}", self.to_required_only()),
+                            setting: Some(format!("{:?}", actual)),
+                        });
+                    }
+                    _ => {}
+                }
+
                 if let Some(exact) = self.exact {
                     // As specified in step 2 of the `fitness distance` algorithm:
                     // <https://www.w3.org/TR/mediacapture-streams/#dfn-fitness-distance>
@@ -98,6 +112,19 @@ macro_rules! impl_value_range_constraint {
                     };
                 }
 
+                if self.exact.is_some() {
+                    // An `exact` value leaves only a single acceptable setting,
+                    // which is thus by definition the best possible fit,
+                    // regardless of how far off any `ideal` value may be.
+                    //
+                    // This deliberately diverges from step 7 of the `fitness distance` algorithm:
+                    // <https://www.w3.org/TR/mediacapture-streams/#dfn-fitness-distance>
+                    //
+                    // The spec would still add the distance to the `ideal` value here,
+                    // penalizing the only acceptable setting for an unreachable `ideal`.
+                    return Ok(0.0);
+                }
+
                 if let Some(ideal) = self.ideal {
                     match setting {
                         Some(&actual) => {
@@ -210,6 +210,92 @@ mod required {
         );
     }
 
+    mod exact_and_ideal {
+        use super::*;
+
+        // The `exact` value dominates any `ideal` value:
+
+        generate_value_range_constraint_tests!(
+            tests: [
+                {
+                    name: i64_setting,
+                    settings: i64 => &[Some(30)],
+                },
+                {
+                    name: f64_setting,
+                    settings: f64 => &[Some(30.0)],
+                },
+            ],
+            constraints: f64 => &[
+                ResolvedValueRangeConstraint::default().exact(30.0).ideal(60.0),
+                ResolvedValueRangeConstraint::default().exact(30.0).ideal(30.0),
+                ResolvedValueRangeConstraint::default().min(20.0).exact(30.0).ideal(60.0),
+            ],
+            expected: Ok(0.0)
+        );
+
+        mod mismatch {
+            use super::*;
+
+            generate_value_range_constraint_tests!(
+                tests: [
+                    {
+                        name: f64_setting,
+                        settings: f64 => &[Some(60.0)],
+                    },
+                ],
+                constraints: f64 => &[
+                    ResolvedValueRangeConstraint::default().exact(30.0).ideal(60.0),
+                ],
+                expected: Err(SettingFitnessDistanceError {
+                    kind: SettingFitnessDistanceErrorKind::Mismatch,
+                    constraint: "(x == 30.0)".to_owned(),
+                    setting: Some("60.0".to_owned()),
+                })
+            );
+        }
+    }
+
+    // Without an ideal value being in range of the bounds is all that matters,
+    // with values closer to a bound NOT getting rewarded with a smaller distance:
+    mod in_range {
+        use super::*;
+
+        generate_value_range_constraint_tests!(
+            tests: [
+                {
+                    name: i64_setting,
+                    settings: i64 => &[Some(30), Some(60)],
+                },
+                {
+                    name: f64_setting,
+                    settings: f64 => &[Some(30.0), Some(60.0)],
+                },
+            ],
+            constraints: f64 => &[
+                ResolvedValueRangeConstraint {
+                    min: Some(15.0),
+                    max: None,
+                    exact: None,
+                    ideal: None,
+                },
+                ResolvedValueRangeConstraint {
+                    min: None,
+                    max: Some(120.0),
+                    exact: None,
+                    ideal: None,
+                },
+                ResolvedValueRangeConstraint {
+                    min: Some(15.0),
+                    max: Some(120.0),
+                    exact: None,
+                    ideal: None,
+                },
+            ],
+            expected: Ok(0.0)
+        );
+    }
+
     mod inf_distance {
         use super::*;
 
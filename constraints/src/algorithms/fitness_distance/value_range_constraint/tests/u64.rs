@@ -210,6 +210,52 @@ mod required {
         );
     }
 
+    mod exact_and_ideal {
+        use super::*;
+
+        // The `exact` value dominates any `ideal` value:
+
+        generate_value_range_constraint_tests!(
+            tests: [
+                {
+                    name: i64_setting,
+                    settings: i64 => &[Some(30)],
+                },
+                {
+                    name: f64_setting,
+                    settings: f64 => &[Some(30.0)],
+                },
+            ],
+            constraints: u64 => &[
+                ResolvedValueRangeConstraint::default().exact(30).ideal(60),
+                ResolvedValueRangeConstraint::default().exact(30).ideal(30),
+                ResolvedValueRangeConstraint::default().min(20).exact(30).ideal(60),
+            ],
+            expected: Ok(0.0)
+        );
+
+        mod mismatch {
+            use super::*;
+
+            generate_value_range_constraint_tests!(
+                tests: [
+                    {
+                        name: i64_setting,
+                        settings: i64 => &[Some(60)],
+                    },
+                ],
+                constraints: u64 => &[
+                    ResolvedValueRangeConstraint::default().exact(30).ideal(60),
+                ],
+                expected: Err(SettingFitnessDistanceError {
+                    kind: SettingFitnessDistanceErrorKind::Mismatch,
+                    constraint: "(x == 30)".to_owned(),
+                    setting: Some("60".to_owned()),
+                })
+            );
+        }
+    }
+
     mod inf_distance {
         use super::*;
 
@@ -1,18 +1,34 @@
-use std::collections::HashSet;
+use std::cmp::Ordering;
+use std::collections::HashMap;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::algorithms::fitness_distance::SettingFitnessDistanceError;
+use crate::algorithms::FitnessDistance;
 use crate::errors::OverconstrainedError;
-use crate::{MediaTrackSettings, SanitizedMediaTrackConstraints};
+use crate::{
+    MediaTrackProperty, MediaTrackSetting, MediaTrackSettings, ResolvedMediaTrackConstraint,
+    SanitizedMediaTrackConstraints,
+};
 
 mod apply_advanced;
 mod apply_mandatory;
+mod best_effort;
+mod ideal_tolerance;
+mod incremental;
+mod joint;
 mod select_optimal;
 mod tie_breaking;
 
 use self::apply_advanced::*;
 use self::apply_mandatory::*;
+pub use self::best_effort::*;
+pub use self::ideal_tolerance::IdealTolerance;
+use self::ideal_tolerance::*;
+pub use self::incremental::*;
+pub use self::joint::*;
 use self::select_optimal::*;
 pub use self::tie_breaking::*;
 
@@ -27,28 +43,62 @@ pub enum DeviceInformationExposureMode {
 
 /// An error type indicating a failure of the `SelectSettings` algorithm.
 #[derive(Error, Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "camelCase"))]
 pub enum SelectSettingsError {
     /// An error caused by one or more over-constrained settings.
     #[error(transparent)]
     Overconstrained(#[from] OverconstrainedError),
+    /// An error caused by an empty set of possible settings to select from.
+    #[error("No possible settings provided to select from")]
+    NoCandidates,
 }
 
 /// This function implements steps 1-5 of the `SelectSettings` algorithm
 /// as defined by the W3C spec:
 /// <https://www.w3.org/TR/mediacapture-streams/#dfn-selectsettings>
 ///
-/// Step 6 (tie-breaking) is omitted by this implementation and expected to be performed
-/// manually on the returned candidates.
-/// For this several implementation of `TieBreakingPolicy` are provided by this crate.
-pub fn select_settings_candidates<'a, I>(
+/// Unlike `select_settings_candidates` this returns every candidate satisfying
+/// the constraints (not just the optimal ones), each paired with its fitness distance,
+/// sorted by ascending fitness distance.
+/// Candidates of equal fitness distance retain their relative order from `possible_settings`.
+pub fn select_settings_scored<'a, I>(
     possible_settings: I,
     constraints: &SanitizedMediaTrackConstraints,
     exposure_mode: DeviceInformationExposureMode,
-) -> Result<Vec<&'a MediaTrackSettings>, SelectSettingsError>
+) -> Result<Vec<(&'a MediaTrackSettings, f64)>, SelectSettingsError>
+where
+    I: IntoIterator<Item = &'a MediaTrackSettings>,
+{
+    select_settings_scored_with_tolerance(
+        possible_settings,
+        constraints,
+        exposure_mode,
+        &IdealTolerance::default(),
+    )
+}
+
+/// This function implements steps 1-5 of the `SelectSettings` algorithm
+/// as defined by the W3C spec:
+/// <https://www.w3.org/TR/mediacapture-streams/#dfn-selectsettings>
+///
+/// Like `select_settings_scored`, but numeric settings within the given `tolerance`
+/// of a mandatory constraint's ideal value contribute a fitness distance of zero,
+/// which may change the optimal candidates in borderline cases.
+pub fn select_settings_scored_with_tolerance<'a, I>(
+    possible_settings: I,
+    constraints: &SanitizedMediaTrackConstraints,
+    exposure_mode: DeviceInformationExposureMode,
+    tolerance: &IdealTolerance,
+) -> Result<Vec<(&'a MediaTrackSettings, f64)>, SelectSettingsError>
 where
     I: IntoIterator<Item = &'a MediaTrackSettings>,
 {
-    let possible_settings = possible_settings.into_iter();
+    let mut possible_settings = possible_settings.into_iter().peekable();
+
+    if possible_settings.peek().is_none() {
+        return Err(SelectSettingsError::NoCandidates);
+    }
 
     // As specified in step 1 of the `SelectSettings` algorithm:
     // <https://www.w3.org/TR/mediacapture-streams/#dfn-selectsettings>
@@ -75,9 +125,16 @@ where
     // This function call corresponds to steps 3 & 4 of the `SelectSettings` algorithm:
     // <https://www.w3.org/TR/mediacapture-streams/#dfn-selectsettings>
 
-    let candidates_and_fitness_distances =
+    let mut candidates_and_fitness_distances =
         apply_mandatory_constraints(possible_settings, &constraints.mandatory, exposure_mode)?;
 
+    if !tolerance.is_empty() {
+        for (candidate, fitness_distance) in candidates_and_fitness_distances.iter_mut() {
+            *fitness_distance =
+                tolerant_fitness_distance(candidate, &constraints.mandatory, tolerance);
+        }
+    }
+
     // As specified in step 5 of the `SelectSettings` algorithm:
     // <https://www.w3.org/TR/mediacapture-streams/#dfn-selectsettings>
     //
@@ -93,9 +150,32 @@ where
     // >
     // >    If the fitness distance is infinite for all settings dictionaries in candidates,
     // >    ignore this ConstraintSet.
-    let candidates =
+    let mut candidates =
         apply_advanced_constraints(candidates_and_fitness_distances, &constraints.advanced);
 
+    // Fitness distances are always finite, hence never `NaN`:
+    candidates.sort_by(|(_, lhs), (_, rhs)| lhs.partial_cmp(rhs).unwrap_or(Ordering::Equal));
+
+    Ok(candidates)
+}
+
+/// This function implements steps 1-5 of the `SelectSettings` algorithm
+/// as defined by the W3C spec:
+/// <https://www.w3.org/TR/mediacapture-streams/#dfn-selectsettings>
+///
+/// Step 6 (tie-breaking) is omitted by this implementation and expected to be performed
+/// manually on the returned candidates.
+/// For this several implementation of `TieBreakingPolicy` are provided by this crate.
+pub fn select_settings_candidates<'a, I>(
+    possible_settings: I,
+    constraints: &SanitizedMediaTrackConstraints,
+    exposure_mode: DeviceInformationExposureMode,
+) -> Result<Vec<&'a MediaTrackSettings>, SelectSettingsError>
+where
+    I: IntoIterator<Item = &'a MediaTrackSettings>,
+{
+    let candidates = select_settings_scored(possible_settings, constraints, exposure_mode)?;
+
     // As specified in step 6 of the `SelectSettings` algorithm:
     // <https://www.w3.org/TR/mediacapture-streams/#dfn-selectsettings>
     //
@@ -111,10 +191,332 @@ where
     Ok(select_optimal_candidates(candidates))
 }
 
+/// This function implements the `SelectSettings` algorithm
+/// as defined by the W3C spec:
+/// <https://www.w3.org/TR/mediacapture-streams/#dfn-selectsettings>
+///
+/// Step 6 (tie-breaking) is delegated to the provided `tie_breaking_policy`,
+/// which gets passed all candidates sharing the smallest fitness distance.
+pub fn select_settings<'a, I, P>(
+    possible_settings: I,
+    constraints: &SanitizedMediaTrackConstraints,
+    exposure_mode: DeviceInformationExposureMode,
+    tie_breaking_policy: &P,
+) -> Result<&'a MediaTrackSettings, SelectSettingsError>
+where
+    I: IntoIterator<Item = &'a MediaTrackSettings>,
+    P: TieBreakingPolicy + ?Sized,
+{
+    let candidates = select_settings_candidates(possible_settings, constraints, exposure_mode)?;
+
+    Ok(tie_breaking_policy.break_tie(&candidates))
+}
+
+/// This function implements the `SelectSettings` algorithm
+/// as defined by the W3C spec:
+/// <https://www.w3.org/TR/mediacapture-streams/#dfn-selectsettings>
+///
+/// Unlike `select_settings` this returns up to `n` candidates satisfying the constraints,
+/// sorted by ascending fitness distance (i.e. the best candidate first).
+/// Candidates of equal fitness distance are ordered by repeatedly applying
+/// the provided `tie_breaking_policy` to those not yet picked.
+pub fn select_settings_top_n<'a, I, P>(
+    possible_settings: I,
+    constraints: &SanitizedMediaTrackConstraints,
+    exposure_mode: DeviceInformationExposureMode,
+    tie_breaking_policy: &P,
+    n: usize,
+) -> Result<Vec<&'a MediaTrackSettings>, SelectSettingsError>
+where
+    I: IntoIterator<Item = &'a MediaTrackSettings>,
+    P: TieBreakingPolicy + ?Sized,
+{
+    let candidates = select_settings_scored(possible_settings, constraints, exposure_mode)?;
+
+    let mut selected = Vec::with_capacity(n.min(candidates.len()));
+    let mut remaining = &candidates[..];
+
+    while selected.len() < n && !remaining.is_empty() {
+        let fitness_distance = remaining[0].1;
+        let tie_len = remaining
+            .iter()
+            .take_while(|(_, distance)| *distance == fitness_distance)
+            .count();
+        let (ties, rest) = remaining.split_at(tie_len);
+        remaining = rest;
+
+        let mut ties: Vec<_> = ties.iter().map(|(settings, _)| *settings).collect();
+        while selected.len() < n && !ties.is_empty() {
+            let preferred = tie_breaking_policy.break_tie(&ties);
+            let index = ties
+                .iter()
+                .position(|candidate| std::ptr::eq(*candidate, preferred))
+                .expect("The preferred settings should be one of the candidates.");
+            selected.push(ties.remove(index));
+        }
+    }
+
+    Ok(selected)
+}
+
+/// This function implements the `SelectSettings` algorithm
+/// as defined by the W3C spec:
+/// <https://www.w3.org/TR/mediacapture-streams/#dfn-selectsettings>
+///
+/// Unlike `select_settings` this consumes owned `possible_settings`
+/// (e.g. lazily enumerated devices) and returns the owned selected settings.
+/// Only those possible settings satisfying the mandatory constraints get buffered,
+/// as tie-breaking requires comparing all of them.
+pub fn select_settings_owned<I, P>(
+    possible_settings: I,
+    constraints: &SanitizedMediaTrackConstraints,
+    exposure_mode: DeviceInformationExposureMode,
+    tie_breaking_policy: &P,
+) -> Result<MediaTrackSettings, SelectSettingsError>
+where
+    I: IntoIterator<Item = MediaTrackSettings>,
+    P: TieBreakingPolicy + ?Sized,
+{
+    let mut possible_settings = possible_settings.into_iter().peekable();
+
+    if possible_settings.peek().is_none() {
+        return Err(SelectSettingsError::NoCandidates);
+    }
+
+    let candidates =
+        retain_satisfying_candidates(possible_settings, &constraints.mandatory, exposure_mode)?;
+
+    let candidates = apply_advanced_constraints(
+        candidates
+            .iter()
+            .map(|(candidate, fitness_distance)| (candidate, *fitness_distance))
+            .collect(),
+        &constraints.advanced,
+    );
+
+    let optimal_candidates = select_optimal_candidates(candidates);
+
+    Ok(tie_breaking_policy.break_tie(&optimal_candidates).clone())
+}
+
+/// An error type indicating a failure of `select_settings_explained`.
+#[derive(Error, Clone, Eq, PartialEq, Debug)]
+pub enum ExplainedSelectSettingsError {
+    /// An error for every one of the possible settings (identified by its index),
+    /// describing the mandatory constraint that it violates.
+    #[error("None of the possible settings satisfy the mandatory constraints")]
+    Overconstrained(Vec<(usize, OverconstrainedError)>),
+    /// An error caused by an empty set of possible settings to select from.
+    #[error("No possible settings provided to select from")]
+    NoCandidates,
+}
+
+/// This function implements steps 1-5 of the `SelectSettings` algorithm
+/// as defined by the W3C spec:
+/// <https://www.w3.org/TR/mediacapture-streams/#dfn-selectsettings>
+///
+/// Unlike `select_settings_candidates` this returns, upon the constraints being overconstrained,
+/// an error for every one of the possible settings (identified by its index),
+/// describing the mandatory constraint that it violates.
+pub fn select_settings_explained<'a, I>(
+    possible_settings: I,
+    constraints: &SanitizedMediaTrackConstraints,
+    exposure_mode: DeviceInformationExposureMode,
+) -> Result<Vec<&'a MediaTrackSettings>, ExplainedSelectSettingsError>
+where
+    I: IntoIterator<Item = &'a MediaTrackSettings>,
+{
+    let possible_settings: Vec<_> = possible_settings.into_iter().collect();
+
+    select_settings_candidates(
+        possible_settings.iter().copied(),
+        constraints,
+        exposure_mode,
+    )
+    .map_err(|error| match error {
+        SelectSettingsError::Overconstrained(_) => ExplainedSelectSettingsError::Overconstrained(
+            explain_mandatory_constraints(possible_settings, &constraints.mandatory, exposure_mode),
+        ),
+        SelectSettingsError::NoCandidates => ExplainedSelectSettingsError::NoCandidates,
+    })
+}
+
+/// Returns the first of the possible settings satisfying the mandatory constraints.
+///
+/// Unlike `select_settings` this does NOT implement the `SelectSettings` algorithm
+/// as defined by the W3C spec, as it neither applies advanced constraints,
+/// nor picks the candidate with the smallest fitness distance.
+/// Instead it stops consuming `possible_settings` as soon as a satisfying
+/// candidate has been found, which makes it suitable for lazily enumerated,
+/// or expensive to enumerate possible settings.
+pub fn select_settings_first_match<'a, I>(
+    possible_settings: I,
+    constraints: &SanitizedMediaTrackConstraints,
+    exposure_mode: DeviceInformationExposureMode,
+) -> Result<&'a MediaTrackSettings, SelectSettingsError>
+where
+    I: IntoIterator<Item = &'a MediaTrackSettings>,
+{
+    let mut possible_settings = possible_settings.into_iter().peekable();
+
+    if possible_settings.peek().is_none() {
+        return Err(SelectSettingsError::NoCandidates);
+    }
+
+    let candidate =
+        find_first_satisfying_candidate(possible_settings, &constraints.mandatory, exposure_mode)?;
+
+    Ok(candidate)
+}
+
+/// Computes the fitness distance of `settings` for the mandatory `constraints`,
+/// as calculated in step 3 of the `SelectSettings` algorithm:
+/// <https://www.w3.org/TR/mediacapture-streams/#dfn-selectsettings>
+///
+/// Returns an error describing the violated constraint
+/// if `settings` does not satisfy the mandatory constraints.
+pub fn fitness_distance(
+    settings: &MediaTrackSettings,
+    constraints: &SanitizedMediaTrackConstraints,
+) -> Result<f64, OverconstrainedError> {
+    match constraints.mandatory.fitness_distance(settings) {
+        Ok(fitness_distance) => Ok(fitness_distance),
+        Err(_) => {
+            let mut errors = explain_mandatory_constraints(
+                [settings],
+                &constraints.mandatory,
+                DeviceInformationExposureMode::Exposed,
+            );
+            let (_, error) = errors
+                .pop()
+                .expect("A fitness distance error should produce an explanation.");
+            Err(error)
+        }
+    }
+}
+
+/// A mandatory constraint whose ideal value is not met by some settings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnmetIdeal {
+    /// The constrained property.
+    pub property: MediaTrackProperty,
+    /// The constraint specifying the ideal value.
+    pub constraint: ResolvedMediaTrackConstraint,
+    /// The actual setting, or `None` if the settings lack the property.
+    pub setting: Option<MediaTrackSetting>,
+    /// The (non-zero) fitness distance of the setting to the constraint.
+    pub fitness_distance: f64,
+}
+
+/// Returns the mandatory constraints whose ideal values are not met by `settings`
+/// (i.e. those with a non-zero fitness distance), in the order of `constraints`.
+///
+/// Constraints violated by `settings` (i.e. those with an infinite fitness distance)
+/// are omitted, as are advanced constraints.
+pub fn unmet_ideals(
+    settings: &MediaTrackSettings,
+    constraints: &SanitizedMediaTrackConstraints,
+) -> Vec<UnmetIdeal> {
+    constraints
+        .mandatory
+        .iter()
+        .filter_map(|(property, constraint)| {
+            let setting = settings.get(property);
+            match constraint.fitness_distance(setting) {
+                Ok(fitness_distance) if fitness_distance > 0.0 => Some(UnmetIdeal {
+                    property: property.clone(),
+                    constraint: (**constraint).clone(),
+                    setting: setting.cloned(),
+                    fitness_distance,
+                }),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// The maximum fitness distance at which `ideal_coverage` considers an ideal value satisfied.
+///
+/// Numeric ideals are thus satisfied by settings within 5% of them,
+/// while all other ideals have to be matched exactly.
+pub const IDEAL_COVERAGE_TOLERANCE: f64 = 0.05;
+
+/// Returns the number of mandatory constraints whose ideal values are satisfied by `settings`,
+/// together with the total number of mandatory constraints specifying an ideal value,
+/// i.e. a coarse "matched N of M preferences" score.
+///
+/// An ideal value is considered satisfied if the fitness distance of `settings`
+/// for its constraint is within `IDEAL_COVERAGE_TOLERANCE`.
+/// Constraints violated by `settings` count as unsatisfied, advanced constraints are omitted.
+pub fn ideal_coverage(
+    settings: &MediaTrackSettings,
+    constraints: &SanitizedMediaTrackConstraints,
+) -> (usize, usize) {
+    constraints
+        .mandatory
+        .iter()
+        .filter(|(_, constraint)| match &***constraint {
+            ResolvedMediaTrackConstraint::Empty(_) => false,
+            #[cfg(feature = "regex")]
+            ResolvedMediaTrackConstraint::Pattern(constraint) => constraint.ideal_pattern.is_some(),
+            ResolvedMediaTrackConstraint::Exclusion(_) => false,
+            ResolvedMediaTrackConstraint::IntegerRange(constraint) => constraint.ideal.is_some(),
+            ResolvedMediaTrackConstraint::FloatRange(constraint) => constraint.ideal.is_some(),
+            ResolvedMediaTrackConstraint::Bool(constraint) => constraint.ideal.is_some(),
+            ResolvedMediaTrackConstraint::StringSequence(constraint) => constraint.ideal.is_some(),
+            ResolvedMediaTrackConstraint::String(constraint) => constraint.ideal.is_some(),
+        })
+        .fold((0, 0), |(satisfied, total), (property, constraint)| {
+            let is_satisfied = matches!(
+                constraint.fitness_distance(settings.get(property)),
+                Ok(fitness_distance) if fitness_distance <= IDEAL_COVERAGE_TOLERANCE
+            );
+            (satisfied + usize::from(is_satisfied), total + 1)
+        })
+}
+
+/// Returns the settings of `chosen` for those mandatory numeric constraints specifying
+/// an ideal value, i.e. the values the ideal values got snapped to by choosing `chosen`
+/// (e.g. an ideal `frameRate` of `50` getting snapped to the chosen settings' `60`).
+///
+/// Properties lacking a numeric setting in `chosen` are omitted, as are advanced constraints.
+pub fn snap_ideal(
+    constraints: &SanitizedMediaTrackConstraints,
+    chosen: &MediaTrackSettings,
+) -> MediaTrackSettings {
+    constraints
+        .mandatory
+        .iter()
+        .filter(|(_, constraint)| match &***constraint {
+            ResolvedMediaTrackConstraint::IntegerRange(constraint) => constraint.ideal.is_some(),
+            ResolvedMediaTrackConstraint::FloatRange(constraint) => constraint.ideal.is_some(),
+            _ => false,
+        })
+        .filter_map(|(property, _)| match chosen.get(property) {
+            Some(setting @ (MediaTrackSetting::Integer(_) | MediaTrackSetting::Float(_))) => {
+                Some((property, setting.clone()))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
 #[derive(Default)]
 pub(crate) struct ConstraintFailureInfo {
     pub(crate) failures: usize,
-    pub(crate) errors: HashSet<SettingFitnessDistanceError>,
+    /// The distinct errors, each with the setting that caused it (if any).
+    pub(crate) errors: HashMap<SettingFitnessDistanceError, Option<MediaTrackSetting>>,
+}
+
+impl ConstraintFailureInfo {
+    pub(crate) fn record(
+        &mut self,
+        error: SettingFitnessDistanceError,
+        setting: Option<&MediaTrackSetting>,
+    ) {
+        self.failures += 1;
+        self.errors.entry(error).or_insert_with(|| setting.cloned());
+    }
 }
 
 #[cfg(test)]
@@ -1,9 +1,58 @@
 use std::collections::HashMap;
 
+use crate::algorithms::fitness_distance::SettingFitnessDistanceError;
 use crate::algorithms::select_settings::{ConstraintFailureInfo, DeviceInformationExposureMode};
 use crate::algorithms::FitnessDistance;
 use crate::errors::OverconstrainedError;
-use crate::{MediaTrackProperty, MediaTrackSettings, SanitizedMediaTrackConstraintSet};
+use crate::{
+    MediaTrackProperty, MediaTrackSetting, MediaTrackSettings, SanitizedMediaTrackConstraintSet,
+};
+
+/// The mandatory constraints failed by a list of candidates, tracked per property.
+#[derive(Default)]
+pub(super) struct FailedConstraints {
+    failure_infos: HashMap<MediaTrackProperty, ConstraintFailureInfo>,
+}
+
+impl FailedConstraints {
+    /// Records the failure of `property`'s constraint for a candidate's `setting`.
+    pub(super) fn record(
+        &mut self,
+        property: MediaTrackProperty,
+        error: SettingFitnessDistanceError,
+        setting: Option<&MediaTrackSetting>,
+    ) {
+        self.failure_infos
+            .entry(property)
+            .or_default()
+            .record(error, setting);
+    }
+
+    /// Records the failures of all of `candidate`'s settings contained in `errors`.
+    fn record_all<I>(&mut self, errors: I, candidate: &MediaTrackSettings)
+    where
+        I: IntoIterator<Item = (MediaTrackProperty, SettingFitnessDistanceError)>,
+    {
+        for (property, error) in errors {
+            let setting = candidate.get(&property);
+            self.record(property, error, setting);
+        }
+    }
+
+    /// Consumes `self`, returning the corresponding error,
+    /// which only describes the failures if `exposure_mode` permits it.
+    pub(super) fn into_error(
+        self,
+        exposure_mode: DeviceInformationExposureMode,
+    ) -> OverconstrainedError {
+        match exposure_mode {
+            DeviceInformationExposureMode::Exposed => {
+                OverconstrainedError::exposing_device_information(self.failure_infos)
+            }
+            DeviceInformationExposureMode::Protected => OverconstrainedError::default(),
+        }
+    }
+}
 
 /// Returns the set of settings for which all mandatory constraints'
 /// fitness distance is finite.
@@ -26,8 +75,7 @@ where
     // > set of settings dictionaries for which the fitness distance is finite.
 
     let mut feasible_candidates: Vec<(&'a MediaTrackSettings, f64)> = vec![];
-    let mut failed_constraints: HashMap<MediaTrackProperty, ConstraintFailureInfo> =
-        Default::default();
+    let mut failed_constraints = FailedConstraints::default();
 
     for candidate in candidates {
         match mandatory_constraints.fitness_distance(candidate) {
@@ -36,28 +84,105 @@ where
 
                 feasible_candidates.push((candidate, fitness_distance));
             }
-            Err(error) => {
-                for (property, setting_error) in error.setting_errors {
-                    let entry = failed_constraints.entry(property).or_default();
-                    entry.failures += 1;
-                    entry.errors.insert(setting_error);
-                }
-            }
+            Err(error) => failed_constraints.record_all(error.setting_errors, candidate),
         }
     }
 
     if feasible_candidates.is_empty() {
-        return Err(match exposure_mode {
-            DeviceInformationExposureMode::Exposed => {
-                OverconstrainedError::exposing_device_information(failed_constraints)
+        return Err(failed_constraints.into_error(exposure_mode));
+    }
+
+    Ok(feasible_candidates)
+}
+
+/// Returns the first of the given candidates for which all mandatory constraints'
+/// fitness distance is finite, without consuming any further candidates.
+pub(super) fn find_first_satisfying_candidate<'a, I>(
+    candidates: I,
+    mandatory_constraints: &SanitizedMediaTrackConstraintSet,
+    exposure_mode: DeviceInformationExposureMode,
+) -> Result<&'a MediaTrackSettings, OverconstrainedError>
+where
+    I: IntoIterator<Item = &'a MediaTrackSettings>,
+{
+    let mut failed_constraints = FailedConstraints::default();
+
+    for candidate in candidates {
+        match mandatory_constraints.fitness_distance(candidate) {
+            Ok(_) => return Ok(candidate),
+            Err(error) => failed_constraints.record_all(error.setting_errors, candidate),
+        }
+    }
+
+    Err(failed_constraints.into_error(exposure_mode))
+}
+
+/// Returns the owned candidates for which all mandatory constraints'
+/// fitness distance is finite, along with that fitness distance,
+/// dropping all others as they get consumed.
+pub(super) fn retain_satisfying_candidates<I>(
+    candidates: I,
+    mandatory_constraints: &SanitizedMediaTrackConstraintSet,
+    exposure_mode: DeviceInformationExposureMode,
+) -> Result<Vec<(MediaTrackSettings, f64)>, OverconstrainedError>
+where
+    I: IntoIterator<Item = MediaTrackSettings>,
+{
+    let mut feasible_candidates: Vec<(MediaTrackSettings, f64)> = vec![];
+    let mut failed_constraints = FailedConstraints::default();
+
+    for candidate in candidates {
+        match mandatory_constraints.fitness_distance(&candidate) {
+            Ok(fitness_distance) => {
+                debug_assert!(fitness_distance.is_finite());
+
+                feasible_candidates.push((candidate, fitness_distance));
             }
-            DeviceInformationExposureMode::Protected => OverconstrainedError::default(),
-        });
+            Err(error) => failed_constraints.record_all(error.setting_errors, &candidate),
+        }
+    }
+
+    if feasible_candidates.is_empty() {
+        return Err(failed_constraints.into_error(exposure_mode));
     }
 
     Ok(feasible_candidates)
 }
 
+/// Returns an error for every one of the given candidates,
+/// describing the mandatory constraint it violates.
+///
+/// Candidates violating more than one mandatory constraint
+/// get reported for the one whose property sorts first by name.
+/// Candidates satisfying all mandatory constraints are omitted.
+pub(super) fn explain_mandatory_constraints<'a, I>(
+    candidates: I,
+    mandatory_constraints: &SanitizedMediaTrackConstraintSet,
+    exposure_mode: DeviceInformationExposureMode,
+) -> Vec<(usize, OverconstrainedError)>
+where
+    I: IntoIterator<Item = &'a MediaTrackSettings>,
+{
+    candidates
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, candidate)| {
+            let error = mandatory_constraints.fitness_distance(candidate).err()?;
+
+            let (property, setting_error) = error
+                .setting_errors
+                .into_iter()
+                .min_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs))
+                .expect("A fitness distance error should contain at least one setting error.");
+
+            let mut failed_constraints = FailedConstraints::default();
+            failed_constraints.record_all([(property, setting_error)], candidate);
+
+            Some((index, failed_constraints.into_error(exposure_mode)))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use std::iter::FromIterator;
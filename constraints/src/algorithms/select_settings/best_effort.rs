@@ -0,0 +1,133 @@
+use std::cmp::Ordering;
+
+use crate::algorithms::fitness_distance::relative_fitness_distance;
+use crate::algorithms::select_settings::{
+    select_settings, DeviceInformationExposureMode, TieBreakingPolicy,
+};
+use crate::algorithms::FitnessDistance;
+use crate::{
+    MediaTrackSetting, MediaTrackSettings, ResolvedMediaTrackConstraint,
+    SanitizedMediaTrackConstraintSet, SanitizedMediaTrackConstraints,
+};
+
+/// Selects the settings closest to satisfying the constraints, even if none of them does.
+///
+/// If any of the possible settings satisfy the mandatory constraints this behaves
+/// exactly like `select_settings`. Otherwise, rather than failing with an
+/// `OverconstrainedError`, the candidate with the smallest fitness distance gets selected,
+/// with each violated mandatory constraint contributing a penalty (exceeding the sum
+/// of all non-violated constraints' fitness distances) plus its distance from being satisfied.
+/// Candidates violating fewer mandatory constraints are thus always preferred.
+///
+/// Returns `None` if, and only if, `possible_settings` is empty.
+///
+/// # W3C Spec Compliance
+///
+/// This diverges from the `SelectSettings` algorithm of the
+/// W3C ["Media Capture and Streams"][media_capture_and_streams_spec] spec,
+/// which mandates failing on overconstrained mandatory constraints.
+///
+/// [media_capture_and_streams_spec]: https://www.w3.org/TR/mediacapture-streams/
+pub fn select_settings_best_effort<'a, I, P>(
+    possible_settings: I,
+    constraints: &SanitizedMediaTrackConstraints,
+    tie_breaking_policy: &P,
+) -> Option<&'a MediaTrackSettings>
+where
+    I: IntoIterator<Item = &'a MediaTrackSettings>,
+    P: TieBreakingPolicy + ?Sized,
+{
+    let possible_settings: Vec<_> = possible_settings.into_iter().collect();
+
+    if possible_settings.is_empty() {
+        return None;
+    }
+
+    if let Ok(settings) = select_settings(
+        possible_settings.iter().copied(),
+        constraints,
+        DeviceInformationExposureMode::Protected,
+        tie_breaking_policy,
+    ) {
+        return Some(settings);
+    }
+
+    let candidates: Vec<_> = possible_settings
+        .into_iter()
+        .map(|settings| {
+            let fitness_distance = relaxed_fitness_distance(&constraints.mandatory, settings);
+            (settings, fitness_distance)
+        })
+        .collect();
+
+    let optimal_fitness_distance = candidates
+        .iter()
+        .map(|(_, fitness_distance)| *fitness_distance)
+        .min_by(|lhs, rhs| lhs.partial_cmp(rhs).unwrap_or(Ordering::Equal))?;
+
+    let optimal_candidates: Vec<_> = candidates
+        .into_iter()
+        .filter(|(_, fitness_distance)| *fitness_distance == optimal_fitness_distance)
+        .map(|(settings, _)| settings)
+        .collect();
+
+    Some(tie_breaking_policy.break_tie(&optimal_candidates))
+}
+
+/// Computes the fitness distance of `settings`, treating violated constraints
+/// as heavily penalized, rather than as infinitely distant.
+fn relaxed_fitness_distance(
+    constraints: &SanitizedMediaTrackConstraintSet,
+    settings: &MediaTrackSettings,
+) -> f64 {
+    // Each satisfied constraint contributes at most `1.0`,
+    // so a penalty of the number of constraints outweighs all of them:
+    let violation_penalty = constraints.len() as f64;
+
+    constraints
+        .iter()
+        .map(|(property, constraint)| {
+            let setting = settings.get(property);
+            match constraint.fitness_distance(setting) {
+                Ok(fitness_distance) => fitness_distance,
+                Err(_) => violation_penalty + violation_distance(constraint, setting),
+            }
+        })
+        .sum()
+}
+
+/// Computes how far `setting` is from satisfying the required parts of `constraint`,
+/// in the range of `0.0..=1.0`.
+fn violation_distance(
+    constraint: &ResolvedMediaTrackConstraint,
+    setting: Option<&MediaTrackSetting>,
+) -> f64 {
+    let actual = match setting {
+        Some(MediaTrackSetting::Integer(actual)) => *actual as f64,
+        Some(MediaTrackSetting::Float(actual)) => *actual,
+        // Non-numeric settings either match or they don't:
+        _ => return 1.0,
+    };
+
+    let (exact, min, max) = match constraint {
+        ResolvedMediaTrackConstraint::IntegerRange(constraint) => (
+            constraint.exact.map(|exact| exact as f64),
+            constraint.min.map(|min| min as f64),
+            constraint.max.map(|max| max as f64),
+        ),
+        ResolvedMediaTrackConstraint::FloatRange(constraint) => {
+            (constraint.exact, constraint.min, constraint.max)
+        }
+        _ => return 1.0,
+    };
+
+    let nearest = match exact {
+        Some(exact) => exact,
+        None => {
+            let actual = min.map_or(actual, |min| actual.max(min));
+            max.map_or(actual, |max| actual.min(max))
+        }
+    };
+
+    relative_fitness_distance(actual, nearest)
+}
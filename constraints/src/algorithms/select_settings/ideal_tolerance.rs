@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use crate::algorithms::FitnessDistance;
+use crate::{
+    MediaTrackProperty, MediaTrackSetting, MediaTrackSettings, ResolvedMediaTrackConstraint,
+    SanitizedMediaTrackConstraintSet,
+};
+
+/// Absolute tolerances per property, within which numeric settings
+/// are considered to fully satisfy a constraint's ideal value
+/// (e.g. a `frameRate` of `58` satisfying an ideal of `60` for a tolerance of `2`).
+///
+/// Settings within tolerance contribute a fitness distance of zero,
+/// while the constraint's required values (i.e. `min`, `max`, `exact`) remain strict.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct IdealTolerance(HashMap<MediaTrackProperty, f64>);
+
+impl IdealTolerance {
+    /// Creates an empty tolerance, i.e. one matching ideal values exactly.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the tolerance with a `tolerance` for the ideal values of `property`.
+    pub fn with<T>(mut self, property: T, tolerance: f64) -> Self
+    where
+        T: Into<MediaTrackProperty>,
+    {
+        self.0.insert(property.into(), tolerance.abs());
+        self
+    }
+
+    /// Returns the tolerance for the ideal values of `property`, if any.
+    pub fn get(&self, property: &MediaTrackProperty) -> Option<f64> {
+        self.0.get(property).copied()
+    }
+
+    /// Returns `true` if no property has a tolerance.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns `true` if `setting` is within tolerance of the ideal value
+    /// of the numeric `constraint` on `property`.
+    fn is_within(
+        &self,
+        property: &MediaTrackProperty,
+        constraint: &ResolvedMediaTrackConstraint,
+        setting: Option<&MediaTrackSetting>,
+    ) -> bool {
+        let tolerance = match self.get(property) {
+            Some(tolerance) => tolerance,
+            None => return false,
+        };
+
+        let ideal = match constraint {
+            ResolvedMediaTrackConstraint::IntegerRange(constraint) => {
+                constraint.ideal.map(|ideal| ideal as f64)
+            }
+            ResolvedMediaTrackConstraint::FloatRange(constraint) => constraint.ideal,
+            _ => None,
+        };
+
+        let actual = match setting {
+            Some(MediaTrackSetting::Integer(actual)) => Some(*actual as f64),
+            Some(MediaTrackSetting::Float(actual)) => Some(*actual),
+            _ => None,
+        };
+
+        match (ideal, actual) {
+            (Some(ideal), Some(actual)) => (actual - ideal).abs() <= tolerance,
+            _ => false,
+        }
+    }
+}
+
+/// Returns the fitness distance of `settings` for the mandatory `constraints`,
+/// treating those numeric settings within `tolerance` of their ideal value as a perfect fit.
+///
+/// Expects `settings` to satisfy `constraints`, i.e. constraints it violates get ignored.
+pub(super) fn tolerant_fitness_distance(
+    settings: &MediaTrackSettings,
+    constraints: &SanitizedMediaTrackConstraintSet,
+    tolerance: &IdealTolerance,
+) -> f64 {
+    constraints
+        .iter()
+        .map(|(property, constraint)| {
+            let setting = settings.get(property);
+            if tolerance.is_within(property, constraint, setting) {
+                0.0
+            } else {
+                constraint.fitness_distance(setting).unwrap_or(0.0)
+            }
+        })
+        .sum()
+}
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+
+use crate::algorithms::fitness_distance::SettingFitnessDistanceError;
+use crate::algorithms::select_settings::apply_advanced::apply_advanced_constraints;
+use crate::algorithms::select_settings::apply_mandatory::FailedConstraints;
+use crate::algorithms::select_settings::select_optimal::select_optimal_candidates;
+use crate::algorithms::select_settings::{
+    DeviceInformationExposureMode, SelectSettingsError, TieBreakingPolicy,
+};
+use crate::algorithms::FitnessDistance;
+use crate::{
+    MediaTrackProperty, MediaTrackSettings, SanitizedMediaTrackConstraint,
+    SanitizedMediaTrackConstraints,
+};
+
+/// The fitness distances of every possible settings item for a single mandatory constraint.
+#[derive(Debug, Clone)]
+struct CachedFitnessDistances {
+    constraint: SanitizedMediaTrackConstraint,
+    fitness_distances: Vec<Result<f64, SettingFitnessDistanceError>>,
+}
+
+/// A selector performing the `SelectSettings` algorithm repeatedly
+/// against a fixed list of possible settings (e.g. while a user is tweaking constraints).
+///
+/// The fitness distances of each mandatory constraint get cached per property,
+/// so that subsequent calls to `update` only re-score the possible settings
+/// for those properties whose constraints have changed in the meantime.
+///
+/// Advanced constraints are cheap to evaluate, as they only apply to the remaining
+/// candidates and get re-evaluated on every call to `update`.
+#[derive(Debug, Clone)]
+pub struct IncrementalSelector {
+    possible_settings: Vec<MediaTrackSettings>,
+    cache: HashMap<MediaTrackProperty, CachedFitnessDistances>,
+}
+
+impl IncrementalSelector {
+    /// Creates a selector for the given possible settings.
+    pub fn new<I>(possible_settings: I) -> Self
+    where
+        I: IntoIterator<Item = MediaTrackSettings>,
+    {
+        Self {
+            possible_settings: possible_settings.into_iter().collect(),
+            cache: HashMap::default(),
+        }
+    }
+
+    /// Returns the possible settings this selector selects from.
+    pub fn possible_settings(&self) -> &[MediaTrackSettings] {
+        &self.possible_settings
+    }
+
+    /// This function implements the `SelectSettings` algorithm
+    /// as defined by the W3C spec:
+    /// <https://www.w3.org/TR/mediacapture-streams/#dfn-selectsettings>
+    ///
+    /// The result is identical to that of calling `select_settings`
+    /// on the selector's possible settings, but only mandatory constraints
+    /// that have changed since the previous call get re-scored.
+    pub fn update<P>(
+        &mut self,
+        constraints: &SanitizedMediaTrackConstraints,
+        exposure_mode: DeviceInformationExposureMode,
+        tie_breaking_policy: &P,
+    ) -> Result<&MediaTrackSettings, SelectSettingsError>
+    where
+        P: TieBreakingPolicy + ?Sized,
+    {
+        if self.possible_settings.is_empty() {
+            return Err(SelectSettingsError::NoCandidates);
+        }
+
+        self.update_cache(constraints);
+
+        let mut feasible_candidates: Vec<(&MediaTrackSettings, f64)> = vec![];
+        let mut failed_constraints = FailedConstraints::default();
+
+        for (index, candidate) in self.possible_settings.iter().enumerate() {
+            let mut total_fitness_distance = 0.0;
+            let mut is_feasible = true;
+
+            for (property, _) in constraints.mandatory.iter() {
+                match &self.cache[property].fitness_distances[index] {
+                    Ok(fitness_distance) => total_fitness_distance += fitness_distance,
+                    Err(setting_error) => {
+                        is_feasible = false;
+
+                        failed_constraints.record(
+                            property.clone(),
+                            setting_error.clone(),
+                            candidate.get(property),
+                        );
+                    }
+                }
+            }
+
+            if is_feasible {
+                feasible_candidates.push((candidate, total_fitness_distance));
+            }
+        }
+
+        if feasible_candidates.is_empty() {
+            return Err(failed_constraints.into_error(exposure_mode).into());
+        }
+
+        let candidates = apply_advanced_constraints(feasible_candidates, &constraints.advanced);
+        let optimal_candidates = select_optimal_candidates(candidates);
+
+        Ok(tie_breaking_policy.break_tie(&optimal_candidates))
+    }
+
+    /// Re-scores the possible settings for every mandatory constraint
+    /// that differs from the one it was last scored for,
+    /// dropping those of properties that are no longer constrained.
+    fn update_cache(&mut self, constraints: &SanitizedMediaTrackConstraints) {
+        let possible_settings = &self.possible_settings;
+
+        self.cache
+            .retain(|property, _| constraints.mandatory.contains_key(property));
+
+        for (property, constraint) in constraints.mandatory.iter() {
+            let is_up_to_date = matches!(
+                self.cache.get(property),
+                Some(cached) if &cached.constraint == constraint
+            );
+
+            if is_up_to_date {
+                continue;
+            }
+
+            let fitness_distances = possible_settings
+                .iter()
+                .map(|settings| constraint.fitness_distance(settings.get(property)))
+                .collect();
+
+            self.cache.insert(
+                property.clone(),
+                CachedFitnessDistances {
+                    constraint: constraint.clone(),
+                    fitness_distances,
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use crate::algorithms::select_settings::{
+    select_settings_scored, DeviceInformationExposureMode, SelectSettingsError,
+};
+use crate::{MediaTrackProperty, MediaTrackSettings, SanitizedMediaTrackConstraints};
+
+/// A constraint spanning the settings selected for multiple tracks.
+///
+/// # W3C Spec Compliance
+///
+/// There exists no corresponding type in the W3C ["Media Capture and Streams"][media_capture_and_streams_spec] spec.
+///
+/// [media_capture_and_streams_spec]: https://www.w3.org/TR/mediacapture-streams/
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum JointConstraint {
+    /// No joint constraint, each track's settings get selected independently.
+    None,
+    /// Prefer selections where all tracks' settings share the same value for the given property
+    /// (e.g. the same `groupId` for a camera and microphone that are part of the same device).
+    SameValue(MediaTrackProperty),
+}
+
+impl JointConstraint {
+    /// Computes the fitness distance of the given selection (one settings item per track).
+    fn fitness_distance(&self, selection: &[&MediaTrackSettings]) -> f64 {
+        match self {
+            Self::None => 0.0,
+            Self::SameValue(property) => {
+                // Analogous to step 8 of the `fitness distance` algorithm:
+                // <https://www.w3.org/TR/mediacapture-streams/#dfn-fitness-distance>
+                //
+                // > For all string, enum and boolean constraints […],
+                // > the fitness distance is the result of the formula:
+                // >
+                // > ```
+                // > (actual == ideal) ? 0 : 1
+                // > ```
+                let mut values = selection.iter().map(|settings| settings.get(property));
+                let first = values.next().flatten();
+                if first.is_some() && values.all(|value| value == first) {
+                    0.0
+                } else {
+                    1.0
+                }
+            }
+        }
+    }
+}
+
+/// Selects one settings item per track, honoring each track's own constraints
+/// as well as the given `joint` constraint spanning all tracks.
+///
+/// The combination with the smallest sum of each track's fitness distance
+/// (as calculated in step 3 of the `SelectSettings` algorithm) and the joint
+/// constraint's fitness distance gets selected.
+/// If more than one combination has the smallest fitness distance
+/// the first one (in order of the provided settings) gets selected.
+///
+/// # Performance
+///
+/// All combinations of satisfying candidates get evaluated,
+/// so the runtime grows with the product of the tracks' candidate counts.
+pub fn select_settings_multi<'a>(
+    streams: &[(&'a [MediaTrackSettings], SanitizedMediaTrackConstraints)],
+    joint: &JointConstraint,
+    exposure_mode: DeviceInformationExposureMode,
+) -> Result<Vec<&'a MediaTrackSettings>, SelectSettingsError> {
+    let candidates: Vec<Vec<(&'a MediaTrackSettings, f64)>> = streams
+        .iter()
+        .map(|(possible_settings, constraints)| {
+            select_settings_scored(possible_settings.iter(), constraints, exposure_mode)
+        })
+        .collect::<Result<_, _>>()?;
+
+    let mut indices = vec![0; candidates.len()];
+    let mut selection = Vec::with_capacity(candidates.len());
+
+    let mut optimal_selection = vec![];
+    let mut optimal_fitness_distance = f64::INFINITY;
+
+    loop {
+        selection.clear();
+        selection.extend(
+            indices
+                .iter()
+                .zip(candidates.iter())
+                .map(|(&index, candidates)| candidates[index]),
+        );
+
+        let settings: Vec<_> = selection.iter().map(|(settings, _)| *settings).collect();
+        let fitness_distance: f64 = selection
+            .iter()
+            .map(|(_, fitness_distance)| fitness_distance)
+            .sum::<f64>()
+            + joint.fitness_distance(&settings);
+
+        if fitness_distance < optimal_fitness_distance {
+            optimal_fitness_distance = fitness_distance;
+            optimal_selection = settings;
+        }
+
+        // Advance to the next combination, odometer-style:
+        let mut track = indices.len();
+        loop {
+            if track == 0 {
+                return Ok(optimal_selection);
+            }
+            track -= 1;
+            indices[track] += 1;
+            if indices[track] < candidates[track].len() {
+                break;
+            }
+            indices[track] = 0;
+        }
+    }
+}
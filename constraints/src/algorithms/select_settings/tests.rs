@@ -3,16 +3,26 @@ use std::iter::FromIterator;
 use lazy_static::lazy_static;
 
 use super::DeviceInformationExposureMode;
-use crate::algorithms::{select_settings_candidates, SelectSettingsError};
+use crate::algorithms::{
+    fitness_distance, ideal_coverage, select_settings, select_settings_best_effort,
+    select_settings_candidates, select_settings_explained, select_settings_first_match,
+    select_settings_multi, select_settings_owned, select_settings_scored,
+    select_settings_scored_with_tolerance, select_settings_top_n, snap_ideal, unmet_ideals,
+    ClosestToIdealPolicy, ExplainedSelectSettingsError, FirstPolicy, IdealTolerance,
+    IncrementalSelector, JointConstraint, SelectByIndexPolicy, SelectLastSettingsPolicy,
+    SelectSettingsError, UnmetIdeal,
+};
 use crate::errors::OverconstrainedError;
 use crate::property::all::name::*;
 use crate::property::all::names as all_properties;
 use crate::{
-    AdvancedMediaTrackConstraints, FacingMode, MandatoryMediaTrackConstraints,
-    MediaTrackConstraints, MediaTrackSettings, MediaTrackSupportedConstraints, ResizeMode,
-    ResolvedAdvancedMediaTrackConstraints, ResolvedMandatoryMediaTrackConstraints,
-    ResolvedMediaTrackConstraint, ResolvedMediaTrackConstraints, ResolvedValueConstraint,
+    AdvancedMediaTrackConstraints, CursorCapture, DisplaySurface, FacingMode,
+    MandatoryMediaTrackConstraints, MediaTrackConstraints, MediaTrackSettings,
+    MediaTrackSupportedConstraints, ResizeMode, ResolvedAdvancedMediaTrackConstraints,
+    ResolvedMandatoryMediaTrackConstraints, ResolvedMediaTrackConstraint,
+    ResolvedMediaTrackConstraintSet, ResolvedMediaTrackConstraints, ResolvedValueConstraint,
     ResolvedValueRangeConstraint, ResolvedValueSequenceConstraint, SanitizedMediaTrackConstraints,
+    ValueExclusionConstraint,
 };
 
 lazy_static! {
@@ -100,9 +110,10 @@ fn test_overconstrained(
 
     let actual = result.err().unwrap();
 
-    let SelectSettingsError::Overconstrained(overconstrained_error) = actual;
-
-    overconstrained_error
+    match actual {
+        SelectSettingsError::Overconstrained(overconstrained_error) => overconstrained_error,
+        error => panic!("Expected overconstrained error, found {error:?}"),
+    }
 }
 
 fn test_constrained(
@@ -160,6 +171,45 @@ mod unconstrained {
     }
 }
 
+mod no_candidates {
+    use super::*;
+
+    #[test]
+    fn empty_possible_settings() {
+        let possible_settings: Vec<MediaTrackSettings> = vec![];
+        let sanitized_constraints = ResolvedMediaTrackConstraints::default()
+            .into_sanitized(&default_supported_constraints());
+
+        for exposure_mode in [
+            DeviceInformationExposureMode::Exposed,
+            DeviceInformationExposureMode::Protected,
+        ] {
+            let actual = select_settings(
+                &possible_settings[..],
+                &sanitized_constraints,
+                exposure_mode,
+                &FirstPolicy,
+            );
+            assert_eq!(actual, Err(SelectSettingsError::NoCandidates));
+
+            let actual = select_settings_first_match(
+                &possible_settings[..],
+                &sanitized_constraints,
+                exposure_mode,
+            );
+            assert_eq!(actual, Err(SelectSettingsError::NoCandidates));
+
+            let actual = select_settings_owned(
+                possible_settings.clone(),
+                &sanitized_constraints,
+                exposure_mode,
+                &FirstPolicy,
+            );
+            assert_eq!(actual, Err(SelectSettingsError::NoCandidates));
+        }
+    }
+}
+
 mod overconstrained {
     use super::*;
     use crate::MediaTrackProperty;
@@ -230,6 +280,60 @@ mod overconstrained {
         );
         }
 
+        #[test]
+        fn bool_mismatch() {
+            let possible_settings = vec![
+                MediaTrackSettings::from_iter([(&AUTO_GAIN_CONTROL, false.into())]),
+                MediaTrackSettings::from_iter([(&AUTO_GAIN_CONTROL, false.into())]),
+            ];
+
+            let error = test_overconstrained(
+                &possible_settings,
+                ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                    AUTO_GAIN_CONTROL.clone(),
+                    ResolvedValueConstraint::default().exact(true).into(),
+                )]),
+                DeviceInformationExposureMode::Exposed,
+            );
+
+            let constraint = &error.constraint;
+            let err_message = error.message.as_ref().expect("Error message.");
+
+            assert_eq!(constraint, &AUTO_GAIN_CONTROL);
+            assert_eq!(
+                err_message,
+                "Setting was a mismatch ([false] do not satisfy (x == true))."
+            );
+        }
+
+        #[cfg(feature = "regex")]
+        #[test]
+        fn pattern_mismatch() {
+            use regex::Regex;
+
+            use crate::ValuePatternConstraint;
+
+            let error = test_overconstrained(
+                &default_possible_settings(),
+                ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                    DEVICE_ID.clone(),
+                    ValuePatternConstraint::default()
+                        .exact(Regex::new("^usb-").unwrap())
+                        .into(),
+                )]),
+                DeviceInformationExposureMode::Exposed,
+            );
+
+            let constraint = &error.constraint;
+            let err_message = error.message.as_ref().expect("Error message.");
+
+            assert_eq!(constraint, &DEVICE_ID);
+            assert_eq!(
+            err_message,
+            "Setting was a mismatch ([\"1080p\", \"1440p\", \"2160p\", \"480p\", \"720p\"] do not satisfy (x =~ /^usb-/))."
+        );
+        }
+
         #[test]
         fn too_small() {
             let error = test_overconstrained(
@@ -247,7 +351,63 @@ mod overconstrained {
             assert_eq!(constraint, &FRAME_RATE);
             assert_eq!(
                 err_message,
-                "Setting was too small ([120, 15, 240, 30, 60] do not satisfy (1000 <= x))."
+                "Setting was too small ([15, 30, 60, 120, 240] do not satisfy (1000 <= x))."
+            );
+        }
+
+        #[test]
+        fn excluded() {
+            let error = test_overconstrained(
+                std::slice::from_ref(&VIDEO_1080P),
+                ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                    DEVICE_ID.clone(),
+                    ValueExclusionConstraint::default()
+                        .not(vec!["1080p".to_owned()])
+                        .into(),
+                )]),
+                DeviceInformationExposureMode::Exposed,
+            );
+
+            let constraint = &error.constraint;
+            let err_message = error.message.as_ref().expect("Error message.");
+
+            assert_eq!(constraint, &DEVICE_ID);
+            assert_eq!(
+                err_message,
+                "Setting was a mismatch ([\"1080p\"] do not satisfy (x != \"1080p\"))."
+            );
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn too_small_json() {
+            let error = test_overconstrained(
+                &default_possible_settings(),
+                ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                    FRAME_RATE.clone(),
+                    ResolvedValueRangeConstraint::default().min(1000).into(),
+                )]),
+                DeviceInformationExposureMode::Exposed,
+            );
+
+            let message =
+                "Setting was too small ([15, 30, 60, 120, 240] do not satisfy (1000 <= x)).";
+
+            assert_eq!(
+                serde_json::to_value(&error).unwrap(),
+                serde_json::json!({
+                    "constraint": "frameRate",
+                    "message": message,
+                })
+            );
+
+            assert_eq!(
+                serde_json::to_value(SelectSettingsError::from(error)).unwrap(),
+                serde_json::json!({
+                    "type": "overconstrained",
+                    "constraint": "frameRate",
+                    "message": message,
+                })
             );
         }
 
@@ -268,7 +428,7 @@ mod overconstrained {
             assert_eq!(constraint, &FRAME_RATE);
             assert_eq!(
                 err_message,
-                "Setting was too large ([120, 15, 240, 30, 60] do not satisfy (x <= 10))."
+                "Setting was too large ([15, 30, 60, 120, 240] do not satisfy (x <= 10))."
             );
         }
     }
@@ -302,6 +462,85 @@ mod constrained {
         }
     }
 
+    #[test]
+    fn excluded_device_id() {
+        let possible_settings = default_possible_settings();
+
+        let actual = test_constrained(
+            &possible_settings,
+            ResolvedMandatoryMediaTrackConstraints::from_iter([
+                (
+                    &DEVICE_ID,
+                    ValueExclusionConstraint::default()
+                        .not(vec!["1080p".to_owned()])
+                        .into(),
+                ),
+                (
+                    &WIDTH,
+                    ResolvedValueRangeConstraint::default().ideal(1920).into(),
+                ),
+            ]),
+            ResolvedAdvancedMediaTrackConstraints::default(),
+        );
+
+        // Without the exclusion 1080p would have been the closest match:
+        let expected = vec![&possible_settings[3]];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn audio_value_range() {
+        let possible_settings = vec![
+            MediaTrackSettings::from_iter([(&DEVICE_ID, "a".into()), (&SAMPLE_RATE, 8000.into())]),
+            MediaTrackSettings::from_iter([(&DEVICE_ID, "b".into()), (&SAMPLE_RATE, 16000.into())]),
+            MediaTrackSettings::from_iter([(&DEVICE_ID, "c".into()), (&SAMPLE_RATE, 48000.into())]),
+        ];
+
+        let actual = test_constrained(
+            &possible_settings,
+            ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &SAMPLE_RATE,
+                ResolvedValueRangeConstraint::default().min(44100).into(),
+            )]),
+            ResolvedAdvancedMediaTrackConstraints::default(),
+        );
+
+        let expected = vec![&possible_settings[2]];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn aspect_ratio_range() {
+        let possible_settings = vec![
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, "4:3".into()),
+                (&ASPECT_RATIO, (4.0 / 3.0).into()),
+            ]),
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, "16:9".into()),
+                (&ASPECT_RATIO, (16.0 / 9.0).into()),
+            ]),
+        ];
+
+        let actual = test_constrained(
+            &possible_settings,
+            ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &ASPECT_RATIO,
+                ResolvedValueRangeConstraint::default()
+                    .min(1.5)
+                    .max(1.8)
+                    .into(),
+            )]),
+            ResolvedAdvancedMediaTrackConstraints::default(),
+        );
+
+        let expected = vec![&possible_settings[1]];
+
+        assert_eq!(actual, expected);
+    }
+
     mod exact {
         use super::*;
 
@@ -338,6 +577,69 @@ mod constrained {
             assert_eq!(actual, expected);
         }
 
+        #[test]
+        fn bool_value() {
+            let possible_settings = vec![
+                MediaTrackSettings::from_iter([
+                    (&DEVICE_ID, "a".into()),
+                    (&ECHO_CANCELLATION, true.into()),
+                ]),
+                MediaTrackSettings::from_iter([
+                    (&DEVICE_ID, "b".into()),
+                    (&ECHO_CANCELLATION, false.into()),
+                ]),
+                MediaTrackSettings::from_iter([
+                    (&DEVICE_ID, "c".into()),
+                    (&ECHO_CANCELLATION, true.into()),
+                ]),
+            ];
+
+            for (exact, expected) in [
+                (true, vec![&possible_settings[0], &possible_settings[2]]),
+                (false, vec![&possible_settings[1]]),
+            ] {
+                let actual = test_constrained(
+                    &possible_settings,
+                    ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                        &ECHO_CANCELLATION,
+                        ResolvedValueConstraint::default().exact(exact).into(),
+                    )]),
+                    ResolvedAdvancedMediaTrackConstraints::default(),
+                );
+
+                assert_eq!(actual, expected);
+            }
+        }
+
+        #[cfg(feature = "regex")]
+        #[test]
+        fn pattern() {
+            use regex::Regex;
+
+            use crate::ValuePatternConstraint;
+
+            let possible_settings = vec![
+                MediaTrackSettings::from_iter([(&DEVICE_ID, "pci-0001".into())]),
+                MediaTrackSettings::from_iter([(&DEVICE_ID, "usb-0001".into())]),
+                MediaTrackSettings::from_iter([(&DEVICE_ID, "usb-0002".into())]),
+            ];
+
+            let actual = test_constrained(
+                &possible_settings,
+                ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                    &DEVICE_ID,
+                    ValuePatternConstraint::default()
+                        .exact(Regex::new("^usb-").unwrap())
+                        .into(),
+                )]),
+                ResolvedAdvancedMediaTrackConstraints::default(),
+            );
+
+            let expected = vec![&possible_settings[1], &possible_settings[2]];
+
+            assert_eq!(actual, expected);
+        }
+
         #[test]
         fn value_range() {
             let possible_settings = vec![
@@ -398,28 +700,28 @@ mod constrained {
         use super::*;
 
         #[test]
-        fn value() {
+        fn facing_mode_sequence() {
             let possible_settings = vec![
                 MediaTrackSettings::from_iter([
-                    (&DEVICE_ID, "a".into()),
-                    (&GROUP_ID, "group-0".into()),
+                    (&DEVICE_ID, "left".into()),
+                    (&FACING_MODE, FacingMode::left().into()),
                 ]),
                 MediaTrackSettings::from_iter([
-                    (&DEVICE_ID, "b".into()),
-                    (&GROUP_ID, "group-1".into()),
+                    (&DEVICE_ID, "environment".into()),
+                    (&FACING_MODE, FacingMode::environment().into()),
                 ]),
                 MediaTrackSettings::from_iter([
-                    (&DEVICE_ID, "c".into()),
-                    (&GROUP_ID, "group-2".into()),
+                    (&DEVICE_ID, "right".into()),
+                    (&FACING_MODE, FacingMode::right().into()),
                 ]),
             ];
 
             let actual = test_constrained(
                 &possible_settings,
                 ResolvedMandatoryMediaTrackConstraints::from_iter([(
-                    &GROUP_ID,
-                    ResolvedValueConstraint::default()
-                        .ideal("group-1".to_owned())
+                    &FACING_MODE,
+                    ResolvedValueSequenceConstraint::default()
+                        .ideal(vec![FacingMode::user(), FacingMode::environment()])
                         .into(),
                 )]),
                 ResolvedAdvancedMediaTrackConstraints::default(),
@@ -431,18 +733,26 @@ mod constrained {
         }
 
         #[test]
-        fn value_range() {
+        fn resize_mode_sequence() {
             let possible_settings = vec![
-                MediaTrackSettings::from_iter([(&DEVICE_ID, "a".into()), (&FRAME_RATE, 15.into())]),
-                MediaTrackSettings::from_iter([(&DEVICE_ID, "b".into()), (&FRAME_RATE, 30.into())]),
-                MediaTrackSettings::from_iter([(&DEVICE_ID, "c".into()), (&FRAME_RATE, 60.into())]),
+                MediaTrackSettings::from_iter([(&DEVICE_ID, "unknown".into())]),
+                MediaTrackSettings::from_iter([
+                    (&DEVICE_ID, "crop-and-scale".into()),
+                    (&RESIZE_MODE, ResizeMode::crop_and_scale().into()),
+                ]),
+                MediaTrackSettings::from_iter([
+                    (&DEVICE_ID, "none".into()),
+                    (&RESIZE_MODE, ResizeMode::none().into()),
+                ]),
             ];
 
             let actual = test_constrained(
-                &possible_settings,
+                &possible_settings[..2],
                 ResolvedMandatoryMediaTrackConstraints::from_iter([(
-                    &FRAME_RATE,
-                    ResolvedValueRangeConstraint::default().ideal(32).into(),
+                    &RESIZE_MODE,
+                    ResolvedValueSequenceConstraint::default()
+                        .ideal(vec![ResizeMode::none(), ResizeMode::crop_and_scale()])
+                        .into(),
                 )]),
                 ResolvedAdvancedMediaTrackConstraints::default(),
             );
@@ -450,10 +760,26 @@ mod constrained {
             let expected = vec![&possible_settings[1]];
 
             assert_eq!(actual, expected);
+
+            // Any value of the ideal sequence is a perfect fit:
+            let actual = test_constrained(
+                &possible_settings,
+                ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                    &RESIZE_MODE,
+                    ResolvedValueSequenceConstraint::default()
+                        .ideal(vec![ResizeMode::none(), ResizeMode::crop_and_scale()])
+                        .into(),
+                )]),
+                ResolvedAdvancedMediaTrackConstraints::default(),
+            );
+
+            let expected = vec![&possible_settings[1], &possible_settings[2]];
+
+            assert_eq!(actual, expected);
         }
 
         #[test]
-        fn value_sequence() {
+        fn value() {
             let possible_settings = vec![
                 MediaTrackSettings::from_iter([
                     (&DEVICE_ID, "a".into()),
@@ -473,8 +799,8 @@ mod constrained {
                 &possible_settings,
                 ResolvedMandatoryMediaTrackConstraints::from_iter([(
                     &GROUP_ID,
-                    ResolvedValueSequenceConstraint::default()
-                        .ideal(vec!["group-1".to_owned(), "group-3".to_owned()])
+                    ResolvedValueConstraint::default()
+                        .ideal("group-1".to_owned())
                         .into(),
                 )]),
                 ResolvedAdvancedMediaTrackConstraints::default(),
@@ -484,37 +810,2019 @@ mod constrained {
 
             assert_eq!(actual, expected);
         }
-    }
-}
 
-// ```
-//                        ┌
-// mandatory constraints: ┤   ┄───────────────────────────────────────────┤
-//                        └
-//                        ┌
-//  advanced constraints: ┤                    ├─┤         ├────────────────────────────┄
-//                        └
-//                        ┌
-//     possible settings: ┤   ●─────────────●──────────────●──────────────●─────────────●
-//                        └  480p          720p          1080p          1440p         2160p
-//                                                         └───────┬──────┘
-//     selected settings: ─────────────────────────────────────────┘
-// ```
-mod smoke {
-    use super::*;
-    use crate::{MediaTrackConstraintSet, ValueConstraint, ValueRangeConstraint};
+        #[test]
+        fn bare_bool_value() {
+            let possible_settings = vec![
+                MediaTrackSettings::from_iter([
+                    (&DEVICE_ID, "a".into()),
+                    (&NOISE_SUPPRESSION, false.into()),
+                ]),
+                MediaTrackSettings::from_iter([
+                    (&DEVICE_ID, "b".into()),
+                    (&NOISE_SUPPRESSION, true.into()),
+                ]),
+            ];
 
-    #[test]
-    fn native() {
-        let supported_constraints = MediaTrackSupportedConstraints::from_iter(vec![
-            &DEVICE_ID,
-            &HEIGHT,
-            &WIDTH,
-            &RESIZE_MODE,
-        ]);
+            // A bare `true` resolves to `{ ideal: true }`:
+            let mandatory =
+                MandatoryMediaTrackConstraints::from_iter([(&NOISE_SUPPRESSION, true.into())])
+                    .into_resolved();
 
-        let possible_settings = vec![
-            MediaTrackSettings::from_iter([
+            assert_eq!(
+                mandatory,
+                ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                    &NOISE_SUPPRESSION,
+                    ResolvedValueConstraint::default().ideal(true).into(),
+                )])
+            );
+
+            let actual = test_constrained(
+                &possible_settings,
+                mandatory,
+                ResolvedAdvancedMediaTrackConstraints::default(),
+            );
+
+            let expected = vec![&possible_settings[1]];
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn value_range() {
+            let possible_settings = vec![
+                MediaTrackSettings::from_iter([(&DEVICE_ID, "a".into()), (&FRAME_RATE, 15.into())]),
+                MediaTrackSettings::from_iter([(&DEVICE_ID, "b".into()), (&FRAME_RATE, 30.into())]),
+                MediaTrackSettings::from_iter([(&DEVICE_ID, "c".into()), (&FRAME_RATE, 60.into())]),
+            ];
+
+            let actual = test_constrained(
+                &possible_settings,
+                ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                    &FRAME_RATE,
+                    ResolvedValueRangeConstraint::default().ideal(32).into(),
+                )]),
+                ResolvedAdvancedMediaTrackConstraints::default(),
+            );
+
+            let expected = vec![&possible_settings[1]];
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn value_sequence() {
+            let possible_settings = vec![
+                MediaTrackSettings::from_iter([
+                    (&DEVICE_ID, "a".into()),
+                    (&GROUP_ID, "group-0".into()),
+                ]),
+                MediaTrackSettings::from_iter([
+                    (&DEVICE_ID, "b".into()),
+                    (&GROUP_ID, "group-1".into()),
+                ]),
+                MediaTrackSettings::from_iter([
+                    (&DEVICE_ID, "c".into()),
+                    (&GROUP_ID, "group-2".into()),
+                ]),
+            ];
+
+            let actual = test_constrained(
+                &possible_settings,
+                ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                    &GROUP_ID,
+                    ResolvedValueSequenceConstraint::default()
+                        .ideal(vec!["group-1".to_owned(), "group-3".to_owned()])
+                        .into(),
+                )]),
+                ResolvedAdvancedMediaTrackConstraints::default(),
+            );
+
+            let expected = vec![&possible_settings[1]];
+
+            assert_eq!(actual, expected);
+        }
+    }
+}
+
+mod unmet_ideals {
+    use super::*;
+
+    #[test]
+    fn ideal() {
+        let constraints = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([
+                (
+                    &WIDTH,
+                    ResolvedValueRangeConstraint::default()
+                        .min(2560)
+                        .ideal(2560)
+                        .into(),
+                ),
+                (
+                    &FRAME_RATE,
+                    ResolvedValueRangeConstraint::default().ideal(60.0).into(),
+                ),
+            ]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints());
+
+        let possible_settings = default_possible_settings();
+        let settings = select_settings(
+            &possible_settings,
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+            &FirstPolicy,
+        )
+        .unwrap();
+
+        assert_eq!(settings, &*VIDEO_1440P);
+
+        let actual = unmet_ideals(settings, &constraints);
+        let expected = vec![UnmetIdeal {
+            property: FRAME_RATE.clone(),
+            constraint: ResolvedValueRangeConstraint::default().ideal(60.0).into(),
+            setting: Some(30.into()),
+            fitness_distance: 0.5,
+        }];
+
+        assert_eq!(actual, expected);
+    }
+}
+
+mod ideal_coverage {
+    use super::*;
+
+    #[test]
+    fn unconstrained() {
+        let constraints = ResolvedMediaTrackConstraints::default()
+            .into_sanitized(&default_supported_constraints());
+
+        for settings in default_possible_settings() {
+            assert_eq!(ideal_coverage(&settings, &constraints), (0, 0));
+        }
+    }
+
+    #[test]
+    fn ideal() {
+        // Every setting of `VIDEO_IDEAL` as an ideal constraint:
+        let constraints = ResolvedMediaTrackConstraints {
+            mandatory: VIDEO_IDEAL
+                .iter()
+                .map(|(property, setting)| {
+                    (
+                        property.clone(),
+                        ResolvedMediaTrackConstraint::ideal_from(setting.clone()),
+                    )
+                })
+                .collect(),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints());
+
+        assert_eq!(ideal_coverage(&VIDEO_IDEAL, &constraints), (6, 6));
+        assert_eq!(ideal_coverage(&VIDEO_1080P, &constraints), (6, 6));
+        // Only `aspectRatio` and `facingMode` match:
+        assert_eq!(ideal_coverage(&VIDEO_720P, &constraints), (2, 6));
+
+        // A `frameRate` within tolerance of the ideal still counts as satisfied:
+        let mut settings = VIDEO_1080P.clone();
+        settings.insert(FRAME_RATE.clone(), 58.0.into());
+        assert_eq!(ideal_coverage(&settings, &constraints), (6, 6));
+
+        settings.insert(FRAME_RATE.clone(), 50.0.into());
+        assert_eq!(ideal_coverage(&settings, &constraints), (5, 6));
+
+        // A string setting has to match its ideal value exactly:
+        settings.insert(RESIZE_MODE.clone(), ResizeMode::crop_and_scale().into());
+        assert_eq!(ideal_coverage(&settings, &constraints), (4, 6));
+    }
+
+    #[test]
+    fn violated() {
+        let constraints = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([
+                (
+                    &WIDTH,
+                    ResolvedValueRangeConstraint::default()
+                        .min(1920)
+                        .ideal(1280)
+                        .into(),
+                ),
+                (
+                    &HEIGHT,
+                    ResolvedValueRangeConstraint::default().min(720).into(),
+                ),
+            ]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints());
+
+        // Constraints violated by the settings count as unsatisfied,
+        // constraints without an ideal value do not count at all:
+        assert_eq!(ideal_coverage(&VIDEO_720P, &constraints), (0, 1));
+    }
+}
+
+mod snap_ideal {
+    use super::*;
+
+    #[test]
+    fn frame_rate() {
+        let possible_settings: Vec<_> = [30.0, 60.0, 120.0]
+            .into_iter()
+            .map(|frame_rate| {
+                MediaTrackSettings::from_iter([
+                    (&DEVICE_ID, format!("{frame_rate}fps").into()),
+                    (&FRAME_RATE, frame_rate.into()),
+                    (&WIDTH, 1280.into()),
+                ])
+            })
+            .collect();
+
+        let constraints = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([
+                (
+                    &FRAME_RATE,
+                    ResolvedValueRangeConstraint::default().ideal(50.0).into(),
+                ),
+                (
+                    &WIDTH,
+                    ResolvedValueRangeConstraint::default().min(720).into(),
+                ),
+            ]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints());
+
+        let chosen = select_settings(
+            &possible_settings,
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+            &FirstPolicy,
+        )
+        .unwrap();
+
+        let actual = snap_ideal(&constraints, chosen);
+
+        // Only constraints with an ideal value get snapped:
+        let expected = MediaTrackSettings::from_iter([(&FRAME_RATE, 60.0.into())]);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+mod standalone_fitness_distance {
+    use super::*;
+
+    fn sanitized(
+        mandatory_constraints: ResolvedMandatoryMediaTrackConstraints,
+    ) -> SanitizedMediaTrackConstraints {
+        ResolvedMediaTrackConstraints {
+            mandatory: mandatory_constraints,
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints())
+    }
+
+    #[test]
+    fn too_small() {
+        let constraints = sanitized(ResolvedMandatoryMediaTrackConstraints::from_iter([(
+            FRAME_RATE.clone(),
+            ResolvedValueRangeConstraint::default().min(1000).into(),
+        )]));
+
+        let error = fitness_distance(&VIDEO_1080P, &constraints).unwrap_err();
+
+        assert_eq!(error.constraint, FRAME_RATE);
+        assert_eq!(
+            error.message.as_deref(),
+            Some("Setting was too small ([60] do not satisfy (1000 <= x)).")
+        );
+    }
+
+    #[test]
+    fn ideal() {
+        let constraints = sanitized(ResolvedMandatoryMediaTrackConstraints::from_iter([
+            (
+                &WIDTH,
+                ResolvedValueRangeConstraint::default().ideal(1920).into(),
+            ),
+            (
+                &FRAME_RATE,
+                ResolvedValueRangeConstraint::default().ideal(30).into(),
+            ),
+        ]));
+
+        let actual = fitness_distance(&VIDEO_1080P, &constraints).unwrap();
+
+        // width: 0.0, frame rate: |60 - 30| / 60
+        assert_eq!(actual, 0.5);
+    }
+}
+
+mod device_id_preference {
+    use super::*;
+    use crate::{MediaTrackConstraintSet, ValueConstraint};
+
+    fn prefer_device_ids(device_ids: &[&str]) -> SanitizedMediaTrackConstraints {
+        // Bare values within advanced constraint sets get resolved to exact constraints:
+        MediaTrackConstraints {
+            mandatory: MandatoryMediaTrackConstraints::default(),
+            advanced: AdvancedMediaTrackConstraints::from_iter(device_ids.iter().map(
+                |device_id| {
+                    MediaTrackConstraintSet::from_iter([(
+                        &DEVICE_ID,
+                        ValueConstraint::Bare(device_id.to_string()).into(),
+                    )])
+                },
+            )),
+        }
+        .into_resolved()
+        .into_sanitized(&default_supported_constraints())
+    }
+
+    #[test]
+    fn first_preference_absent() {
+        let possible_settings = default_possible_settings();
+
+        let constraints = prefer_device_ids(&["4320p", "720p"]);
+
+        let actual = select_settings(
+            &possible_settings,
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+            &FirstPolicy,
+        )
+        .unwrap();
+
+        assert_eq!(actual, &*VIDEO_720P);
+    }
+
+    #[test]
+    fn first_preference_present() {
+        let possible_settings = default_possible_settings();
+
+        // Once the first advanced set got applied, the second one matches no candidate
+        // and thus gets ignored:
+        let constraints = prefer_device_ids(&["1080p", "720p"]);
+
+        let actual = select_settings(
+            &possible_settings,
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+            &FirstPolicy,
+        )
+        .unwrap();
+
+        assert_eq!(actual, &*VIDEO_1080P);
+    }
+
+    #[test]
+    fn no_preference_present() {
+        let possible_settings = default_possible_settings();
+
+        let constraints = prefer_device_ids(&["4320p", "8640p"]);
+
+        let actual = select_settings(
+            &possible_settings,
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+            &FirstPolicy,
+        )
+        .unwrap();
+
+        assert_eq!(actual, &possible_settings[0]);
+    }
+}
+
+mod multi {
+    use super::*;
+
+    #[test]
+    fn same_group_id() {
+        let cameras = [
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, "built-in-camera".into()),
+                (&GROUP_ID, "built-in".into()),
+                (&WIDTH, 1920.into()),
+            ]),
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, "usb-camera".into()),
+                (&GROUP_ID, "usb".into()),
+                (&WIDTH, 1280.into()),
+            ]),
+        ];
+        let microphones = [
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, "usb-microphone".into()),
+                (&GROUP_ID, "usb".into()),
+            ]),
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, "built-in-microphone".into()),
+                (&GROUP_ID, "built-in".into()),
+            ]),
+        ];
+
+        let camera_constraints = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &WIDTH,
+                ResolvedValueRangeConstraint::default().ideal(1920).into(),
+            )]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints());
+        let microphone_constraints = SanitizedMediaTrackConstraints::default();
+
+        let streams = [
+            (&cameras[..], camera_constraints),
+            (&microphones[..], microphone_constraints),
+        ];
+
+        let actual = select_settings_multi(
+            &streams,
+            &JointConstraint::None,
+            DeviceInformationExposureMode::Exposed,
+        )
+        .unwrap();
+
+        assert_eq!(actual, vec![&cameras[0], &microphones[0]]);
+
+        let actual = select_settings_multi(
+            &streams,
+            &JointConstraint::SameValue(GROUP_ID.clone()),
+            DeviceInformationExposureMode::Exposed,
+        )
+        .unwrap();
+
+        assert_eq!(actual, vec![&cameras[0], &microphones[1]]);
+    }
+}
+
+mod best_effort {
+    use super::*;
+
+    #[test]
+    fn overconstrained() {
+        let possible_settings = default_possible_settings();
+
+        // No device is 8K-capable, so the widest one is the closest match:
+        let constraints = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &WIDTH,
+                ResolvedValueRangeConstraint::default().min(7680).into(),
+            )]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints());
+
+        let actual = select_settings_best_effort(&possible_settings, &constraints, &FirstPolicy);
+
+        assert_eq!(actual, Some(&*VIDEO_2160P));
+    }
+
+    #[test]
+    fn fewer_violations() {
+        let possible_settings = default_possible_settings();
+
+        // Only the 480p device satisfies the frame rate constraint,
+        // which outweighs it being farthest from satisfying the width constraint:
+        let constraints = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([
+                (
+                    &WIDTH,
+                    ResolvedValueRangeConstraint::default().min(7680).into(),
+                ),
+                (
+                    &FRAME_RATE,
+                    ResolvedValueRangeConstraint::default().min(240.0).into(),
+                ),
+            ]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints());
+
+        let actual = select_settings_best_effort(&possible_settings, &constraints, &FirstPolicy);
+
+        assert_eq!(actual, Some(&*VIDEO_480P));
+    }
+
+    #[test]
+    fn satisfiable() {
+        let possible_settings = default_possible_settings();
+
+        let constraints = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &WIDTH,
+                ResolvedValueRangeConstraint::default().ideal(1280).into(),
+            )]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints());
+
+        let actual = select_settings_best_effort(&possible_settings, &constraints, &FirstPolicy);
+
+        assert_eq!(actual, Some(&*VIDEO_720P));
+    }
+
+    #[test]
+    fn empty() {
+        let actual = select_settings_best_effort(
+            &[],
+            &SanitizedMediaTrackConstraints::default(),
+            &FirstPolicy,
+        );
+
+        assert_eq!(actual, None);
+    }
+}
+
+mod owned {
+    use super::*;
+
+    #[test]
+    fn lazily_enumerated() {
+        let constraints = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &WIDTH,
+                ResolvedValueRangeConstraint::default()
+                    .min(1280)
+                    .ideal(1920)
+                    .into(),
+            )]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints());
+
+        // Simulates devices being enumerated one by one:
+        let mut index = 0;
+        let possible_settings = std::iter::from_fn(|| {
+            let settings = default_possible_settings().get(index).cloned();
+            index += 1;
+            settings
+        });
+
+        let actual = select_settings_owned(
+            possible_settings,
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+            &FirstPolicy,
+        );
+
+        assert_eq!(actual, Ok(VIDEO_1080P.clone()));
+    }
+
+    #[test]
+    fn overconstrained() {
+        let constraints = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &WIDTH,
+                ResolvedValueRangeConstraint::default().min(7680).into(),
+            )]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints());
+
+        let actual = select_settings_owned(
+            default_possible_settings(),
+            &constraints,
+            DeviceInformationExposureMode::Protected,
+            &FirstPolicy,
+        );
+
+        assert_eq!(
+            actual,
+            Err(SelectSettingsError::Overconstrained(
+                OverconstrainedError::default()
+            ))
+        );
+    }
+}
+
+mod custom_properties {
+    use super::*;
+
+    fn possible_settings() -> Vec<MediaTrackSettings> {
+        [false, true]
+            .into_iter()
+            .map(|torch| {
+                let mut settings = VIDEO_1080P.clone();
+                settings.insert("torch".into(), torch.into());
+                settings
+            })
+            .collect()
+    }
+
+    fn constraints(
+        supported_constraints: &MediaTrackSupportedConstraints,
+    ) -> SanitizedMediaTrackConstraints {
+        ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                "torch",
+                ResolvedValueConstraint::default().exact(true).into(),
+            )]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(supported_constraints)
+    }
+
+    #[test]
+    fn supported() {
+        let possible_settings = possible_settings();
+        let mut supported_constraints = default_supported_constraints();
+        supported_constraints.insert("torch".into());
+
+        let actual = select_settings(
+            &possible_settings,
+            &constraints(&supported_constraints),
+            DeviceInformationExposureMode::Exposed,
+            &FirstPolicy,
+        );
+
+        assert_eq!(actual, Ok(&possible_settings[1]));
+    }
+
+    #[test]
+    fn unsupported() {
+        let possible_settings = possible_settings();
+
+        let actual = select_settings(
+            &possible_settings,
+            &constraints(&default_supported_constraints()),
+            DeviceInformationExposureMode::Exposed,
+            &FirstPolicy,
+        );
+
+        // Unsupported properties get sanitized away, leaving the candidates unconstrained:
+        assert_eq!(actual, Ok(&possible_settings[0]));
+    }
+}
+
+mod derived_aspect_ratio {
+    use super::*;
+
+    #[test]
+    fn selectable_after_derivation() {
+        let possible_settings = vec![MediaTrackSettings::from_iter([
+            (&DEVICE_ID, "1080p".into()),
+            (&WIDTH, 1920.into()),
+            (&HEIGHT, 1080.into()),
+        ])];
+
+        let constraints = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &ASPECT_RATIO,
+                ResolvedValueRangeConstraint::default().min(1.7).into(),
+            )]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints());
+
+        let result = select_settings_candidates(
+            &possible_settings,
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+        );
+
+        assert!(result.is_err());
+
+        let derived_settings: Vec<_> = possible_settings
+            .iter()
+            .map(MediaTrackSettings::with_derived_aspect_ratio)
+            .collect();
+
+        let actual = select_settings_candidates(
+            &derived_settings,
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+        )
+        .unwrap();
+
+        assert_eq!(actual, vec![&derived_settings[0]]);
+    }
+}
+
+mod mixed_numeric {
+    use super::*;
+
+    // Virtual cameras may report fractional (e.g. DPI-scaled) dimensions:
+    fn mixed_possible_settings() -> Vec<MediaTrackSettings> {
+        vec![
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, "integer".into()),
+                (&WIDTH, 1920.into()),
+                (&HEIGHT, 1080.into()),
+            ]),
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, "scaled".into()),
+                (&WIDTH, 2559.5.into()),
+                (&HEIGHT, 1439.5.into()),
+            ]),
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, "float".into()),
+                (&WIDTH, 1280.0.into()),
+                (&HEIGHT, 720.0.into()),
+            ]),
+        ]
+    }
+
+    #[test]
+    fn float_constraints() {
+        let possible_settings = mixed_possible_settings();
+
+        let actual = test_constrained(
+            &possible_settings,
+            ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &WIDTH,
+                ResolvedValueRangeConstraint::default()
+                    .max(1920.0)
+                    .ideal(1920.0)
+                    .into(),
+            )]),
+            ResolvedAdvancedMediaTrackConstraints::default(),
+        );
+
+        assert_eq!(actual, vec![&possible_settings[0]]);
+
+        let actual = test_constrained(
+            &possible_settings,
+            ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &HEIGHT,
+                ResolvedValueRangeConstraint::default().exact(720.0).into(),
+            )]),
+            ResolvedAdvancedMediaTrackConstraints::default(),
+        );
+
+        assert_eq!(actual, vec![&possible_settings[2]]);
+    }
+
+    #[test]
+    fn integer_constraints() {
+        let possible_settings = mixed_possible_settings();
+
+        let actual = test_constrained(
+            &possible_settings,
+            ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &WIDTH,
+                ResolvedValueRangeConstraint::default()
+                    .min(1280)
+                    .max(1920)
+                    .into(),
+            )]),
+            ResolvedAdvancedMediaTrackConstraints::default(),
+        );
+
+        // Both candidates satisfy the range, hence tie in the absence of an ideal value:
+        assert_eq!(actual, vec![&possible_settings[0], &possible_settings[2]]);
+
+        let actual = test_constrained(
+            &possible_settings,
+            ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &WIDTH,
+                ResolvedValueRangeConstraint::default().ideal(2560).into(),
+            )]),
+            ResolvedAdvancedMediaTrackConstraints::default(),
+        );
+
+        assert_eq!(actual, vec![&possible_settings[1]]);
+    }
+}
+
+mod channel_count {
+    use super::*;
+
+    fn audio_possible_settings(channel_counts: &[i64]) -> Vec<MediaTrackSettings> {
+        channel_counts
+            .iter()
+            .map(|&channel_count| {
+                MediaTrackSettings::from_iter([
+                    (&DEVICE_ID, format!("{channel_count}ch").into()),
+                    (&CHANNEL_COUNT, channel_count.into()),
+                ])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn exact() {
+        let possible_settings = audio_possible_settings(&[2, 1, 6]);
+
+        for (channel_count, expected) in [(1, 1), (2, 0), (6, 2)] {
+            let actual = test_constrained(
+                &possible_settings,
+                ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                    &CHANNEL_COUNT,
+                    ResolvedValueRangeConstraint::default()
+                        .exact(channel_count)
+                        .into(),
+                )]),
+                ResolvedAdvancedMediaTrackConstraints::default(),
+            );
+
+            assert_eq!(actual, vec![&possible_settings[expected]]);
+        }
+    }
+
+    #[test]
+    fn range() {
+        let possible_settings = audio_possible_settings(&[1, 2, 6]);
+
+        let actual = test_constrained(
+            &possible_settings,
+            ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &CHANNEL_COUNT,
+                ResolvedValueRangeConstraint::default().min(2).into(),
+            )]),
+            ResolvedAdvancedMediaTrackConstraints::default(),
+        );
+
+        assert_eq!(actual, vec![&possible_settings[1], &possible_settings[2]]);
+
+        let actual = test_constrained(
+            &possible_settings,
+            ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &CHANNEL_COUNT,
+                ResolvedValueRangeConstraint::default()
+                    .min(2)
+                    .ideal(2)
+                    .into(),
+            )]),
+            ResolvedAdvancedMediaTrackConstraints::default(),
+        );
+
+        assert_eq!(actual, vec![&possible_settings[1]]);
+
+        let actual = test_constrained(
+            &possible_settings,
+            ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &CHANNEL_COUNT,
+                ResolvedValueRangeConstraint::default().max(1).into(),
+            )]),
+            ResolvedAdvancedMediaTrackConstraints::default(),
+        );
+
+        assert_eq!(actual, vec![&possible_settings[0]]);
+    }
+
+    #[test]
+    fn overconstrained() {
+        let possible_settings = audio_possible_settings(&[1, 1]);
+
+        for (constraint, expected_message) in [
+            (
+                ResolvedValueRangeConstraint::default().exact(2),
+                "Setting was a mismatch ([1] do not satisfy (x == 2)).",
+            ),
+            (
+                ResolvedValueRangeConstraint::default().min(2),
+                "Setting was too small ([1] do not satisfy (2 <= x)).",
+            ),
+        ] {
+            let error = test_overconstrained(
+                &possible_settings,
+                ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                    &CHANNEL_COUNT,
+                    constraint.into(),
+                )]),
+                DeviceInformationExposureMode::Exposed,
+            );
+
+            assert_eq!(error.constraint, CHANNEL_COUNT);
+            assert_eq!(error.message.as_deref(), Some(expected_message));
+        }
+    }
+}
+
+mod facing_mode {
+    use super::*;
+
+    #[test]
+    fn exact_left() {
+        let possible_settings: Vec<_> = [
+            FacingMode::user(),
+            FacingMode::environment(),
+            FacingMode::left(),
+            FacingMode::right(),
+        ]
+        .into_iter()
+        .map(|facing_mode| {
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, format!("{facing_mode}-camera").into()),
+                (&FACING_MODE, facing_mode.into()),
+            ])
+        })
+        .collect();
+
+        for (facing_mode, expected) in [
+            (FacingMode::user(), 0),
+            (FacingMode::environment(), 1),
+            (FacingMode::left(), 2),
+            (FacingMode::right(), 3),
+        ] {
+            let actual = test_constrained(
+                &possible_settings,
+                ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                    &FACING_MODE,
+                    ResolvedValueConstraint::default().exact(facing_mode).into(),
+                )]),
+                ResolvedAdvancedMediaTrackConstraints::default(),
+            );
+
+            assert_eq!(actual, vec![&possible_settings[expected]]);
+        }
+
+        let actual = test_constrained(
+            &possible_settings,
+            ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &FACING_MODE,
+                ResolvedValueSequenceConstraint::default()
+                    .exact(vec![FacingMode::left(), FacingMode::right()])
+                    .into(),
+            )]),
+            ResolvedAdvancedMediaTrackConstraints::default(),
+        );
+
+        assert_eq!(actual, vec![&possible_settings[2], &possible_settings[3]]);
+    }
+}
+
+mod screen_capture {
+    use super::*;
+
+    fn display_settings() -> Vec<MediaTrackSettings> {
+        DisplaySurface::all()
+            .into_iter()
+            .map(|display_surface| {
+                MediaTrackSettings::from_iter([
+                    (&DEVICE_ID, format!("{display_surface}-capture").into()),
+                    (&DISPLAY_SURFACE, display_surface.to_string().into()),
+                    (&LOGICAL_SURFACE, true.into()),
+                    (&CURSOR, CursorCapture::motion().into()),
+                    (&SUPPRESS_LOCAL_AUDIO_PLAYBACK, false.into()),
+                ])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn exact_monitor() {
+        let possible_settings = display_settings();
+
+        let actual = test_constrained(
+            &possible_settings,
+            ResolvedMandatoryMediaTrackConstraints::from_iter([
+                (
+                    &DISPLAY_SURFACE,
+                    ResolvedValueConstraint::default()
+                        .exact(DisplaySurface::monitor())
+                        .into(),
+                ),
+                (
+                    &CURSOR,
+                    ResolvedValueConstraint::default()
+                        .ideal(CursorCapture::always())
+                        .into(),
+                ),
+                (
+                    &SUPPRESS_LOCAL_AUDIO_PLAYBACK,
+                    ResolvedValueConstraint::default().ideal(false).into(),
+                ),
+            ]),
+            ResolvedAdvancedMediaTrackConstraints::default(),
+        );
+
+        assert_eq!(actual, vec![&possible_settings[0]]);
+    }
+
+    #[test]
+    fn overconstrained() {
+        let possible_settings = display_settings();
+
+        let error = test_overconstrained(
+            &possible_settings,
+            ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &LOGICAL_SURFACE,
+                ResolvedValueConstraint::default().exact(false).into(),
+            )]),
+            DeviceInformationExposureMode::Exposed,
+        );
+
+        assert_eq!(error.constraint, LOGICAL_SURFACE);
+    }
+}
+
+mod invariants {
+    use quickcheck::{QuickCheck, TestResult};
+
+    use crate::algorithms::FitnessDistance;
+
+    use super::*;
+
+    fn all_supported_constraints() -> MediaTrackSupportedConstraints {
+        MediaTrackSupportedConstraints::from_iter(all_properties().into_iter().cloned())
+    }
+
+    /// Selects from `possible_settings`, checking that the selected settings
+    /// are among the possible settings and satisfy every mandatory constraint.
+    fn select_satisfies_mandatory(
+        possible_settings: Vec<MediaTrackSettings>,
+        constraints: MediaTrackConstraints,
+    ) -> TestResult {
+        let constraints = constraints
+            .to_resolved()
+            .to_sanitized(&all_supported_constraints());
+
+        let result = select_settings(
+            possible_settings.iter(),
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+            &FirstPolicy,
+        );
+
+        match result {
+            Ok(selected) => {
+                if !possible_settings
+                    .iter()
+                    .any(|settings| settings == selected)
+                {
+                    return TestResult::error(format!(
+                        "Selected settings {selected:?} are not among the possible settings."
+                    ));
+                }
+                for (property, constraint) in constraints.mandatory.iter() {
+                    if let Err(error) = constraint.fitness_distance(selected.get(property)) {
+                        return TestResult::error(format!(
+                            "Selected settings {selected:?} do not satisfy {property}: {error:?}"
+                        ));
+                    }
+                }
+                TestResult::passed()
+            }
+            Err(SelectSettingsError::NoCandidates) => {
+                TestResult::from_bool(possible_settings.is_empty())
+            }
+            Err(SelectSettingsError::Overconstrained(_)) => {
+                TestResult::from_bool(!possible_settings.is_empty())
+            }
+        }
+    }
+
+    #[test]
+    fn selected_settings_satisfy_mandatory_constraints() {
+        QuickCheck::new().tests(500).quickcheck(
+            select_satisfies_mandatory as fn(Vec<MediaTrackSettings>, MediaTrackConstraints) -> _,
+        );
+    }
+
+    // Regression: an inverted range (`min > max`) must overconstrain,
+    // rather than select settings that satisfy neither of its bounds.
+    #[test]
+    fn inverted_range() {
+        let possible_settings = [VIDEO_480P.clone(), VIDEO_720P.clone()];
+        let constraints = MediaTrackConstraints {
+            mandatory: MandatoryMediaTrackConstraints::from_iter([(
+                &WIDTH,
+                ResolvedValueRangeConstraint::default()
+                    .min(1280_u64)
+                    .max(720_u64)
+                    .into(),
+            )]),
+            advanced: AdvancedMediaTrackConstraints::default(),
+        };
+
+        let constraints = constraints
+            .to_resolved()
+            .to_sanitized(&all_supported_constraints());
+        let result = select_settings(
+            possible_settings.iter(),
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+            &FirstPolicy,
+        );
+        assert!(matches!(
+            result,
+            Err(SelectSettingsError::Overconstrained(_))
+        ));
+    }
+}
+
+mod ideal_tolerance {
+    use super::*;
+
+    fn frame_rate_settings() -> [MediaTrackSettings; 2] {
+        [
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, "57fps".into()),
+                (&FRAME_RATE, 57.0.into()),
+            ]),
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, "60fps".into()),
+                (&FRAME_RATE, 60.0.into()),
+            ]),
+        ]
+    }
+
+    fn ideal_frame_rate() -> SanitizedMediaTrackConstraints {
+        ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &FRAME_RATE,
+                ResolvedValueRangeConstraint::default().ideal(60.0).into(),
+            )]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints())
+    }
+
+    #[test]
+    fn without_tolerance() {
+        let possible_settings = frame_rate_settings();
+
+        let actual = select_settings_scored_with_tolerance(
+            &possible_settings[..],
+            &ideal_frame_rate(),
+            DeviceInformationExposureMode::Exposed,
+            &IdealTolerance::default(),
+        )
+        .unwrap();
+
+        let expected = vec![
+            (&possible_settings[1], 0.0),
+            (&possible_settings[0], 3.0 / 60.0),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn within_tolerance() {
+        let possible_settings = frame_rate_settings();
+
+        let actual = select_settings_scored_with_tolerance(
+            &possible_settings[..],
+            &ideal_frame_rate(),
+            DeviceInformationExposureMode::Exposed,
+            &IdealTolerance::new().with(&FRAME_RATE, 3.0),
+        )
+        .unwrap();
+
+        // Both candidates are within tolerance, hence tie (retaining their order):
+        let expected = vec![(&possible_settings[0], 0.0), (&possible_settings[1], 0.0)];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn outside_tolerance() {
+        let possible_settings = frame_rate_settings();
+
+        let actual = select_settings_scored_with_tolerance(
+            &possible_settings[..],
+            &ideal_frame_rate(),
+            DeviceInformationExposureMode::Exposed,
+            &IdealTolerance::new().with(&FRAME_RATE, 2.0),
+        )
+        .unwrap();
+
+        let expected = vec![
+            (&possible_settings[1], 0.0),
+            (&possible_settings[0], 3.0 / 60.0),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn other_property() {
+        let possible_settings = frame_rate_settings();
+
+        let actual = select_settings_scored_with_tolerance(
+            &possible_settings[..],
+            &ideal_frame_rate(),
+            DeviceInformationExposureMode::Exposed,
+            &IdealTolerance::new().with(&WIDTH, 3.0),
+        )
+        .unwrap();
+
+        let expected = vec![
+            (&possible_settings[1], 0.0),
+            (&possible_settings[0], 3.0 / 60.0),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn required_values_remain_strict() {
+        let possible_settings = frame_rate_settings();
+
+        let constraints = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &FRAME_RATE,
+                ResolvedValueRangeConstraint::default()
+                    .min(58.0)
+                    .ideal(60.0)
+                    .into(),
+            )]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints());
+
+        let actual = select_settings_scored_with_tolerance(
+            &possible_settings[..],
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+            &IdealTolerance::new().with(&FRAME_RATE, 3.0),
+        )
+        .unwrap();
+
+        let expected = vec![(&possible_settings[1], 0.0)];
+
+        assert_eq!(actual, expected);
+    }
+}
+
+mod non_finite {
+    use super::*;
+
+    fn aspect_ratio_settings() -> [MediaTrackSettings; 3] {
+        [
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, "NaN".into()),
+                (&ASPECT_RATIO, f64::NAN.into()),
+            ]),
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, "4:3".into()),
+                (&ASPECT_RATIO, (4.0 / 3.0).into()),
+            ]),
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, "16:9".into()),
+                (&ASPECT_RATIO, (16.0 / 9.0).into()),
+            ]),
+        ]
+    }
+
+    #[test]
+    fn nan_ranked_last_for_ideal() {
+        let possible_settings = aspect_ratio_settings();
+
+        let constraints = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &ASPECT_RATIO,
+                ResolvedValueRangeConstraint::default()
+                    .ideal(16.0 / 9.0)
+                    .into(),
+            )]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints());
+
+        let actual = select_settings_scored(
+            &possible_settings[..],
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+        )
+        .unwrap();
+
+        // Settings containing `NaN` never compare equal, hence comparing device IDs instead:
+        let actual: Vec<_> = actual
+            .into_iter()
+            .map(|(settings, fitness_distance)| {
+                (settings.get(&DEVICE_ID).cloned(), fitness_distance)
+            })
+            .collect();
+        let expected = vec![
+            (Some("16:9".into()), 0.0),
+            (
+                Some("4:3".into()),
+                crate::algorithms::fitness_distance::relative_fitness_distance(
+                    4.0 / 3.0,
+                    16.0 / 9.0,
+                ),
+            ),
+            (Some("NaN".into()), 1.0),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn nan_fails_range() {
+        let possible_settings = aspect_ratio_settings();
+
+        // A `NaN` compares neither less, nor greater than any bound:
+        let constraints = [
+            ResolvedValueRangeConstraint::default().min(1.5),
+            ResolvedValueRangeConstraint::default().max(1.5),
+        ];
+
+        for constraint in constraints {
+            let actual = test_constrained(
+                &possible_settings,
+                ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                    &ASPECT_RATIO,
+                    constraint.into(),
+                )]),
+                ResolvedAdvancedMediaTrackConstraints::default(),
+            );
+
+            let device_ids: Vec<_> = actual
+                .into_iter()
+                .map(|settings| settings.get(&DEVICE_ID).cloned())
+                .collect();
+            assert!(!device_ids.contains(&Some("NaN".into())));
+            assert_eq!(device_ids.len(), 1);
+        }
+    }
+
+    #[test]
+    fn infinity_fails_range() {
+        let possible_settings = [MediaTrackSettings::from_iter([
+            (&DEVICE_ID, "infinite".into()),
+            (&FRAME_RATE, f64::INFINITY.into()),
+        ])];
+
+        let error = test_overconstrained(
+            &possible_settings,
+            ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &FRAME_RATE,
+                ResolvedValueRangeConstraint::default().min(30.0).into(),
+            )]),
+            DeviceInformationExposureMode::Exposed,
+        );
+
+        assert_eq!(error.constraint, FRAME_RATE);
+    }
+}
+
+mod advanced_then_ideal {
+    use super::*;
+
+    // Restricting candidates to the cropped/scaled ones (i.e. 480p & 720p):
+    fn crop_and_scale() -> ResolvedAdvancedMediaTrackConstraints {
+        ResolvedAdvancedMediaTrackConstraints::from_iter([
+            ResolvedMediaTrackConstraintSet::from_iter([(
+                &RESIZE_MODE,
+                ResolvedValueConstraint::default()
+                    .exact(ResizeMode::crop_and_scale())
+                    .into(),
+            )]),
+        ])
+    }
+
+    #[test]
+    fn mandatory_ideal() {
+        let possible_settings = default_possible_settings();
+
+        // On its own the ideal width would pick 1080p:
+        let mandatory_constraints = ResolvedMandatoryMediaTrackConstraints::from_iter([(
+            &WIDTH,
+            ResolvedValueRangeConstraint::default().ideal(1920).into(),
+        )]);
+
+        let actual = test_constrained(
+            &possible_settings,
+            mandatory_constraints.clone(),
+            ResolvedAdvancedMediaTrackConstraints::default(),
+        );
+
+        assert_eq!(actual, vec![&*VIDEO_1080P]);
+
+        // The advanced set gets applied first, leaving the ideal width
+        // to pick among the surviving candidates:
+        let constraints = ResolvedMediaTrackConstraints {
+            mandatory: mandatory_constraints,
+            advanced: crop_and_scale(),
+        }
+        .to_sanitized(&default_supported_constraints());
+
+        let actual = select_settings_scored(
+            &possible_settings,
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+        )
+        .unwrap();
+
+        let expected = vec![
+            (&possible_settings[1], 640.0 / 1920.0),
+            (&possible_settings[0], 1200.0 / 1920.0),
+        ];
+
+        assert_eq!(actual, expected);
+
+        let actual = select_settings(
+            &possible_settings,
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+            &FirstPolicy,
+        )
+        .unwrap();
+
+        assert_eq!(actual, &*VIDEO_720P);
+    }
+
+    #[test]
+    fn tie_breaking_policy() {
+        let possible_settings = default_possible_settings();
+
+        let policy =
+            ClosestToIdealPolicy::new(VIDEO_IDEAL.clone(), &default_supported_constraints());
+
+        let select = |advanced_constraints: ResolvedAdvancedMediaTrackConstraints| {
+            let constraints = ResolvedMediaTrackConstraints {
+                mandatory: ResolvedMandatoryMediaTrackConstraints::default(),
+                advanced: advanced_constraints,
+            }
+            .to_sanitized(&default_supported_constraints());
+
+            select_settings(
+                &possible_settings,
+                &constraints,
+                DeviceInformationExposureMode::Exposed,
+                &policy,
+            )
+            .unwrap()
+        };
+
+        // On its own the policy would pick 1080p:
+        assert_eq!(
+            select(ResolvedAdvancedMediaTrackConstraints::default()),
+            &*VIDEO_1080P
+        );
+
+        // With the advanced set applied first the policy only gets to pick
+        // among the surviving candidates, of which 720p is closest to the ideal:
+        assert_eq!(select(crop_and_scale()), &*VIDEO_720P);
+    }
+}
+
+mod first_match {
+    use super::*;
+
+    #[test]
+    fn short_circuits() {
+        let possible_settings = default_possible_settings();
+
+        let constraints = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &WIDTH,
+                ResolvedValueRangeConstraint::default().min(1280).into(),
+            )]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints());
+
+        // Consuming the possible settings past the first match (i.e. 720p) fails the test:
+        let possible_settings_iter =
+            possible_settings
+                .iter()
+                .enumerate()
+                .map(|(index, settings)| {
+                    assert!(
+                        index <= 1,
+                        "Iterator should not have been consumed any further."
+                    );
+                    settings
+                });
+
+        let actual = select_settings_first_match(
+            possible_settings_iter,
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+        )
+        .unwrap();
+
+        assert_eq!(actual, &possible_settings[1]);
+    }
+
+    #[test]
+    fn overconstrained() {
+        let constraints = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &WIDTH,
+                ResolvedValueRangeConstraint::default().min(5000).into(),
+            )]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints());
+
+        let possible_settings = default_possible_settings();
+
+        let actual = select_settings_first_match(
+            &possible_settings,
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+        );
+
+        match actual.unwrap_err() {
+            SelectSettingsError::Overconstrained(error) => assert_eq!(error.constraint, WIDTH),
+            error => panic!("Expected overconstrained error, found {error:?}"),
+        }
+    }
+}
+
+mod scored {
+    use super::*;
+
+    #[test]
+    fn ideal_width() {
+        let possible_settings = default_possible_settings();
+
+        let constraints = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &WIDTH,
+                ResolvedValueRangeConstraint::default().ideal(1920).into(),
+            )]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints());
+
+        let actual = select_settings_scored(
+            &possible_settings[..],
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+        )
+        .unwrap();
+
+        let expected = vec![
+            (&*VIDEO_1080P, 0.0),
+            (&*VIDEO_1440P, 640.0 / 2560.0),
+            (&*VIDEO_720P, 640.0 / 1920.0),
+            (&*VIDEO_2160P, 1920.0 / 3840.0),
+            (&*VIDEO_480P, 1200.0 / 1920.0),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn ideal_aspect_ratio() {
+        let possible_settings = [
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, "4:3".into()),
+                (&ASPECT_RATIO, (4.0 / 3.0).into()),
+            ]),
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, "16:9".into()),
+                (&ASPECT_RATIO, (16.0 / 9.0).into()),
+            ]),
+        ];
+
+        let constraints = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &ASPECT_RATIO,
+                ResolvedValueRangeConstraint::default().ideal(1.7777).into(),
+            )]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints());
+
+        let actual = select_settings_scored(
+            &possible_settings[..],
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+        )
+        .unwrap();
+
+        // Distances are relative (i.e. `|actual - ideal| / max(|actual|, |ideal|)`),
+        // rather than the raw difference between actual and ideal value:
+        let expected = vec![
+            (
+                &possible_settings[1],
+                ((16.0 / 9.0) - 1.7777) / (16.0 / 9.0),
+            ),
+            (&possible_settings[0], (1.7777 - (4.0 / 3.0)) / 1.7777),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn ideal_group_id() {
+        let possible_settings = [
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, "a".into()),
+                (&GROUP_ID, "group-a".into()),
+            ]),
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, "b".into()),
+                (&GROUP_ID, "group-b".into()),
+            ]),
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, "c".into()),
+                (&GROUP_ID, "group-abc".into()),
+            ]),
+        ];
+
+        let constraints = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &GROUP_ID,
+                ResolvedValueConstraint::default()
+                    .ideal("group-b".to_owned())
+                    .into(),
+            )]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints());
+
+        let actual = select_settings_scored(
+            &possible_settings[..],
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+        )
+        .unwrap();
+
+        // String distances are binary (i.e. `(actual == ideal) ? 0 : 1`),
+        // regardless of how similar a mismatching value is to the ideal value:
+        let expected = vec![
+            (&possible_settings[1], 0.0),
+            (&possible_settings[0], 1.0),
+            (&possible_settings[2], 1.0),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
+
+mod top_n {
+    use super::*;
+
+    #[test]
+    fn ideal_width() {
+        let possible_settings = default_possible_settings();
+
+        let constraints = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &WIDTH,
+                ResolvedValueRangeConstraint::default().ideal(1920).into(),
+            )]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints());
+
+        let actual = select_settings_top_n(
+            &possible_settings[..],
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+            &FirstPolicy,
+            2,
+        )
+        .unwrap();
+
+        let expected = vec![&*VIDEO_1080P, &*VIDEO_1440P];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn fewer_candidates_than_requested() {
+        let possible_settings = default_possible_settings();
+
+        let constraints = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &WIDTH,
+                ResolvedValueRangeConstraint::default().min(2000).into(),
+            )]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints());
+
+        let actual = select_settings_top_n(
+            &possible_settings[..],
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+            &FirstPolicy,
+            10,
+        )
+        .unwrap();
+
+        let expected = vec![&*VIDEO_1440P, &*VIDEO_2160P];
+
+        assert_eq!(actual, expected);
+    }
+}
+
+mod incremental {
+    use super::*;
+
+    fn constraints_with_frame_rate(
+        frame_rate: ResolvedValueRangeConstraint<f64>,
+    ) -> SanitizedMediaTrackConstraints {
+        ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([
+                (
+                    &WIDTH,
+                    ResolvedValueRangeConstraint::default().min(1200).into(),
+                ),
+                (&FRAME_RATE, frame_rate.into()),
+            ]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints())
+    }
+
+    #[test]
+    fn frame_rate_changes() {
+        let possible_settings = default_possible_settings();
+        let mut selector = IncrementalSelector::new(possible_settings.clone());
+
+        for (frame_rate, expected) in [
+            (120.0, &*VIDEO_720P),
+            (30.0, &*VIDEO_1440P),
+            (55.0, &*VIDEO_1080P),
+        ] {
+            let constraints = constraints_with_frame_rate(
+                ResolvedValueRangeConstraint::default().ideal(frame_rate),
+            );
+
+            let actual = selector
+                .update(
+                    &constraints,
+                    DeviceInformationExposureMode::Exposed,
+                    &FirstPolicy,
+                )
+                .unwrap();
+
+            assert_eq!(actual, expected);
+
+            let non_incremental = select_settings(
+                &possible_settings[..],
+                &constraints,
+                DeviceInformationExposureMode::Exposed,
+                &FirstPolicy,
+            )
+            .unwrap();
+
+            assert_eq!(actual, non_incremental);
+        }
+    }
+
+    #[test]
+    fn overconstrained() {
+        let mut selector = IncrementalSelector::new(default_possible_settings());
+
+        let constraints =
+            constraints_with_frame_rate(ResolvedValueRangeConstraint::default().ideal(60.0));
+        let actual = selector.update(
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+            &FirstPolicy,
+        );
+        assert_eq!(actual, Ok(&*VIDEO_1080P));
+
+        let constraints =
+            constraints_with_frame_rate(ResolvedValueRangeConstraint::default().min(300.0));
+        let actual = selector.update(
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+            &FirstPolicy,
+        );
+        let error = match actual {
+            Err(SelectSettingsError::Overconstrained(error)) => error,
+            result => panic!("Expected overconstrained error, found {result:?}"),
+        };
+        assert_eq!(
+            error.constraint,
+            crate::MediaTrackProperty::from(&FRAME_RATE)
+        );
+    }
+
+    #[test]
+    fn no_candidates() {
+        let mut selector = IncrementalSelector::new(vec![]);
+
+        let constraints =
+            constraints_with_frame_rate(ResolvedValueRangeConstraint::default().ideal(60.0));
+        let actual = selector.update(
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+            &FirstPolicy,
+        );
+
+        assert_eq!(actual, Err(SelectSettingsError::NoCandidates));
+    }
+}
+
+mod explained {
+    use super::*;
+
+    #[test]
+    fn overconstrained() {
+        let possible_settings = vec![
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, "a".into()),
+                (&WIDTH, 640.into()),
+                (&FRAME_RATE, 30.into()),
+                (&RESIZE_MODE, ResizeMode::none().into()),
+            ]),
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, "b".into()),
+                (&WIDTH, 1920.into()),
+                (&FRAME_RATE, 15.into()),
+                (&RESIZE_MODE, ResizeMode::none().into()),
+            ]),
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, "c".into()),
+                (&WIDTH, 1920.into()),
+                (&FRAME_RATE, 30.into()),
+                (&RESIZE_MODE, ResizeMode::crop_and_scale().into()),
+            ]),
+        ];
+
+        let constraints = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([
+                (
+                    &WIDTH,
+                    ResolvedValueRangeConstraint::default().min(1280).into(),
+                ),
+                (
+                    &FRAME_RATE,
+                    ResolvedValueRangeConstraint::default().min(24).into(),
+                ),
+                (
+                    &RESIZE_MODE,
+                    ResolvedValueConstraint::default()
+                        .exact(ResizeMode::none())
+                        .into(),
+                ),
+            ]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints());
+
+        let actual = select_settings_explained(
+            &possible_settings,
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+        )
+        .unwrap_err();
+
+        let actual = match actual {
+            ExplainedSelectSettingsError::Overconstrained(errors) => errors,
+            error => panic!("Expected overconstrained error, found {error:?}"),
+        };
+
+        let actual: Vec<_> = actual
+            .into_iter()
+            .map(|(index, error)| (index, error.constraint, error.message.unwrap()))
+            .collect();
+
+        let expected = vec![
+            (
+                0,
+                WIDTH.clone(),
+                "Setting was too small ([640] do not satisfy (1280 <= x)).".to_owned(),
+            ),
+            (
+                1,
+                FRAME_RATE.clone(),
+                "Setting was too small ([15] do not satisfy (24 <= x)).".to_owned(),
+            ),
+            (
+                2,
+                RESIZE_MODE.clone(),
+                "Setting was a mismatch ([\"crop-and-scale\"] do not satisfy (x == \"none\"))."
+                    .to_owned(),
+            ),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn no_candidates() {
+        let constraints =
+            ResolvedMediaTrackConstraints::default().to_sanitized(&default_supported_constraints());
+
+        let actual =
+            select_settings_explained(&[], &constraints, DeviceInformationExposureMode::Exposed);
+
+        assert_eq!(actual, Err(ExplainedSelectSettingsError::NoCandidates));
+    }
+}
+
+mod tie_breaking {
+    use super::*;
+
+    #[test]
+    fn tied_candidates() {
+        let possible_settings = vec![
+            MediaTrackSettings::from_iter([(&DEVICE_ID, "a".into()), (&WIDTH, 1280.into())]),
+            MediaTrackSettings::from_iter([(&DEVICE_ID, "b".into()), (&WIDTH, 1280.into())]),
+            MediaTrackSettings::from_iter([(&DEVICE_ID, "c".into()), (&WIDTH, 720.into())]),
+        ];
+
+        let constraints = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &WIDTH,
+                ResolvedValueRangeConstraint::default().ideal(1920).into(),
+            )]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        }
+        .to_sanitized(&default_supported_constraints());
+
+        // Only the two equally fit candidates are subject to tie-breaking:
+        let candidates = select_settings_candidates(
+            &possible_settings,
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+        )
+        .unwrap();
+
+        assert_eq!(
+            candidates,
+            vec![&possible_settings[0], &possible_settings[1]]
+        );
+
+        let actual = select_settings(
+            &possible_settings,
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+            &FirstPolicy,
+        )
+        .unwrap();
+
+        assert_eq!(actual, &possible_settings[0]);
+
+        let ideal_settings = |device_id: &str| {
+            ClosestToIdealPolicy::new(
+                MediaTrackSettings::from_iter([(&DEVICE_ID, device_id.into())]),
+                &default_supported_constraints(),
+            )
+        };
+
+        let actual = select_settings(
+            &possible_settings,
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+            &ideal_settings("b"),
+        )
+        .unwrap();
+
+        assert_eq!(actual, &possible_settings[1]);
+
+        // The less fit candidate never takes part in tie-breaking:
+        let actual = select_settings(
+            &possible_settings,
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+            &ideal_settings("c"),
+        )
+        .unwrap();
+
+        assert_eq!(actual, &possible_settings[0]);
+
+        let actual = select_settings(
+            &possible_settings,
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+            &SelectLastSettingsPolicy,
+        )
+        .unwrap();
+
+        assert_eq!(actual, &possible_settings[1]);
+
+        // Out of bounds indices get clamped to the tied candidates:
+        let actual = select_settings(
+            &possible_settings,
+            &constraints,
+            DeviceInformationExposureMode::Exposed,
+            &SelectByIndexPolicy(2),
+        )
+        .unwrap();
+
+        assert_eq!(actual, &possible_settings[1]);
+    }
+}
+
+// ```
+//                        ┌
+// mandatory constraints: ┤   ┄───────────────────────────────────────────┤
+//                        └
+//                        ┌
+//  advanced constraints: ┤                    ├─┤         ├────────────────────────────┄
+//                        └
+//                        ┌
+//     possible settings: ┤   ●─────────────●──────────────●──────────────●─────────────●
+//                        └  480p          720p          1080p          1440p         2160p
+//                                                         └───────┬──────┘
+//     selected settings: ─────────────────────────────────────────┘
+// ```
+mod smoke {
+    use super::*;
+    use crate::{MediaTrackConstraintSet, ValueConstraint, ValueRangeConstraint};
+
+    #[test]
+    fn native() {
+        let supported_constraints = MediaTrackSupportedConstraints::from_iter(vec![
+            &DEVICE_ID,
+            &HEIGHT,
+            &WIDTH,
+            &RESIZE_MODE,
+        ]);
+
+        let possible_settings = vec![
+            MediaTrackSettings::from_iter([
                 (&DEVICE_ID, "480p".into()),
                 (&HEIGHT, 480.into()),
                 (&WIDTH, 720.into()),
@@ -775,4 +3083,89 @@ mod smoke {
 
         assert_eq!(actual, expected);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_settings() {
+        // Serialize selected settings to JSON,
+        // matching the shape of `MediaStreamTrack.getSettings()`:
+        let actual = serde_json::to_value(&*VIDEO_1080P).unwrap();
+
+        let expected = serde_json::json!({
+            "deviceId": "1080p",
+            "aspectRatio": 0.5625,
+            "facingMode": "user",
+            "frameRate": 60,
+            "width": 1920,
+            "height": 1080,
+            "resizeMode": "none",
+        });
+
+        assert_eq!(actual, expected);
+
+        let actual = serde_json::to_value(&*VIDEO_480P).unwrap();
+
+        assert_eq!(actual["resizeMode"], "crop-and-scale");
+
+        // Deserializing the serialized settings produces the original settings:
+        let actual: MediaTrackSettings = serde_json::from_value(expected).unwrap();
+
+        assert_eq!(actual, *VIDEO_1080P);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_audio() {
+        let supported_constraints = MediaTrackSupportedConstraints::from_iter(vec![
+            &DEVICE_ID,
+            &SAMPLE_RATE,
+            &SAMPLE_SIZE,
+            &CHANNEL_COUNT,
+            &LATENCY,
+        ]);
+
+        // Deserialize possible settings from JSON:
+        let possible_settings: Vec<MediaTrackSettings> = {
+            let json = serde_json::json!([
+                { "deviceId": "narrowband", "sampleRate": 8000, "sampleSize": 16, "channelCount": 1, "latency": 0.01 },
+                { "deviceId": "wideband", "sampleRate": 16000, "sampleSize": 16, "channelCount": 1, "latency": 0.01 },
+                { "deviceId": "fullband", "sampleRate": 48000, "sampleSize": 24, "channelCount": 2, "latency": 0.02 },
+            ]);
+            serde_json::from_value(json).unwrap()
+        };
+
+        // Deserialize constraints from JSON:
+        let constraints: MediaTrackConstraints = {
+            let json = serde_json::json!({
+                "sampleRate": {
+                    "min": 44100,
+                },
+                "sampleSize": {
+                    "ideal": 24,
+                },
+                "channelCount": 2,
+                "latency": {
+                    "max": 0.05,
+                },
+            });
+            serde_json::from_value(json).unwrap()
+        };
+
+        // Resolve bare values to proper constraints:
+        let resolved_constraints = constraints.into_resolved();
+
+        // Sanitize constraints, removing empty and unsupported constraints:
+        let sanitized_constraints = resolved_constraints.to_sanitized(&supported_constraints);
+
+        let actual = select_settings_candidates(
+            &possible_settings,
+            &sanitized_constraints,
+            DeviceInformationExposureMode::Exposed,
+        )
+        .unwrap();
+
+        let expected = vec![&possible_settings[2]];
+
+        assert_eq!(actual, expected);
+    }
 }
@@ -1,17 +1,23 @@
+#[cfg(feature = "rand")]
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::iter::FromIterator;
 
 use ordered_float::NotNan;
+#[cfg(feature = "rand")]
+use rand::Rng;
+use thiserror::Error;
 
 use crate::algorithms::FitnessDistance;
 use crate::{
-    MandatoryMediaTrackConstraints, MediaTrackSettings, MediaTrackSupportedConstraints,
-    SanitizedMandatoryMediaTrackConstraints,
+    MandatoryMediaTrackConstraints, MediaTrackProperty, MediaTrackSettings,
+    MediaTrackSupportedConstraints, SanitizedMandatoryMediaTrackConstraints,
 };
 
 /// A tie-breaking policy used for selecting a single preferred candidate
 /// from a set list of equally optimal setting candidates.
 pub trait TieBreakingPolicy {
-    /// Selects a preferred candidate from a non-empty selection of optimal candidates.
+    /// Selects a preferred candidate from a non-empty selection of equally optimal candidates.
     ///
     /// As specified in step 6 of the `SelectSettings` algorithm:
     /// <https://www.w3.org/TR/mediacapture-streams/#dfn-selectsettings>
@@ -22,9 +28,9 @@ pub trait TieBreakingPolicy {
     /// > If more than one settings dictionary have the smallest fitness distance,
     /// > the User Agent chooses one of them based on system default property values
     /// > and User Agent default property values.
-    fn select_candidate<'a, I>(&self, candidates: I) -> &'a MediaTrackSettings
-    where
-        I: IntoIterator<Item = &'a MediaTrackSettings>;
+    ///
+    /// The `candidates` passed to this method all share the smallest fitness distance.
+    fn break_tie<'a>(&self, candidates: &[&'a MediaTrackSettings]) -> &'a MediaTrackSettings;
 }
 
 /// A naïve tie-breaking policy that just picks the first settings item it encounters.
@@ -44,15 +50,60 @@ impl Default for FirstPolicy {
 }
 
 impl TieBreakingPolicy for FirstPolicy {
-    fn select_candidate<'a, I>(&self, candidates: I) -> &'a MediaTrackSettings
-    where
-        I: IntoIterator<Item = &'a MediaTrackSettings>,
-    {
+    fn break_tie<'a>(&self, candidates: &[&'a MediaTrackSettings]) -> &'a MediaTrackSettings {
+        // Safety: We know that `candidates is non-empty:
+        candidates
+            .first()
+            .expect("The `candidates` slice should contain at least one item.")
+    }
+}
+
+/// A naïve tie-breaking policy that just picks the last settings item it encounters.
+pub struct SelectLastSettingsPolicy;
+
+impl SelectLastSettingsPolicy {
+    /// Creates a new policy.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for SelectLastSettingsPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TieBreakingPolicy for SelectLastSettingsPolicy {
+    fn break_tie<'a>(&self, candidates: &[&'a MediaTrackSettings]) -> &'a MediaTrackSettings {
         // Safety: We know that `candidates is non-empty:
         candidates
-            .into_iter()
-            .next()
-            .expect("The `candidates` iterator should have produced at least one item.")
+            .last()
+            .expect("The `candidates` slice should contain at least one item.")
+    }
+}
+
+/// A naïve tie-breaking policy that picks the settings item at the given index,
+/// or the last settings item if the index is out of bounds.
+pub struct SelectByIndexPolicy(pub usize);
+
+impl SelectByIndexPolicy {
+    /// Creates a new policy picking the settings item at `index`.
+    pub fn new(index: usize) -> Self {
+        Self(index)
+    }
+}
+
+impl TieBreakingPolicy for SelectByIndexPolicy {
+    fn break_tie<'a>(&self, candidates: &[&'a MediaTrackSettings]) -> &'a MediaTrackSettings {
+        assert!(
+            !candidates.is_empty(),
+            "The `candidates` slice should contain at least one item."
+        );
+
+        let index = self.0.min(candidates.len() - 1);
+
+        candidates[index]
     }
 }
 
@@ -82,12 +133,10 @@ impl ClosestToIdealPolicy {
 }
 
 impl TieBreakingPolicy for ClosestToIdealPolicy {
-    fn select_candidate<'b, I>(&self, candidates: I) -> &'b MediaTrackSettings
-    where
-        I: IntoIterator<Item = &'b MediaTrackSettings>,
-    {
+    fn break_tie<'b>(&self, candidates: &[&'b MediaTrackSettings]) -> &'b MediaTrackSettings {
         candidates
-            .into_iter()
+            .iter()
+            .copied()
             .min_by_key(|settings| {
                 let fitness_distance = self
                     .sanitized_constraints
@@ -95,7 +144,125 @@ impl TieBreakingPolicy for ClosestToIdealPolicy {
                     .expect("Fitness distance should be positive.");
                 NotNan::new(fitness_distance).expect("Expected non-NaN fitness distance.")
             })
-            .expect("The `candidates` iterator should have produced at least one item.")
+            .expect("The `candidates` slice should contain at least one item.")
+    }
+}
+
+/// An error indicating a weight of a `WeightedClosestToIdealPolicy` that is negative or not finite.
+#[derive(Error, Clone, PartialEq, Debug)]
+#[error("Invalid weight for {property}: {weight} (expected a finite, non-negative weight)")]
+pub struct InvalidWeightError {
+    /// The property of the invalid weight.
+    pub property: MediaTrackProperty,
+    /// The invalid weight.
+    pub weight: f64,
+}
+
+/// A tie-breaking policy that picks the settings item that's closest to the specified ideal settings,
+/// with each property's fitness distance scaled by a per-property weight.
+///
+/// Properties without an explicit weight are weighted with `1.0`.
+pub struct WeightedClosestToIdealPolicy {
+    sanitized_constraints: SanitizedMandatoryMediaTrackConstraints,
+    weights: HashMap<MediaTrackProperty, f64>,
+}
+
+impl WeightedClosestToIdealPolicy {
+    /// Creates a new policy from the given ideal settings, per-property weights and supported constraints.
+    ///
+    /// Fails if any of the weights is negative or not finite.
+    pub fn new(
+        ideal_settings: MediaTrackSettings,
+        weights: HashMap<MediaTrackProperty, f64>,
+        supported_constraints: &MediaTrackSupportedConstraints,
+    ) -> Result<Self, InvalidWeightError> {
+        if let Some((property, weight)) = weights
+            .iter()
+            .find(|(_, weight)| !weight.is_finite() || **weight < 0.0)
+        {
+            return Err(InvalidWeightError {
+                property: property.clone(),
+                weight: *weight,
+            });
+        }
+
+        let sanitized_constraints = MandatoryMediaTrackConstraints::from_iter(
+            ideal_settings
+                .into_iter()
+                .map(|(property, setting)| (property, setting.into())),
+        )
+        .into_resolved()
+        .into_sanitized(supported_constraints);
+
+        Ok(Self {
+            sanitized_constraints,
+            weights,
+        })
+    }
+
+    fn weighted_fitness_distance(&self, settings: &MediaTrackSettings) -> f64 {
+        self.sanitized_constraints
+            .iter()
+            .map(|(property, constraint)| {
+                let fitness_distance = constraint
+                    .fitness_distance(settings.get(property))
+                    .expect("Fitness distance should be positive.");
+                let weight = self.weights.get(property).copied().unwrap_or(1.0);
+                weight * fitness_distance
+            })
+            .sum()
+    }
+}
+
+impl TieBreakingPolicy for WeightedClosestToIdealPolicy {
+    fn break_tie<'b>(&self, candidates: &[&'b MediaTrackSettings]) -> &'b MediaTrackSettings {
+        candidates
+            .iter()
+            .copied()
+            .min_by_key(|settings| {
+                let fitness_distance = self.weighted_fitness_distance(settings);
+                NotNan::new(fitness_distance).expect("Expected non-NaN fitness distance.")
+            })
+            .expect("The `candidates` slice should contain at least one item.")
+    }
+}
+
+/// A tie-breaking policy that picks a random settings item from the candidates.
+///
+/// The policy draws from the provided random number generator,
+/// so picks are reproducible when using a seeded generator.
+#[cfg(feature = "rand")]
+pub struct RandomPolicy<R> {
+    rng: RefCell<R>,
+}
+
+#[cfg(feature = "rand")]
+impl<R> RandomPolicy<R>
+where
+    R: Rng,
+{
+    /// Creates a new policy drawing from the given random number generator.
+    pub fn new(rng: R) -> Self {
+        Self {
+            rng: RefCell::new(rng),
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<R> TieBreakingPolicy for RandomPolicy<R>
+where
+    R: Rng,
+{
+    fn break_tie<'a>(&self, candidates: &[&'a MediaTrackSettings]) -> &'a MediaTrackSettings {
+        assert!(
+            !candidates.is_empty(),
+            "The `candidates` slice should contain at least one item."
+        );
+
+        let index = self.rng.borrow_mut().gen_range(0..candidates.len());
+
+        candidates[index]
     }
 }
 
@@ -109,21 +276,58 @@ mod tests {
 
     #[test]
     fn first() {
-        let settings = vec![
+        let settings = [
             MediaTrackSettings::from_iter([(&DEVICE_ID, "device-id-0".into())]),
             MediaTrackSettings::from_iter([(&DEVICE_ID, "device-id-1".into())]),
             MediaTrackSettings::from_iter([(&DEVICE_ID, "device-id-2".into())]),
         ];
+        let candidates: Vec<_> = settings.iter().collect();
 
         let policy = FirstPolicy;
 
-        let actual = policy.select_candidate(&settings);
+        let actual = policy.break_tie(&candidates);
 
         let expected = &settings[0];
 
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn select_last() {
+        let settings = [
+            MediaTrackSettings::from_iter([(&DEVICE_ID, "device-id-0".into())]),
+            MediaTrackSettings::from_iter([(&DEVICE_ID, "device-id-1".into())]),
+            MediaTrackSettings::from_iter([(&DEVICE_ID, "device-id-2".into())]),
+        ];
+        let candidates: Vec<_> = settings.iter().collect();
+
+        let policy = SelectLastSettingsPolicy;
+
+        let actual = policy.break_tie(&candidates);
+
+        let expected = &settings[2];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn select_by_index() {
+        let settings = [
+            MediaTrackSettings::from_iter([(&DEVICE_ID, "device-id-0".into())]),
+            MediaTrackSettings::from_iter([(&DEVICE_ID, "device-id-1".into())]),
+            MediaTrackSettings::from_iter([(&DEVICE_ID, "device-id-2".into())]),
+        ];
+        let candidates: Vec<_> = settings.iter().collect();
+
+        for (index, expected) in [(0, 0), (1, 1), (2, 2), (3, 2), (usize::MAX, 2)] {
+            let policy = SelectByIndexPolicy(index);
+
+            let actual = policy.break_tie(&candidates);
+
+            assert_eq!(actual, &settings[expected], "index {index}");
+        }
+    }
+
     #[test]
     fn closest_to_ideal() {
         let supported_constraints = MediaTrackSupportedConstraints::from_iter(vec![
@@ -133,7 +337,7 @@ mod tests {
             &RESIZE_MODE,
         ]);
 
-        let settings = vec![
+        let settings = [
             MediaTrackSettings::from_iter([
                 (&DEVICE_ID, "480p".into()),
                 (&HEIGHT, 480.into()),
@@ -165,6 +369,7 @@ mod tests {
                 (&RESIZE_MODE, ResizeMode::none().into()),
             ]),
         ];
+        let candidates: Vec<_> = settings.iter().collect();
 
         let ideal_settings = vec![
             MediaTrackSettings::from_iter([(&HEIGHT, 450.into()), (&WIDTH, 700.into())]),
@@ -177,11 +382,111 @@ mod tests {
         for (index, ideal) in ideal_settings.iter().enumerate() {
             let policy = ClosestToIdealPolicy::new(ideal.clone(), &supported_constraints);
 
-            let actual = policy.select_candidate(&settings);
+            let actual = policy.break_tie(&candidates);
 
             let expected = &settings[index];
 
             assert_eq!(actual, expected);
         }
     }
+
+    #[test]
+    fn weighted_closest_to_ideal() {
+        let supported_constraints =
+            MediaTrackSupportedConstraints::from_iter(vec![&DEVICE_ID, &WIDTH, &FRAME_RATE]);
+
+        let settings = [
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, "wide".into()),
+                (&WIDTH, 1920.into()),
+                (&FRAME_RATE, 45.0.into()),
+            ]),
+            MediaTrackSettings::from_iter([
+                (&DEVICE_ID, "fast".into()),
+                (&WIDTH, 1280.into()),
+                (&FRAME_RATE, 60.0.into()),
+            ]),
+        ];
+        let candidates: Vec<_> = settings.iter().collect();
+
+        let ideal =
+            MediaTrackSettings::from_iter([(&WIDTH, 1920.into()), (&FRAME_RATE, 60.0.into())]);
+
+        // Unweighted, "wide" wins: 0.0 + 0.25 < 0.333… + 0.0
+        let policy = ClosestToIdealPolicy::new(ideal.clone(), &supported_constraints);
+        assert_eq!(policy.break_tie(&candidates), &settings[0]);
+
+        let policy = WeightedClosestToIdealPolicy::new(
+            ideal.clone(),
+            HashMap::new(),
+            &supported_constraints,
+        )
+        .unwrap();
+        assert_eq!(policy.break_tie(&candidates), &settings[0]);
+
+        // Weighting frame rate twice as heavily, "fast" wins: 0.0 + 0.5 > 0.333… + 0.0
+        let policy = WeightedClosestToIdealPolicy::new(
+            ideal,
+            HashMap::from_iter([(FRAME_RATE.clone(), 2.0)]),
+            &supported_constraints,
+        )
+        .unwrap();
+        assert_eq!(policy.break_tie(&candidates), &settings[1]);
+    }
+
+    #[test]
+    fn weighted_closest_to_ideal_invalid_weight() {
+        let supported_constraints = MediaTrackSupportedConstraints::from_iter(vec![&WIDTH]);
+        let ideal = MediaTrackSettings::from_iter([(&WIDTH, 1920.into())]);
+
+        for weight in [f64::NAN, f64::INFINITY, -1.0] {
+            let result = WeightedClosestToIdealPolicy::new(
+                ideal.clone(),
+                HashMap::from_iter([(WIDTH.clone(), weight)]),
+                &supported_constraints,
+            );
+
+            let error = result.err().unwrap();
+            assert_eq!(error.property, WIDTH);
+            assert!(error.weight.is_nan() || error.weight == weight);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let settings = [
+            MediaTrackSettings::from_iter([(&DEVICE_ID, "device-id-0".into())]),
+            MediaTrackSettings::from_iter([(&DEVICE_ID, "device-id-1".into())]),
+            MediaTrackSettings::from_iter([(&DEVICE_ID, "device-id-2".into())]),
+        ];
+        let candidates: Vec<_> = settings.iter().collect();
+
+        let picks = |seed| {
+            let policy = RandomPolicy::new(StdRng::seed_from_u64(seed));
+            (0..300)
+                .map(|_| policy.break_tie(&candidates))
+                .collect::<Vec<_>>()
+        };
+
+        let actual = picks(42);
+
+        // Every pick is one of the tied candidates, and over enough picks each of them gets picked:
+        assert!(actual.iter().all(|pick| candidates
+            .iter()
+            .any(|candidate| std::ptr::eq(*pick, *candidate))));
+        for candidate in &candidates {
+            assert!(actual.contains(candidate));
+        }
+
+        // A single candidate is always picked:
+        let policy = RandomPolicy::new(StdRng::seed_from_u64(42));
+        assert_eq!(policy.break_tie(&candidates[..1]), &settings[0]);
+
+        // Picks are reproducible for a given seed:
+        assert_eq!(picks(42), actual);
+    }
 }
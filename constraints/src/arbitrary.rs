@@ -0,0 +1,403 @@
+//! Implementations of [`quickcheck::Arbitrary`][arbitrary] for property-based testing.
+//!
+//! Settings and constraints get generated per standard property, respecting its value kind
+//! (e.g. only numeric values for `width`, only known facing modes for `facingMode`).
+//! Values are drawn from small pools, so that generated constraints are likely
+//! to either match or conflict with generated settings, rather than being unrelated.
+//!
+//! [arbitrary]: https://docs.rs/quickcheck/latest/quickcheck/trait.Arbitrary.html
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::property::all::{info, names as all_properties};
+use crate::property::{MediaTrackPropertyInfo, MediaTrackPropertyValueKind};
+use crate::{
+    AdvancedMediaTrackConstraints, CursorCapture, DisplaySurface, FacingMode,
+    MandatoryMediaTrackConstraints, MediaTrackConstraint, MediaTrackConstraintSet,
+    MediaTrackConstraints, MediaTrackProperty, MediaTrackSetting, MediaTrackSettings, ResizeMode,
+    ResolvedValueConstraint, ResolvedValueRangeConstraint, ResolvedValueSequenceConstraint,
+    ValueConstraint, ValueExclusionConstraint, ValueRangeConstraint, ValueSequenceConstraint,
+};
+
+const INTEGERS: [u64; 10] = [0, 1, 2, 30, 60, 480, 720, 1080, 1280, 1920];
+const FLOATS: [f64; 8] = [0.0, 0.5625, 1.0, 1.7777, 29.97, 30.0, 60.0, 1280.0];
+const STRINGS: [&str; 3] = ["a", "b", "c"];
+
+/// The maximum number of advanced constraint sets generated per constraints value.
+const MAX_ADVANCED_CONSTRAINT_SETS: u8 = 2;
+
+// Value constraints:
+
+impl<T> Arbitrary for ResolvedValueConstraint<T>
+where
+    T: Arbitrary,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        Self {
+            exact: Arbitrary::arbitrary(g),
+            ideal: Arbitrary::arbitrary(g),
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let mut shrunk = vec![];
+        if self.exact.is_some() {
+            shrunk.push(Self {
+                exact: None,
+                ..self.clone()
+            });
+        }
+        if self.ideal.is_some() {
+            shrunk.push(Self {
+                ideal: None,
+                ..self.clone()
+            });
+        }
+        Box::new(shrunk.into_iter())
+    }
+}
+
+impl<T> Arbitrary for ValueConstraint<T>
+where
+    T: Arbitrary,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        if bool::arbitrary(g) {
+            Self::Bare(T::arbitrary(g))
+        } else {
+            Self::Constraint(Arbitrary::arbitrary(g))
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        match self {
+            Self::Bare(bare) => Box::new(bare.shrink().map(Self::Bare)),
+            Self::Constraint(constraint) => Box::new(constraint.shrink().map(Self::Constraint)),
+        }
+    }
+}
+
+impl<T> Arbitrary for ResolvedValueRangeConstraint<T>
+where
+    T: Arbitrary,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        Self {
+            min: Arbitrary::arbitrary(g),
+            max: Arbitrary::arbitrary(g),
+            exact: Arbitrary::arbitrary(g),
+            ideal: Arbitrary::arbitrary(g),
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let mut shrunk = vec![];
+        if self.min.is_some() {
+            shrunk.push(Self {
+                min: None,
+                ..self.clone()
+            });
+        }
+        if self.max.is_some() {
+            shrunk.push(Self {
+                max: None,
+                ..self.clone()
+            });
+        }
+        if self.exact.is_some() {
+            shrunk.push(Self {
+                exact: None,
+                ..self.clone()
+            });
+        }
+        if self.ideal.is_some() {
+            shrunk.push(Self {
+                ideal: None,
+                ..self.clone()
+            });
+        }
+        Box::new(shrunk.into_iter())
+    }
+}
+
+impl<T> Arbitrary for ValueRangeConstraint<T>
+where
+    T: Arbitrary,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        if bool::arbitrary(g) {
+            Self::Bare(T::arbitrary(g))
+        } else {
+            Self::Constraint(Arbitrary::arbitrary(g))
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        match self {
+            Self::Bare(bare) => Box::new(bare.shrink().map(Self::Bare)),
+            Self::Constraint(constraint) => Box::new(constraint.shrink().map(Self::Constraint)),
+        }
+    }
+}
+
+impl<T> Arbitrary for ResolvedValueSequenceConstraint<T>
+where
+    T: Arbitrary,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        Self {
+            exact: Arbitrary::arbitrary(g),
+            ideal: Arbitrary::arbitrary(g),
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let mut shrunk = vec![];
+        if self.exact.is_some() {
+            shrunk.push(Self {
+                exact: None,
+                ..self.clone()
+            });
+        }
+        if self.ideal.is_some() {
+            shrunk.push(Self {
+                ideal: None,
+                ..self.clone()
+            });
+        }
+        Box::new(shrunk.into_iter())
+    }
+}
+
+impl<T> Arbitrary for ValueSequenceConstraint<T>
+where
+    T: Arbitrary,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        if bool::arbitrary(g) {
+            Self::Bare(Arbitrary::arbitrary(g))
+        } else {
+            Self::Constraint(Arbitrary::arbitrary(g))
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        match self {
+            Self::Bare(bare) => Box::new(bare.shrink().map(Self::Bare)),
+            Self::Constraint(constraint) => Box::new(constraint.shrink().map(Self::Constraint)),
+        }
+    }
+}
+
+// Settings:
+
+impl Arbitrary for MediaTrackSettings {
+    fn arbitrary(g: &mut Gen) -> Self {
+        arbitrary_properties(g)
+            .into_iter()
+            .map(|info| (info.property.clone(), arbitrary_setting(g, &info)))
+            .collect()
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let settings = self.clone();
+        Box::new(
+            self.keys()
+                .cloned()
+                .collect::<Vec<_>>()
+                .into_iter()
+                .map(move |property| {
+                    let mut shrunk = settings.clone();
+                    shrunk.remove(&property);
+                    shrunk
+                }),
+        )
+    }
+}
+
+// Constraints:
+
+impl Arbitrary for MediaTrackConstraints {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let mandatory = MandatoryMediaTrackConstraints::new(arbitrary_constraint_set(g));
+        let advanced_count = u8::arbitrary(g) % (MAX_ADVANCED_CONSTRAINT_SETS + 1);
+        let advanced = AdvancedMediaTrackConstraints::new(
+            (0..advanced_count)
+                .map(|_| arbitrary_constraint_set(g))
+                .collect(),
+        );
+
+        Self {
+            mandatory,
+            advanced,
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let mut shrunk = vec![];
+
+        for index in 0..self.advanced.len() {
+            let mut constraints = self.clone();
+            constraints.advanced.remove(index);
+            shrunk.push(constraints);
+        }
+
+        for property in self.mandatory.keys() {
+            let mut constraints = self.clone();
+            constraints.mandatory.shift_remove(property);
+            shrunk.push(constraints);
+        }
+
+        Box::new(shrunk.into_iter())
+    }
+}
+
+fn arbitrary_constraint_set(g: &mut Gen) -> MediaTrackConstraintSet {
+    arbitrary_properties(g)
+        .into_iter()
+        .map(|info| (info.property.clone(), arbitrary_constraint(g, &info)))
+        .collect()
+}
+
+/// Returns a random subset of the standard properties.
+fn arbitrary_properties(g: &mut Gen) -> Vec<MediaTrackPropertyInfo> {
+    all_properties()
+        .into_iter()
+        .filter(|_| bool::arbitrary(g))
+        .filter_map(|property| info(property.name()))
+        .collect()
+}
+
+fn arbitrary_setting(g: &mut Gen, info: &MediaTrackPropertyInfo) -> MediaTrackSetting {
+    match info.kind {
+        MediaTrackPropertyValueKind::Bool => bool::arbitrary(g).into(),
+        // Some devices report fractional values for integer-valued properties:
+        MediaTrackPropertyValueKind::Integer | MediaTrackPropertyValueKind::Float
+            if bool::arbitrary(g) =>
+        {
+            pick(g, &FLOATS).into()
+        }
+        MediaTrackPropertyValueKind::Integer | MediaTrackPropertyValueKind::Float => {
+            (pick(g, &INTEGERS) as i64).into()
+        }
+        MediaTrackPropertyValueKind::String | MediaTrackPropertyValueKind::Enum => {
+            pick(g, &string_values(info.property)).into()
+        }
+    }
+}
+
+fn arbitrary_constraint(g: &mut Gen, info: &MediaTrackPropertyInfo) -> MediaTrackConstraint {
+    match info.kind {
+        MediaTrackPropertyValueKind::Bool => {
+            if bool::arbitrary(g) {
+                ValueConstraint::Bare(bool::arbitrary(g)).into()
+            } else {
+                ResolvedValueConstraint {
+                    exact: maybe(g, &[false, true]),
+                    ideal: maybe(g, &[false, true]),
+                }
+                .into()
+            }
+        }
+        MediaTrackPropertyValueKind::Integer | MediaTrackPropertyValueKind::Float
+            if bool::arbitrary(g) =>
+        {
+            if bool::arbitrary(g) {
+                ValueRangeConstraint::Bare(pick(g, &INTEGERS)).into()
+            } else {
+                ResolvedValueRangeConstraint {
+                    min: maybe(g, &INTEGERS),
+                    max: maybe(g, &INTEGERS),
+                    exact: maybe(g, &INTEGERS),
+                    ideal: maybe(g, &INTEGERS),
+                }
+                .into()
+            }
+        }
+        MediaTrackPropertyValueKind::Integer | MediaTrackPropertyValueKind::Float => {
+            if bool::arbitrary(g) {
+                ValueRangeConstraint::Bare(pick(g, &FLOATS)).into()
+            } else {
+                ResolvedValueRangeConstraint {
+                    min: maybe(g, &FLOATS),
+                    max: maybe(g, &FLOATS),
+                    exact: maybe(g, &FLOATS),
+                    ideal: maybe(g, &FLOATS),
+                }
+                .into()
+            }
+        }
+        MediaTrackPropertyValueKind::String | MediaTrackPropertyValueKind::Enum => {
+            let values = string_values(info.property);
+            match u8::arbitrary(g) % 4 {
+                0 => ValueConstraint::Bare(pick(g, &values)).into(),
+                1 => ResolvedValueConstraint {
+                    exact: maybe(g, &values),
+                    ideal: maybe(g, &values),
+                }
+                .into(),
+                2 => ResolvedValueSequenceConstraint {
+                    exact: maybe_subset(g, &values),
+                    ideal: maybe_subset(g, &values),
+                }
+                .into(),
+                _ => ValueExclusionConstraint::default()
+                    .not(subset(g, &values))
+                    .into(),
+            }
+        }
+    }
+}
+
+/// Returns the known values of enumeration properties, or a small pool of free-form strings.
+fn string_values(property: &MediaTrackProperty) -> Vec<String> {
+    use crate::property::all::name::{CURSOR, DISPLAY_SURFACE, FACING_MODE, RESIZE_MODE};
+
+    if property == &FACING_MODE {
+        FacingMode::all().iter().map(ToString::to_string).collect()
+    } else if property == &RESIZE_MODE {
+        ResizeMode::all().iter().map(ToString::to_string).collect()
+    } else if property == &DISPLAY_SURFACE {
+        DisplaySurface::all()
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    } else if property == &CURSOR {
+        CursorCapture::all()
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    } else {
+        STRINGS.iter().map(|value| (*value).to_owned()).collect()
+    }
+}
+
+fn pick<T: Clone>(g: &mut Gen, values: &[T]) -> T {
+    g.choose(values)
+        .expect("Expected non-empty values.")
+        .clone()
+}
+
+fn maybe<T: Clone>(g: &mut Gen, values: &[T]) -> Option<T> {
+    if bool::arbitrary(g) {
+        Some(pick(g, values))
+    } else {
+        None
+    }
+}
+
+fn subset<T: Clone>(g: &mut Gen, values: &[T]) -> Vec<T> {
+    values
+        .iter()
+        .filter(|_| bool::arbitrary(g))
+        .cloned()
+        .collect()
+}
+
+fn maybe_subset<T: Clone>(g: &mut Gen, values: &[T]) -> Option<Vec<T>> {
+    if bool::arbitrary(g) {
+        Some(subset(g, values))
+    } else {
+        None
+    }
+}
@@ -4,11 +4,18 @@ use std::ops::Deref;
 use serde::{Deserialize, Serialize};
 
 pub use self::value::{ResolvedValueConstraint, ValueConstraint};
+pub use self::value_exclusion::ValueExclusionConstraint;
+#[cfg(feature = "regex")]
+pub use self::value_pattern::ValuePatternConstraint;
 pub use self::value_range::{ResolvedValueRangeConstraint, ValueRangeConstraint};
 pub use self::value_sequence::{ResolvedValueSequenceConstraint, ValueSequenceConstraint};
+use crate::property::MediaTrackPropertyValueKind;
 use crate::MediaTrackSetting;
 
 mod value;
+mod value_exclusion;
+#[cfg(feature = "regex")]
+mod value_pattern;
 mod value_range;
 mod value_sequence;
 
@@ -41,6 +48,29 @@ pub enum MediaTrackConstraintResolutionStrategy {
     BareToExact,
 }
 
+/// The resolution strategies to apply to the bare values of a track's constraints.
+///
+/// The default policy follows the W3C ["Media Capture and Streams"][media_capture_and_streams_spec] spec,
+/// which treats bare values as `ideal` in mandatory constraints, but as `exact` in advanced constraint sets.
+///
+/// [media_capture_and_streams_spec]: https://www.w3.org/TR/mediacapture-streams/
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct MediaTrackConstraintResolutionPolicy {
+    /// The strategy for resolving bare values of mandatory constraints.
+    pub mandatory: MediaTrackConstraintResolutionStrategy,
+    /// The strategy for resolving bare values of advanced constraint sets.
+    pub advanced: MediaTrackConstraintResolutionStrategy,
+}
+
+impl Default for MediaTrackConstraintResolutionPolicy {
+    fn default() -> Self {
+        Self {
+            mandatory: MediaTrackConstraintResolutionStrategy::BareToIdeal,
+            advanced: MediaTrackConstraintResolutionStrategy::BareToExact,
+        }
+    }
+}
+
 /// A single [constraint][media_track_constraints] value for a [`MediaStreamTrack`][media_stream_track] object.
 ///
 /// # W3C Spec Compliance
@@ -56,6 +86,16 @@ pub enum MediaTrackConstraintResolutionStrategy {
 pub enum MediaTrackConstraint {
     /// An empty constraint.
     Empty(EmptyConstraint),
+    // `Pattern` must be ordered before any other non-empty variant in order for
+    // `serde` to decode the correct variant, as those would silently ignore its fields.
+    /// A pattern-matching string-valued media track constraint.
+    #[cfg(feature = "regex")]
+    Pattern(ValuePatternConstraint),
+    // `Exclusion` must be ordered before any other non-empty variant in order for
+    // `serde` to decode `{ "not": […] }` as an exclusion, as the range variants
+    // would otherwise silently ignore its `not` field and decode it as unconstrained.
+    /// A string-valued media track constraint excluding specific values.
+    Exclusion(ValueExclusionConstraint),
     // `IntegerRange` must be ordered before `FloatRange(…)` in order for
     // `serde` to decode the correct variant.
     /// An integer-valued media track range constraint.
@@ -196,6 +236,23 @@ impl From<ValueConstraint<String>> for MediaTrackConstraint {
 
 // Conversion from settings:
 
+// Pattern constraint:
+
+#[cfg(feature = "regex")]
+impl From<ValuePatternConstraint> for MediaTrackConstraint {
+    fn from(constraint: ValuePatternConstraint) -> Self {
+        Self::Pattern(constraint)
+    }
+}
+
+// Exclusion constraint:
+
+impl From<ValueExclusionConstraint> for MediaTrackConstraint {
+    fn from(constraint: ValueExclusionConstraint) -> Self {
+        Self::Exclusion(constraint)
+    }
+}
+
 impl From<MediaTrackSetting> for MediaTrackConstraint {
     fn from(settings: MediaTrackSetting) -> Self {
         match settings {
@@ -209,11 +266,59 @@ impl From<MediaTrackSetting> for MediaTrackConstraint {
     }
 }
 
+/// The kind of a constraint, for validating it against the value kinds of standard properties.
+pub trait MediaTrackConstraintKind {
+    /// Returns `true` if the constraint is applicable to property values of `kind`, otherwise `false`.
+    fn is_applicable_to(&self, kind: MediaTrackPropertyValueKind) -> bool;
+
+    /// Returns a human-readable description of the constraint's kind (e.g. `"string"`).
+    fn kind_description(&self) -> &'static str;
+}
+
+macro_rules! impl_media_track_constraint_kind {
+    ($t:ty) => {
+        impl MediaTrackConstraintKind for $t {
+            fn is_applicable_to(&self, kind: MediaTrackPropertyValueKind) -> bool {
+                use MediaTrackPropertyValueKind::*;
+
+                match self {
+                    Self::Empty(_) => true,
+                    #[cfg(feature = "regex")]
+                    Self::Pattern(_) => matches!(kind, String | Enum),
+                    Self::Exclusion(_) => matches!(kind, String | Enum),
+                    Self::IntegerRange(_) | Self::FloatRange(_) => matches!(kind, Integer | Float),
+                    Self::Bool(_) => matches!(kind, Bool),
+                    Self::StringSequence(_) | Self::String(_) => matches!(kind, String | Enum),
+                }
+            }
+
+            fn kind_description(&self) -> &'static str {
+                match self {
+                    Self::Empty(_) => "empty constraint",
+                    #[cfg(feature = "regex")]
+                    Self::Pattern(_) => "pattern",
+                    Self::Exclusion(_) => "exclusion",
+                    Self::IntegerRange(_) | Self::FloatRange(_) => "number",
+                    Self::Bool(_) => "boolean",
+                    Self::StringSequence(_) => "sequence of strings",
+                    Self::String(_) => "string",
+                }
+            }
+        }
+    };
+}
+
+impl_media_track_constraint_kind!(MediaTrackConstraint);
+impl_media_track_constraint_kind!(ResolvedMediaTrackConstraint);
+
 impl MediaTrackConstraint {
     /// Returns `true` if `self` is empty, otherwise `false`.
     pub fn is_empty(&self) -> bool {
         match self {
             Self::Empty(_) => true,
+            #[cfg(feature = "regex")]
+            Self::Pattern(constraint) => constraint.is_empty(),
+            Self::Exclusion(constraint) => constraint.is_empty(),
             Self::IntegerRange(constraint) => constraint.is_empty(),
             Self::FloatRange(constraint) => constraint.is_empty(),
             Self::Bool(constraint) => constraint.is_empty(),
@@ -239,6 +344,9 @@ impl MediaTrackConstraint {
     ) -> ResolvedMediaTrackConstraint {
         match self {
             Self::Empty(constraint) => ResolvedMediaTrackConstraint::Empty(constraint),
+            #[cfg(feature = "regex")]
+            Self::Pattern(constraint) => ResolvedMediaTrackConstraint::Pattern(constraint),
+            Self::Exclusion(constraint) => ResolvedMediaTrackConstraint::Exclusion(constraint),
             Self::IntegerRange(constraint) => {
                 ResolvedMediaTrackConstraint::IntegerRange(constraint.into_resolved(strategy))
             }
@@ -274,6 +382,11 @@ impl MediaTrackConstraint {
 pub enum ResolvedMediaTrackConstraint {
     /// An empty constraint.
     Empty(EmptyConstraint),
+    /// A pattern-matching string-valued media track constraint.
+    #[cfg(feature = "regex")]
+    Pattern(ValuePatternConstraint),
+    /// A string-valued media track constraint excluding specific values.
+    Exclusion(ValueExclusionConstraint),
     /// An integer-valued media track range constraint.
     IntegerRange(ResolvedValueRangeConstraint<u64>),
     /// An floating-point-valued media track range constraint.
@@ -292,10 +405,31 @@ impl Default for ResolvedMediaTrackConstraint {
     }
 }
 
+/// Bare values are rendered as-is, since their meaning depends on
+/// the [`MediaTrackConstraintResolutionStrategy`] they get resolved with.
+impl std::fmt::Display for MediaTrackConstraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty(_constraint) => "<empty>".fmt(f),
+            #[cfg(feature = "regex")]
+            Self::Pattern(constraint) => constraint.fmt(f),
+            Self::Exclusion(constraint) => constraint.fmt(f),
+            Self::IntegerRange(constraint) => constraint.fmt(f),
+            Self::FloatRange(constraint) => constraint.fmt(f),
+            Self::Bool(constraint) => constraint.fmt(f),
+            Self::StringSequence(constraint) => constraint.fmt(f),
+            Self::String(constraint) => constraint.fmt(f),
+        }
+    }
+}
+
 impl std::fmt::Display for ResolvedMediaTrackConstraint {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Empty(_constraint) => "<empty>".fmt(f),
+            #[cfg(feature = "regex")]
+            Self::Pattern(constraint) => constraint.fmt(f),
+            Self::Exclusion(constraint) => constraint.fmt(f),
             Self::IntegerRange(constraint) => constraint.fmt(f),
             Self::FloatRange(constraint) => constraint.fmt(f),
             Self::Bool(constraint) => constraint.fmt(f),
@@ -345,6 +479,23 @@ impl From<ResolvedValueConstraint<String>> for ResolvedMediaTrackConstraint {
     }
 }
 
+// Pattern constraint:
+
+#[cfg(feature = "regex")]
+impl From<ValuePatternConstraint> for ResolvedMediaTrackConstraint {
+    fn from(constraint: ValuePatternConstraint) -> Self {
+        Self::Pattern(constraint)
+    }
+}
+
+// Exclusion constraint:
+
+impl From<ValueExclusionConstraint> for ResolvedMediaTrackConstraint {
+    fn from(constraint: ValueExclusionConstraint) -> Self {
+        Self::Exclusion(constraint)
+    }
+}
+
 impl ResolvedMediaTrackConstraint {
     /// Creates a resolved media track constraint by resolving
     /// bare values to exact constraints: `{ exact: bare }`.
@@ -364,6 +515,9 @@ impl ResolvedMediaTrackConstraint {
     pub fn is_required(&self) -> bool {
         match self {
             Self::Empty(_constraint) => false,
+            #[cfg(feature = "regex")]
+            Self::Pattern(constraint) => constraint.is_required(),
+            Self::Exclusion(constraint) => constraint.is_required(),
             Self::IntegerRange(constraint) => constraint.is_required(),
             Self::FloatRange(constraint) => constraint.is_required(),
             Self::Bool(constraint) => constraint.is_required(),
@@ -376,6 +530,9 @@ impl ResolvedMediaTrackConstraint {
     pub fn is_empty(&self) -> bool {
         match self {
             Self::Empty(_constraint) => true,
+            #[cfg(feature = "regex")]
+            Self::Pattern(constraint) => constraint.is_empty(),
+            Self::Exclusion(constraint) => constraint.is_empty(),
             Self::IntegerRange(constraint) => constraint.is_empty(),
             Self::FloatRange(constraint) => constraint.is_empty(),
             Self::Bool(constraint) => constraint.is_empty(),
@@ -394,6 +551,9 @@ impl ResolvedMediaTrackConstraint {
     pub fn into_required_only(self) -> Self {
         match self {
             Self::Empty(constraint) => Self::Empty(constraint),
+            #[cfg(feature = "regex")]
+            Self::Pattern(constraint) => Self::Pattern(constraint.into_required_only()),
+            Self::Exclusion(constraint) => Self::Exclusion(constraint.into_required_only()),
             Self::IntegerRange(constraint) => Self::IntegerRange(constraint.into_required_only()),
             Self::FloatRange(constraint) => Self::FloatRange(constraint.into_required_only()),
             Self::Bool(constraint) => Self::Bool(constraint.into_required_only()),
@@ -639,6 +799,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn to_string() {
+        let scenarios = [
+            (Subject::Empty(EmptyConstraint {}), "<empty>"),
+            (Subject::Bool(ValueConstraint::Bare(true)), "true"),
+            (
+                Subject::IntegerRange(ValueRangeConstraint::Constraint(
+                    ResolvedValueRangeConstraint::default().min(1000),
+                )),
+                "(1000 <= x)",
+            ),
+            (
+                Subject::String(ValueConstraint::Constraint(
+                    ResolvedValueConstraint::default().exact("missing-group".to_owned()),
+                )),
+                "(x == \"missing-group\")",
+            ),
+            (
+                Subject::StringSequence(ValueSequenceConstraint::Bare(vec!["string".to_owned()])),
+                "[\"string\"]",
+            ),
+        ];
+
+        for (constraint, expected) in scenarios {
+            let actual = constraint.to_string();
+
+            assert_eq!(actual, expected);
+        }
+    }
+
     mod resolved {
         use super::*;
 
@@ -697,6 +887,26 @@ mod serde_tests {
         test_serde_symmetry!(subject: subject, json: json);
     }
 
+    #[cfg(feature = "regex")]
+    #[test]
+    fn pattern() {
+        let subject = Subject::Pattern(
+            ValuePatternConstraint::default().exact(regex::Regex::new("^usb-").unwrap()),
+        );
+        let json = serde_json::json!({ "exactPattern": "^usb-" });
+
+        test_serde_symmetry!(subject: subject, json: json);
+    }
+
+    #[test]
+    fn exclusion() {
+        let subject =
+            Subject::Exclusion(ValueExclusionConstraint::default().not(vec!["foo".to_owned()]));
+        let json = serde_json::json!({ "not": ["foo"] });
+
+        test_serde_symmetry!(subject: subject, json: json);
+    }
+
     #[test]
     fn bool_bare() {
         let subject = Subject::Bool(true.into());
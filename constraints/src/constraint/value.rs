@@ -189,6 +189,18 @@ impl<T> Default for ResolvedValueConstraint<T> {
     }
 }
 
+impl<T> std::fmt::Display for ValueConstraint<T>
+where
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bare(bare) => f.write_fmt(format_args!("{bare:?}")),
+            Self::Constraint(constraint) => constraint.fmt(f),
+        }
+    }
+}
+
 impl<T> std::fmt::Display for ResolvedValueConstraint<T>
 where
     T: std::fmt::Debug,
@@ -219,6 +231,33 @@ where
 mod tests {
     use super::*;
 
+    mod unresolved {
+        use super::*;
+
+        type Subject = ValueConstraint<bool>;
+
+        #[test]
+        fn to_string() {
+            let scenarios = [
+                (Subject::Bare(true), "true"),
+                (
+                    Subject::Constraint(ResolvedValueConstraint::default().exact(true)),
+                    "(x == true)",
+                ),
+                (
+                    Subject::Constraint(ResolvedValueConstraint::default().ideal(false)),
+                    "(x ~= false)",
+                ),
+            ];
+
+            for (constraint, expected) in scenarios {
+                let actual = constraint.to_string();
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
     #[test]
     fn to_string() {
         let scenarios = [
@@ -0,0 +1,130 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A constraint specifying string values that are NOT accepted.
+///
+/// # W3C Spec Compliance
+///
+/// There exists no corresponding type in the W3C ["Media Capture and Streams"][media_capture_and_streams_spec] spec.
+///
+/// The excluded values are serialized as `{ "not": […] }`.
+///
+/// [media_capture_and_streams_spec]: https://www.w3.org/TR/mediacapture-streams/
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct ValueExclusionConstraint {
+    /// The values excluded for this property.
+    ///
+    /// This is a required value.
+    pub not: Vec<String>,
+}
+
+impl ValueExclusionConstraint {
+    /// Consumes `self`, returning a corresponding constraint
+    /// with the excluded values set to `not`.
+    #[inline]
+    pub fn not<U>(mut self, not: U) -> Self
+    where
+        Vec<String>: From<U>,
+    {
+        self.not = not.into();
+        self
+    }
+
+    /// Returns `true` if any values are excluded, otherwise `false`.
+    pub fn is_required(&self) -> bool {
+        !self.not.is_empty()
+    }
+
+    /// Returns `true` if no values are excluded, otherwise `false`.
+    pub fn is_empty(&self) -> bool {
+        self.not.is_empty()
+    }
+
+    /// Returns `true` if `value` is excluded, otherwise `false`.
+    pub fn excludes(&self, value: &str) -> bool {
+        self.not.iter().any(|excluded| excluded == value)
+    }
+
+    /// Returns a corresponding constraint containing only required values.
+    pub fn to_required_only(&self) -> Self {
+        self.clone().into_required_only()
+    }
+
+    /// Consumes `self, returning a corresponding constraint
+    /// containing only required values.
+    pub fn into_required_only(self) -> Self {
+        self
+    }
+}
+
+impl std::fmt::Display for ValueExclusionConstraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("(")?;
+        if self.not.is_empty() {
+            f.write_str("<empty>")?;
+        }
+        for (index, excluded) in self.not.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" && ")?;
+            }
+            f.write_fmt(format_args!("x != {excluded:?}"))?;
+        }
+        f.write_str(")")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Subject = ValueExclusionConstraint;
+
+    #[test]
+    fn to_string() {
+        let scenarios = [
+            (Subject::default(), "(<empty>)"),
+            (Subject::default().not(vec!["a".to_owned()]), "(x != \"a\")"),
+            (
+                Subject::default().not(vec!["a".to_owned(), "b".to_owned()]),
+                "(x != \"a\" && x != \"b\")",
+            ),
+        ];
+
+        for (constraint, expected) in scenarios {
+            let actual = constraint.to_string();
+
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn excludes() {
+        let subject = Subject::default().not(vec!["a".to_owned(), "b".to_owned()]);
+
+        assert!(subject.excludes("a"));
+        assert!(subject.excludes("b"));
+        assert!(!subject.excludes("c"));
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(test)]
+mod serde_tests {
+    use super::*;
+    use crate::macros::test_serde_symmetry;
+
+    type Subject = ValueExclusionConstraint;
+
+    #[test]
+    fn customized() {
+        let subject = Subject::default().not(vec!["a".to_owned(), "b".to_owned()]);
+        let json = serde_json::json!({
+            "not": ["a", "b"],
+        });
+
+        test_serde_symmetry!(subject: subject, json: json);
+    }
+}
@@ -0,0 +1,284 @@
+use regex::Regex;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A constraint specifying a regular expression pattern that accepted string values have to match.
+///
+/// # W3C Spec Compliance
+///
+/// There exists no corresponding type in the W3C ["Media Capture and Streams"][media_capture_and_streams_spec] spec.
+///
+/// The pattern fields are named `exactPattern` and `idealPattern` (rather than `exact` and `ideal`)
+/// when serialized, in order to keep them distinguishable from string-valued constraints.
+///
+/// [media_capture_and_streams_spec]: https://www.w3.org/TR/mediacapture-streams/
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct ValuePatternConstraint {
+    /// The pattern required to be matched by the value of this property.
+    ///
+    /// This is a required value.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "serialize_optional_regex",
+            skip_serializing_if = "core::option::Option::is_none"
+        )
+    )]
+    pub exact_pattern: Option<Regex>,
+    /// The pattern ideally (but not necessarily) matched by the value of this property.
+    ///
+    /// This is an optional value.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "serialize_optional_regex",
+            skip_serializing_if = "core::option::Option::is_none"
+        )
+    )]
+    pub ideal_pattern: Option<Regex>,
+}
+
+impl ValuePatternConstraint {
+    /// Consumes `self`, returning a corresponding constraint
+    /// with the exact required pattern set to `exact_pattern`.
+    #[inline]
+    pub fn exact<U>(mut self, exact_pattern: U) -> Self
+    where
+        Option<Regex>: From<U>,
+    {
+        self.exact_pattern = exact_pattern.into();
+        self
+    }
+
+    /// Consumes `self`, returning a corresponding constraint
+    /// with the ideal pattern set to `ideal_pattern`.
+    #[inline]
+    pub fn ideal<U>(mut self, ideal_pattern: U) -> Self
+    where
+        Option<Regex>: From<U>,
+    {
+        self.ideal_pattern = ideal_pattern.into();
+        self
+    }
+
+    /// Returns `true` if a required pattern is set, otherwise `false`.
+    pub fn is_required(&self) -> bool {
+        self.exact_pattern.is_some()
+    }
+
+    /// Returns `true` if neither pattern is set, otherwise `false`.
+    pub fn is_empty(&self) -> bool {
+        self.exact_pattern.is_none() && self.ideal_pattern.is_none()
+    }
+
+    /// Returns a corresponding constraint containing only required values.
+    pub fn to_required_only(&self) -> Self {
+        self.clone().into_required_only()
+    }
+
+    /// Consumes `self, returning a corresponding constraint
+    /// containing only required values.
+    pub fn into_required_only(self) -> Self {
+        Self {
+            exact_pattern: self.exact_pattern,
+            ideal_pattern: None,
+        }
+    }
+}
+
+impl Default for ValuePatternConstraint {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            exact_pattern: None,
+            ideal_pattern: None,
+        }
+    }
+}
+
+// `Regex` does not implement `PartialEq`, so we compare the patterns' source strings instead:
+impl PartialEq for ValuePatternConstraint {
+    fn eq(&self, other: &Self) -> bool {
+        fn as_str(regex: &Option<Regex>) -> Option<&str> {
+            regex.as_ref().map(Regex::as_str)
+        }
+
+        as_str(&self.exact_pattern) == as_str(&other.exact_pattern)
+            && as_str(&self.ideal_pattern) == as_str(&other.ideal_pattern)
+    }
+}
+
+impl std::fmt::Display for ValuePatternConstraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut is_first = true;
+        f.write_str("(")?;
+        if let Some(ref exact) = &self.exact_pattern {
+            f.write_fmt(format_args!("x =~ /{exact}/"))?;
+            is_first = false;
+        }
+        if let Some(ref ideal) = &self.ideal_pattern {
+            if !is_first {
+                f.write_str(" && ")?;
+            }
+            f.write_fmt(format_args!("x ~= /{ideal}/"))?;
+            is_first = false;
+        }
+        if is_first {
+            f.write_str("<empty>")?;
+        }
+        f.write_str(")")?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+fn serialize_optional_regex<S>(regex: &Option<Regex>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match regex {
+        Some(regex) => serializer.serialize_some(regex.as_str()),
+        None => serializer.serialize_none(),
+    }
+}
+
+// A derived implementation would also accept sequences (e.g. `["foo", "bar"]`),
+// which would shadow the `StringSequence` variant of the untagged constraint enums.
+// So we implement it by hand, only ever accepting maps:
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ValuePatternConstraint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::{Error, MapAccess, Visitor};
+
+        const FIELDS: &[&str] = &["exactPattern", "idealPattern"];
+
+        struct PatternVisitor;
+
+        impl<'de> Visitor<'de> for PatternVisitor {
+            type Value = ValuePatternConstraint;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("a map with `exactPattern` and/or `idealPattern`")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut constraint = ValuePatternConstraint::default();
+
+                while let Some(key) = map.next_key::<String>()? {
+                    let slot = match key.as_str() {
+                        "exactPattern" => &mut constraint.exact_pattern,
+                        "idealPattern" => &mut constraint.ideal_pattern,
+                        _ => return Err(A::Error::unknown_field(&key, FIELDS)),
+                    };
+                    if slot.is_some() {
+                        return Err(A::Error::custom(format_args!("duplicate field `{key}`")));
+                    }
+                    let pattern: String = map.next_value()?;
+                    *slot = Some(Regex::new(&pattern).map_err(A::Error::custom)?);
+                }
+
+                Ok(constraint)
+            }
+        }
+
+        deserializer.deserialize_map(PatternVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Subject = ValuePatternConstraint;
+
+    fn regex(pattern: &str) -> Regex {
+        Regex::new(pattern).unwrap()
+    }
+
+    #[test]
+    fn to_string() {
+        let scenarios = [
+            (Subject::default(), "(<empty>)"),
+            (Subject::default().exact(regex("^usb-")), "(x =~ /^usb-/)"),
+            (Subject::default().ideal(regex("^usb-")), "(x ~= /^usb-/)"),
+            (
+                Subject::default()
+                    .exact(regex("^usb-"))
+                    .ideal(regex("-hd$")),
+                "(x =~ /^usb-/ && x ~= /-hd$/)",
+            ),
+        ];
+
+        for (constraint, expected) in scenarios {
+            let actual = constraint.to_string();
+
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn is_required() {
+        let scenarios = [
+            (Subject::default(), false),
+            (Subject::default().exact(regex("^usb-")), true),
+            (Subject::default().ideal(regex("^usb-")), false),
+        ];
+
+        for (constraint, expected) in scenarios {
+            let actual = constraint.is_required();
+
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn eq() {
+        assert_eq!(
+            Subject::default().exact(regex("^usb-")),
+            Subject::default().exact(regex("^usb-"))
+        );
+        assert_ne!(
+            Subject::default().exact(regex("^usb-")),
+            Subject::default().ideal(regex("^usb-"))
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(test)]
+mod serde_tests {
+    use super::*;
+    use crate::macros::test_serde_symmetry;
+
+    type Subject = ValuePatternConstraint;
+
+    #[test]
+    fn customized() {
+        let subject = Subject::default()
+            .exact(Regex::new("^usb-").unwrap())
+            .ideal(Regex::new("-hd$").unwrap());
+        let json = serde_json::json!({
+            "exactPattern": "^usb-",
+            "idealPattern": "-hd$",
+        });
+
+        test_serde_symmetry!(subject: subject, json: json);
+    }
+
+    #[test]
+    fn invalid_pattern() {
+        let json = serde_json::json!({
+            "exactPattern": "(",
+        });
+
+        assert!(serde_json::from_value::<Subject>(json).is_err());
+    }
+}
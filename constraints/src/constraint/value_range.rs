@@ -225,6 +225,18 @@ impl<T> Default for ResolvedValueRangeConstraint<T> {
     }
 }
 
+impl<T> std::fmt::Display for ValueRangeConstraint<T>
+where
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bare(bare) => f.write_fmt(format_args!("{bare:?}")),
+            Self::Constraint(constraint) => constraint.fmt(f),
+        }
+    }
+}
+
 impl<T> std::fmt::Display for ResolvedValueRangeConstraint<T>
 where
     T: std::fmt::Debug,
@@ -264,6 +276,39 @@ where
 mod tests {
     use super::*;
 
+    mod unresolved {
+        use super::*;
+
+        type Subject = ValueRangeConstraint<u64>;
+
+        #[test]
+        fn to_string() {
+            let scenarios = [
+                (Subject::Bare(1000), "1000"),
+                (
+                    Subject::Constraint(ResolvedValueRangeConstraint::default().min(1000)),
+                    "(1000 <= x)",
+                ),
+                (
+                    Subject::Constraint(ResolvedValueRangeConstraint::default().max(2000)),
+                    "(x <= 2000)",
+                ),
+                (
+                    Subject::Constraint(
+                        ResolvedValueRangeConstraint::default().min(1000).max(2000),
+                    ),
+                    "(1000 <= x <= 2000)",
+                ),
+            ];
+
+            for (constraint, expected) in scenarios {
+                let actual = constraint.to_string();
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
     #[test]
     fn to_string() {
         let scenarios = [
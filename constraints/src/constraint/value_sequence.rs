@@ -196,6 +196,18 @@ impl<T> Default for ResolvedValueSequenceConstraint<T> {
     }
 }
 
+impl<T> std::fmt::Display for ValueSequenceConstraint<T>
+where
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bare(bare) => f.write_fmt(format_args!("{bare:?}")),
+            Self::Constraint(constraint) => constraint.fmt(f),
+        }
+    }
+}
+
 impl<T> std::fmt::Display for ResolvedValueSequenceConstraint<T>
 where
     T: std::fmt::Debug,
@@ -226,6 +238,31 @@ where
 mod tests {
     use super::*;
 
+    mod unresolved {
+        use super::*;
+
+        type Subject = ValueSequenceConstraint<u64>;
+
+        #[test]
+        fn to_string() {
+            let scenarios = [
+                (Subject::Bare(vec![1, 2]), "[1, 2]"),
+                (
+                    Subject::Constraint(
+                        ResolvedValueSequenceConstraint::default().exact(vec![1, 2]),
+                    ),
+                    "(x == [1, 2])",
+                ),
+            ];
+
+            for (constraint, expected) in scenarios {
+                let actual = constraint.to_string();
+
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
     #[test]
     fn to_string() {
         let scenarios = [
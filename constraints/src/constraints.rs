@@ -1,4 +1,6 @@
 mod advanced;
+mod builder;
+mod cache;
 mod constraint_set;
 mod mandatory;
 mod stream;
@@ -8,8 +10,11 @@ pub use self::advanced::{
     AdvancedMediaTrackConstraints, ResolvedAdvancedMediaTrackConstraints,
     SanitizedAdvancedMediaTrackConstraints,
 };
+pub use self::builder::MediaTrackConstraintsBuilder;
+pub use self::cache::SanitizedConstraintsCache;
 pub use self::constraint_set::{
-    MediaTrackConstraintSet, ResolvedMediaTrackConstraintSet, SanitizedMediaTrackConstraintSet,
+    MediaTrackConstraintSet, ResolvedMediaTrackConstraintSet, SanitizationDropReason,
+    SanitizedMediaTrackConstraintSet,
 };
 pub use self::mandatory::{
     MandatoryMediaTrackConstraints, ResolvedMandatoryMediaTrackConstraints,
@@ -18,5 +23,5 @@ pub use self::mandatory::{
 pub use self::stream::MediaStreamConstraints;
 pub use self::track::{
     BoolOrMediaTrackConstraints, MediaTrackConstraints, ResolvedMediaTrackConstraints,
-    SanitizedMediaTrackConstraints,
+    SanitizationError, SanitizationReport, SanitizedMediaTrackConstraints,
 };
@@ -34,6 +34,10 @@ pub type SanitizedAdvancedMediaTrackConstraints =
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "T: Deserialize<'de> + crate::MediaTrackConstraintKind"))
+)]
 pub struct GenericAdvancedMediaTrackConstraints<T>(Vec<GenericMediaTrackConstraintSet<T>>);
 
 impl<T> GenericAdvancedMediaTrackConstraints<T> {
@@ -92,7 +96,24 @@ impl AdvancedMediaTrackConstraints {
     }
 
     pub fn into_resolved(self) -> ResolvedAdvancedMediaTrackConstraints {
-        let strategy = MediaTrackConstraintResolutionStrategy::BareToExact;
+        self.into_resolved_with(MediaTrackConstraintResolutionStrategy::BareToExact)
+    }
+
+    /// Returns a resolved representation with bare values resolved using `strategy`
+    /// (rather than the spec's default of `BareToExact`).
+    pub fn to_resolved_with(
+        &self,
+        strategy: MediaTrackConstraintResolutionStrategy,
+    ) -> ResolvedAdvancedMediaTrackConstraints {
+        self.clone().into_resolved_with(strategy)
+    }
+
+    /// Consumes `self`, returning a resolved representation with bare values resolved
+    /// using `strategy` (rather than the spec's default of `BareToExact`).
+    pub fn into_resolved_with(
+        self,
+        strategy: MediaTrackConstraintResolutionStrategy,
+    ) -> ResolvedAdvancedMediaTrackConstraints {
         ResolvedAdvancedMediaTrackConstraints::from_iter(
             self.into_iter()
                 .map(|constraint_set| constraint_set.into_resolved(strategy)),
@@ -125,7 +146,9 @@ impl ResolvedAdvancedMediaTrackConstraints {
 mod serde_tests {
     use super::*;
     use crate::property::all::name::*;
-    use crate::MediaTrackConstraintSet;
+    use crate::{
+        MediaTrackConstraintSet, ResolvedMediaTrackConstraintSet, ResolvedValueRangeConstraint,
+    };
 
     #[test]
     fn serialize_default() {
@@ -188,4 +211,28 @@ mod serde_tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn deserialize_and_resolve_bare_values() {
+        let json = serde_json::json!([
+            { "width": 1280 },
+            { "width": { "ideal": 1280 } },
+        ]);
+        let advanced: AdvancedMediaTrackConstraints = serde_json::from_value(json).unwrap();
+
+        let actual = advanced.into_resolved();
+        // Bare values within advanced constraint sets resolve to `exact` (rather than `ideal`):
+        let expected = ResolvedAdvancedMediaTrackConstraints::new(vec![
+            ResolvedMediaTrackConstraintSet::from_iter([(
+                &WIDTH,
+                ResolvedValueRangeConstraint::default().exact(1280).into(),
+            )]),
+            ResolvedMediaTrackConstraintSet::from_iter([(
+                &WIDTH,
+                ResolvedValueRangeConstraint::default().ideal(1280).into(),
+            )]),
+        ]);
+
+        assert_eq!(actual, expected);
+    }
 }
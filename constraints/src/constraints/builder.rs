@@ -0,0 +1,321 @@
+use super::advanced::AdvancedMediaTrackConstraints;
+use super::constraint_set::MediaTrackConstraintSet;
+use super::mandatory::MandatoryMediaTrackConstraints;
+use super::track::MediaTrackConstraints;
+use crate::property::all::name::*;
+use crate::{
+    CursorCapture, DisplaySurface, FacingMode, MediaTrackConstraint, MediaTrackProperty,
+    ResizeMode, ResolvedValueConstraint, ResolvedValueSequenceConstraint, ValueConstraint,
+    ValueRangeConstraint, ValueSequenceConstraint,
+};
+
+/// A builder for [`MediaTrackConstraints`] with per-property typed setters.
+///
+/// Unlike constructing constraints via `FromIterator` each setter only accepts
+/// the kind of constraint that is applicable to its property
+/// (e.g. a range constraint for `width`, a `FacingMode` constraint for `facingMode`),
+/// turning a mismatch of property and constraint kind into a compile error.
+///
+/// # W3C Spec Compliance
+///
+/// There exists no corresponding type in the W3C ["Media Capture and Streams"][media_capture_and_streams_spec] spec.
+///
+/// [media_capture_and_streams_spec]: https://www.w3.org/TR/mediacapture-streams/
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MediaTrackConstraintsBuilder {
+    mandatory: MediaTrackConstraintSet,
+    advanced: Vec<MediaTrackConstraintSet>,
+}
+
+macro_rules! typed_setters {
+    ($($(#[$meta:meta])* $name:ident: $property:ident => $constraint:ty,)*) => {
+        $(
+            $(#[$meta])*
+            #[inline]
+            pub fn $name<U>(self, constraint: U) -> Self
+            where
+                $constraint: From<U>,
+            {
+                self.constraint(&$property, <$constraint>::from(constraint))
+            }
+        )*
+    };
+}
+
+macro_rules! enumeration_setters {
+    ($(
+        $(#[$meta:meta])* $name:ident,
+        $(#[$sequence_meta:meta])* $sequence_name:ident: $property:ident => $enumeration:ty,
+    )*) => {
+        $(
+            $(#[$meta])*
+            #[inline]
+            pub fn $name<U>(self, constraint: U) -> Self
+            where
+                ValueConstraint<$enumeration>: From<U>,
+            {
+                let constraint = ValueConstraint::<$enumeration>::from(constraint);
+                self.constraint(&$property, stringify_value_constraint(constraint))
+            }
+
+            $(#[$sequence_meta])*
+            #[inline]
+            pub fn $sequence_name<U>(self, constraint: U) -> Self
+            where
+                ValueSequenceConstraint<$enumeration>: From<U>,
+            {
+                let constraint = ValueSequenceConstraint::<$enumeration>::from(constraint);
+                self.constraint(&$property, stringify_value_sequence_constraint(constraint))
+            }
+        )*
+    };
+}
+
+impl MediaTrackConstraintsBuilder {
+    typed_setters! {
+        /// Consumes `self`, returning a builder with a `deviceId` constraint.
+        device_id: DEVICE_ID => ValueConstraint<String>,
+        /// Consumes `self`, returning a builder with a `deviceId` sequence constraint.
+        device_id_sequence: DEVICE_ID => ValueSequenceConstraint<String>,
+        /// Consumes `self`, returning a builder with a `groupId` constraint.
+        group_id: GROUP_ID => ValueConstraint<String>,
+        /// Consumes `self`, returning a builder with a `groupId` sequence constraint.
+        group_id_sequence: GROUP_ID => ValueSequenceConstraint<String>,
+        /// Consumes `self`, returning a builder with an `autoGainControl` constraint.
+        auto_gain_control: AUTO_GAIN_CONTROL => ValueConstraint<bool>,
+        /// Consumes `self`, returning a builder with a `channelCount` constraint.
+        channel_count: CHANNEL_COUNT => ValueRangeConstraint<u64>,
+        /// Consumes `self`, returning a builder with an `echoCancellation` constraint.
+        echo_cancellation: ECHO_CANCELLATION => ValueConstraint<bool>,
+        /// Consumes `self`, returning a builder with a `latency` constraint.
+        latency: LATENCY => ValueRangeConstraint<f64>,
+        /// Consumes `self`, returning a builder with a `noiseSuppression` constraint.
+        noise_suppression: NOISE_SUPPRESSION => ValueConstraint<bool>,
+        /// Consumes `self`, returning a builder with a `sampleRate` constraint.
+        sample_rate: SAMPLE_RATE => ValueRangeConstraint<u64>,
+        /// Consumes `self`, returning a builder with a `sampleSize` constraint.
+        sample_size: SAMPLE_SIZE => ValueRangeConstraint<u64>,
+        /// Consumes `self`, returning a builder with an `aspectRatio` constraint.
+        aspect_ratio: ASPECT_RATIO => ValueRangeConstraint<f64>,
+        /// Consumes `self`, returning a builder with a `frameRate` constraint.
+        frame_rate: FRAME_RATE => ValueRangeConstraint<f64>,
+        /// Consumes `self`, returning a builder with a `height` constraint.
+        height: HEIGHT => ValueRangeConstraint<u64>,
+        /// Consumes `self`, returning a builder with a `width` constraint.
+        width: WIDTH => ValueRangeConstraint<u64>,
+        /// Consumes `self`, returning a builder with a `logicalSurface` constraint.
+        logical_surface: LOGICAL_SURFACE => ValueConstraint<bool>,
+        /// Consumes `self`, returning a builder with a `suppressLocalAudioPlayback` constraint.
+        suppress_local_audio_playback: SUPPRESS_LOCAL_AUDIO_PLAYBACK => ValueConstraint<bool>,
+    }
+
+    enumeration_setters! {
+        /// Consumes `self`, returning a builder with a `facingMode` constraint.
+        facing_mode,
+        /// Consumes `self`, returning a builder with a `facingMode` sequence constraint.
+        facing_mode_sequence: FACING_MODE => FacingMode,
+        /// Consumes `self`, returning a builder with a `resizeMode` constraint.
+        resize_mode,
+        /// Consumes `self`, returning a builder with a `resizeMode` sequence constraint.
+        resize_mode_sequence: RESIZE_MODE => ResizeMode,
+        /// Consumes `self`, returning a builder with a `displaySurface` constraint.
+        display_surface,
+        /// Consumes `self`, returning a builder with a `displaySurface` sequence constraint.
+        display_surface_sequence: DISPLAY_SURFACE => DisplaySurface,
+        /// Consumes `self`, returning a builder with a `cursor` constraint.
+        cursor,
+        /// Consumes `self`, returning a builder with a `cursor` sequence constraint.
+        cursor_sequence: CURSOR => CursorCapture,
+    }
+
+    /// Consumes `self`, returning a builder with an untyped constraint for `property`.
+    ///
+    /// Useful for non-standard properties, for which there exists no typed setter.
+    pub fn constraint<P, C>(mut self, property: P, constraint: C) -> Self
+    where
+        P: Into<MediaTrackProperty>,
+        C: Into<MediaTrackConstraint>,
+    {
+        self.mandatory.insert(property.into(), constraint.into());
+        self
+    }
+
+    /// Consumes `self`, returning a builder with the mandatory constraints
+    /// of `constraint_set` appended as an advanced constraint set,
+    /// followed by the advanced constraint sets of `constraint_set` (if any).
+    pub fn advanced(mut self, constraint_set: MediaTrackConstraintsBuilder) -> Self {
+        self.advanced.push(constraint_set.mandatory);
+        self.advanced.extend(constraint_set.advanced);
+        self
+    }
+
+    /// Consumes `self`, returning the corresponding constraints.
+    pub fn build(self) -> MediaTrackConstraints {
+        MediaTrackConstraints {
+            mandatory: MandatoryMediaTrackConstraints::new(self.mandatory),
+            advanced: AdvancedMediaTrackConstraints::new(self.advanced),
+        }
+    }
+}
+
+fn stringify_value_constraint<T>(constraint: ValueConstraint<T>) -> ValueConstraint<String>
+where
+    T: ToString,
+{
+    match constraint {
+        ValueConstraint::Bare(bare) => ValueConstraint::Bare(bare.to_string()),
+        ValueConstraint::Constraint(constraint) => {
+            ValueConstraint::Constraint(ResolvedValueConstraint {
+                exact: constraint.exact.map(|exact| exact.to_string()),
+                ideal: constraint.ideal.map(|ideal| ideal.to_string()),
+            })
+        }
+    }
+}
+
+fn stringify_value_sequence_constraint<T>(
+    constraint: ValueSequenceConstraint<T>,
+) -> ValueSequenceConstraint<String>
+where
+    T: ToString,
+{
+    fn stringify<T: ToString>(values: Vec<T>) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    match constraint {
+        ValueSequenceConstraint::Bare(bare) => ValueSequenceConstraint::Bare(stringify(bare)),
+        ValueSequenceConstraint::Constraint(constraint) => {
+            ValueSequenceConstraint::Constraint(ResolvedValueSequenceConstraint {
+                exact: constraint.exact.map(stringify),
+                ideal: constraint.ideal.map(stringify),
+            })
+        }
+    }
+}
+
+impl MediaTrackConstraints {
+    /// Returns a builder for constraints with per-property typed setters.
+    pub fn builder() -> MediaTrackConstraintsBuilder {
+        MediaTrackConstraintsBuilder::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ResolvedValueRangeConstraint;
+
+    type Subject = MediaTrackConstraintsBuilder;
+
+    #[test]
+    fn default() {
+        let actual = Subject::default().build();
+        let expected = MediaTrackConstraints::default();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn mandatory() {
+        let actual = MediaTrackConstraints::builder()
+            .width(ResolvedValueRangeConstraint::default().min(640))
+            .frame_rate(30.0)
+            .facing_mode(ResolvedValueConstraint::default().exact(FacingMode::User))
+            .echo_cancellation(true)
+            .build();
+
+        let expected = MediaTrackConstraints {
+            mandatory: MandatoryMediaTrackConstraints::from_iter([
+                (
+                    &WIDTH,
+                    ResolvedValueRangeConstraint::default().min(640).into(),
+                ),
+                (&FRAME_RATE, 30.0.into()),
+                (
+                    &FACING_MODE,
+                    ResolvedValueConstraint::default()
+                        .exact(FacingMode::user())
+                        .into(),
+                ),
+                (&ECHO_CANCELLATION, true.into()),
+            ]),
+            advanced: AdvancedMediaTrackConstraints::default(),
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn sequence() {
+        let actual = MediaTrackConstraints::builder()
+            .device_id_sequence(vec!["front".to_owned(), "back".to_owned()])
+            .facing_mode_sequence(
+                ResolvedValueSequenceConstraint::default()
+                    .ideal(vec![FacingMode::Environment, FacingMode::Left]),
+            )
+            .cursor_sequence(CursorCapture::Never)
+            .build();
+
+        let expected = MediaTrackConstraints {
+            mandatory: MandatoryMediaTrackConstraints::from_iter([
+                (&DEVICE_ID, vec!["front", "back"].into()),
+                (
+                    &FACING_MODE,
+                    ResolvedValueSequenceConstraint::default()
+                        .ideal(vec![FacingMode::environment(), FacingMode::left()])
+                        .into(),
+                ),
+                (&CURSOR, vec!["never"].into()),
+            ]),
+            advanced: AdvancedMediaTrackConstraints::default(),
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn advanced() {
+        let actual = MediaTrackConstraints::builder()
+            .device_id("camera".to_owned())
+            .advanced(Subject::default().width(1920).height(1080))
+            .advanced(Subject::default().constraint("zoom", 2.0))
+            .build();
+
+        let expected = MediaTrackConstraints {
+            mandatory: MandatoryMediaTrackConstraints::from_iter([(
+                &DEVICE_ID,
+                "camera".to_owned().into(),
+            )]),
+            advanced: AdvancedMediaTrackConstraints::new(vec![
+                MediaTrackConstraintSet::from_iter([(&WIDTH, 1920.into()), (&HEIGHT, 1080.into())]),
+                MediaTrackConstraintSet::from_iter([("zoom", 2.0.into())]),
+            ]),
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn nested_advanced() {
+        let actual = MediaTrackConstraints::builder()
+            .advanced(
+                Subject::default()
+                    .width(1920)
+                    .advanced(Subject::default().height(1080)),
+            )
+            .advanced(Subject::default().frame_rate(60.0))
+            .build();
+
+        let expected = MediaTrackConstraints {
+            mandatory: MandatoryMediaTrackConstraints::default(),
+            advanced: AdvancedMediaTrackConstraints::new(vec![
+                MediaTrackConstraintSet::from_iter([(&WIDTH, 1920.into())]),
+                MediaTrackConstraintSet::from_iter([(&HEIGHT, 1080.into())]),
+                MediaTrackConstraintSet::from_iter([(&FRAME_RATE, 60.0.into())]),
+            ]),
+        };
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{
+    MediaTrackSupportedConstraints, ResolvedMediaTrackConstraints, SanitizedMediaTrackConstraints,
+};
+
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    constraints: ResolvedMediaTrackConstraints,
+    supported_constraints: MediaTrackSupportedConstraints,
+    sanitized: SanitizedMediaTrackConstraints,
+}
+
+/// A cache memoizing the results of sanitizing constraints against sets of supported constraints,
+/// for repeatedly selecting settings with the same constraints (e.g. across many device lists).
+///
+/// Entries are looked up by equality (as constraints are not hashable),
+/// so the cache is meant to hold a handful of distinct entries, rather than thousands.
+/// Once it holds `capacity` entries the least recently used entry gets evicted
+/// for every new one, which bounds its size even for constraints that never compare
+/// equal to themselves (e.g. ones containing `NaN`).
+#[derive(Debug, Clone)]
+pub struct SanitizedConstraintsCache {
+    entries: Vec<CacheEntry>,
+    capacity: usize,
+}
+
+impl Default for SanitizedConstraintsCache {
+    fn default() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl SanitizedConstraintsCache {
+    /// The capacity of caches created via `new()`.
+    pub const DEFAULT_CAPACITY: usize = 16;
+
+    /// Creates an empty cache with a capacity of `DEFAULT_CAPACITY` entries.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty cache holding at most `capacity` entries (but at least one).
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: vec![],
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Returns the result of sanitizing `constraints` against `supported_constraints`,
+    /// sanitizing them only if they have not been sanitized against those before
+    /// (or if the corresponding entry has been evicted since).
+    pub fn get_or_sanitize(
+        &mut self,
+        constraints: &ResolvedMediaTrackConstraints,
+        supported_constraints: &MediaTrackSupportedConstraints,
+    ) -> &SanitizedMediaTrackConstraints {
+        let index = self.entries.iter().position(|entry| {
+            &entry.constraints == constraints
+                && &entry.supported_constraints == supported_constraints
+        });
+
+        // Entries are kept in order of use, with the most recently used one last:
+        let entry = match index {
+            Some(index) => self.entries.remove(index),
+            None => {
+                if self.entries.len() >= self.capacity {
+                    self.entries.remove(0);
+                }
+                CacheEntry {
+                    constraints: constraints.clone(),
+                    supported_constraints: supported_constraints.clone(),
+                    sanitized: constraints.to_sanitized(supported_constraints),
+                }
+            }
+        };
+        self.entries.push(entry);
+
+        &self.entries[self.entries.len() - 1].sanitized
+    }
+
+    /// Returns the maximum number of cached entries.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of cached entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the cache holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes all cached entries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::iter::FromIterator;
+
+    use super::*;
+    use crate::property::all::name::*;
+    use crate::{
+        ResolvedAdvancedMediaTrackConstraints, ResolvedMandatoryMediaTrackConstraints,
+        ResolvedValueConstraint, ResolvedValueRangeConstraint,
+    };
+
+    #[test]
+    fn get_or_sanitize() {
+        let constraints = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([
+                (
+                    &DEVICE_ID,
+                    ResolvedValueConstraint::default()
+                        .exact("device".to_owned())
+                        .into(),
+                ),
+                (
+                    &WIDTH,
+                    ResolvedValueRangeConstraint::default().ideal(1920).into(),
+                ),
+            ]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        };
+        let all_supported = MediaTrackSupportedConstraints::from_iter([&DEVICE_ID, &WIDTH]);
+        let width_supported = MediaTrackSupportedConstraints::from_iter([&WIDTH]);
+
+        let mut cache = SanitizedConstraintsCache::new();
+        assert!(cache.is_empty());
+
+        let sanitized = cache.get_or_sanitize(&constraints, &all_supported).clone();
+        assert_eq!(sanitized, constraints.to_sanitized(&all_supported));
+        assert_eq!(cache.len(), 1);
+
+        // Equal inputs hit the existing entry:
+        let cached = cache.get_or_sanitize(&constraints.clone(), &all_supported.clone());
+        assert_eq!(cached, &sanitized);
+        assert_eq!(cache.len(), 1);
+
+        // A different set of supported constraints produces a different entry:
+        let other = cache
+            .get_or_sanitize(&constraints, &width_supported)
+            .clone();
+        assert_eq!(other, constraints.to_sanitized(&width_supported));
+        assert_ne!(other, sanitized);
+        assert_eq!(cache.len(), 2);
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn eviction() {
+        let constraints = |aspect_ratio: f64| ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &ASPECT_RATIO,
+                ResolvedValueRangeConstraint::default()
+                    .ideal(aspect_ratio)
+                    .into(),
+            )]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        };
+        let supported = MediaTrackSupportedConstraints::from_iter([&ASPECT_RATIO]);
+
+        let mut cache = SanitizedConstraintsCache::with_capacity(2);
+        assert_eq!(cache.capacity(), 2);
+
+        cache.get_or_sanitize(&constraints(1.0), &supported);
+        cache.get_or_sanitize(&constraints(2.0), &supported);
+        // Using the first entry makes the second one the least recently used:
+        cache.get_or_sanitize(&constraints(1.0), &supported);
+        cache.get_or_sanitize(&constraints(3.0), &supported);
+        assert_eq!(cache.len(), 2);
+
+        let cached: Vec<_> = cache
+            .entries
+            .iter()
+            .map(|entry| entry.constraints.clone())
+            .collect();
+        assert_eq!(cached, vec![constraints(1.0), constraints(3.0)]);
+
+        // Constraints containing `NaN` never hit, yet don't grow the cache beyond its capacity:
+        for _ in 0..10 {
+            cache.get_or_sanitize(&constraints(f64::NAN), &supported);
+        }
+        assert_eq!(cache.len(), 2);
+
+        assert_eq!(SanitizedConstraintsCache::with_capacity(0).capacity(), 1);
+    }
+}
@@ -3,9 +3,13 @@ use std::ops::{Deref, DerefMut};
 
 use indexmap::IndexMap;
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 use crate::constraint::SanitizedMediaTrackConstraint;
+#[cfg(feature = "serde")]
+use crate::property::{all as all_properties, MediaTrackPropertyValueKind};
+#[cfg(feature = "serde")]
+use crate::MediaTrackConstraintKind;
 use crate::{
     MediaTrackConstraint, MediaTrackConstraintResolutionStrategy, MediaTrackProperty,
     MediaTrackSupportedConstraints, ResolvedMediaTrackConstraint,
@@ -33,7 +37,7 @@ pub type SanitizedMediaTrackConstraintSet =
 /// [media_track_constraint_set]: https://www.w3.org/TR/mediacapture-streams/#dom-mediatrackconstraintset
 /// [media_capture_and_streams_spec]: https://www.w3.org/TR/mediacapture-streams/
 #[derive(Debug, Clone, Eq, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
 pub struct GenericMediaTrackConstraintSet<T>(IndexMap<MediaTrackProperty, T>);
 
@@ -47,6 +51,83 @@ impl<T> GenericMediaTrackConstraintSet<T> {
     }
 }
 
+// A derived implementation would accept constraints of any kind for any property
+// (e.g. `"width": "wide"`) and report failures without mentioning the property.
+// So we implement it by hand, validating constraints of standard properties against their value kind:
+#[cfg(feature = "serde")]
+impl<'de, T> Deserialize<'de> for GenericMediaTrackConstraintSet<T>
+where
+    T: Deserialize<'de> + MediaTrackConstraintKind,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use std::marker::PhantomData;
+
+        use serde::de::{Error, MapAccess, Visitor};
+
+        struct ConstraintSetVisitor<T>(PhantomData<T>);
+
+        impl<'de, T> Visitor<'de> for ConstraintSetVisitor<T>
+        where
+            T: Deserialize<'de> + MediaTrackConstraintKind,
+        {
+            type Value = GenericMediaTrackConstraintSet<T>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("a map of constraints")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut constraint_set = IndexMap::new();
+
+                while let Some(property) = map.next_key::<MediaTrackProperty>()? {
+                    let constraint: T = map.next_value().map_err(|error| {
+                        A::Error::custom(format_args!(
+                            "invalid value for constraint {:?}: {error}",
+                            property.name()
+                        ))
+                    })?;
+
+                    if let Some(info) = all_properties::info(property.name()) {
+                        if !constraint.is_applicable_to(info.kind) {
+                            return Err(A::Error::custom(format_args!(
+                                "invalid value for constraint {:?}: expected {}, got {}",
+                                property.name(),
+                                expected_kind_description(info.kind),
+                                constraint.kind_description()
+                            )));
+                        }
+                    }
+
+                    constraint_set.insert(property, constraint);
+                }
+
+                Ok(GenericMediaTrackConstraintSet(constraint_set))
+            }
+        }
+
+        deserializer.deserialize_map(ConstraintSetVisitor(PhantomData))
+    }
+}
+
+#[cfg(feature = "serde")]
+fn expected_kind_description(kind: MediaTrackPropertyValueKind) -> &'static str {
+    match kind {
+        MediaTrackPropertyValueKind::Bool => "boolean",
+        MediaTrackPropertyValueKind::Integer | MediaTrackPropertyValueKind::Float => {
+            "number or range"
+        }
+        MediaTrackPropertyValueKind::String | MediaTrackPropertyValueKind::Enum => {
+            "string or sequence of strings"
+        }
+    }
+}
+
 impl<T> Deref for GenericMediaTrackConstraintSet<T> {
     type Target = IndexMap<MediaTrackProperty, T>;
 
@@ -88,6 +169,23 @@ impl<T> IntoIterator for GenericMediaTrackConstraintSet<T> {
     }
 }
 
+impl<T> GenericMediaTrackConstraintSet<T>
+where
+    T: Clone,
+{
+    /// Returns a constraint set containing the constraints of both, `self` and `other`,
+    /// with constraints from `other` replacing those of `self` for the same property.
+    pub fn merge(&self, other: &Self) -> Self {
+        let mut merged = self.clone();
+        merged.extend(
+            other
+                .iter()
+                .map(|(property, constraint)| (property.clone(), constraint.clone())),
+        );
+        merged
+    }
+}
+
 impl MediaTrackConstraintSet {
     pub fn to_resolved(
         &self,
@@ -106,6 +204,30 @@ impl MediaTrackConstraintSet {
                 .collect(),
         )
     }
+
+    /// Returns a sanitized representation with bare values resolved using `strategy`.
+    ///
+    /// Unlike track constraints a standalone set has no implied role,
+    /// hence the strategy has to be provided explicitly:
+    /// `BareToIdeal` for mandatory sets, `BareToExact` for advanced sets.
+    pub fn to_sanitized(
+        &self,
+        strategy: MediaTrackConstraintResolutionStrategy,
+        supported_constraints: &MediaTrackSupportedConstraints,
+    ) -> SanitizedMediaTrackConstraintSet {
+        self.clone().into_sanitized(strategy, supported_constraints)
+    }
+
+    /// Consumes `self`, returning a sanitized representation
+    /// with bare values resolved using `strategy`.
+    pub fn into_sanitized(
+        self,
+        strategy: MediaTrackConstraintResolutionStrategy,
+        supported_constraints: &MediaTrackSupportedConstraints,
+    ) -> SanitizedMediaTrackConstraintSet {
+        self.into_resolved(strategy)
+            .into_sanitized(supported_constraints)
+    }
 }
 
 impl ResolvedMediaTrackConstraintSet {
@@ -134,6 +256,138 @@ impl ResolvedMediaTrackConstraintSet {
             .collect();
         SanitizedMediaTrackConstraintSet::new(index_map)
     }
+
+    /// Returns the properties whose constraints `into_sanitized` would drop,
+    /// along with the reason for dropping each.
+    pub fn dropped_by_sanitization(
+        &self,
+        supported_constraints: &MediaTrackSupportedConstraints,
+    ) -> Vec<(MediaTrackProperty, SanitizationDropReason)> {
+        self.iter()
+            .filter_map(|(property, constraint)| {
+                if !supported_constraints.contains(property) {
+                    Some((property.clone(), SanitizationDropReason::Unsupported))
+                } else if constraint.is_empty() {
+                    Some((property.clone(), SanitizationDropReason::Empty))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+/// The reason for a constraint getting dropped during sanitization.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum SanitizationDropReason {
+    /// The constraint's property is not among the supported constraints.
+    Unsupported,
+    /// The constraint is empty (i.e. it does not constrain anything).
+    Empty,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::property::all::name::*;
+    use crate::ResolvedValueRangeConstraint;
+
+    #[test]
+    fn resolve_and_sanitize() {
+        use crate::ResolvedValueConstraint;
+
+        let constraint_set = MediaTrackConstraintSet::from_iter([
+            (&FRAME_RATE, 30.0.into()),
+            (&DEVICE_ID, "device-id".into()),
+            (&GROUP_ID, MediaTrackConstraint::default()),
+        ]);
+
+        let actual =
+            constraint_set.to_resolved(MediaTrackConstraintResolutionStrategy::BareToIdeal);
+        let expected = ResolvedMediaTrackConstraintSet::from_iter([
+            (
+                &FRAME_RATE,
+                ResolvedValueRangeConstraint::default().ideal(30.0).into(),
+            ),
+            (
+                &DEVICE_ID,
+                ResolvedValueConstraint::default()
+                    .ideal("device-id".to_owned())
+                    .into(),
+            ),
+            (&GROUP_ID, ResolvedMediaTrackConstraint::default()),
+        ]);
+        assert_eq!(actual, expected);
+
+        let actual =
+            constraint_set.to_resolved(MediaTrackConstraintResolutionStrategy::BareToExact);
+        let expected = ResolvedMediaTrackConstraintSet::from_iter([
+            (
+                &FRAME_RATE,
+                ResolvedValueRangeConstraint::default().exact(30.0).into(),
+            ),
+            (
+                &DEVICE_ID,
+                ResolvedValueConstraint::default()
+                    .exact("device-id".to_owned())
+                    .into(),
+            ),
+            (&GROUP_ID, ResolvedMediaTrackConstraint::default()),
+        ]);
+        assert_eq!(actual, expected);
+
+        // Sanitization drops both, empty and unsupported constraints:
+        let supported_constraints =
+            MediaTrackSupportedConstraints::from_iter([FRAME_RATE.clone(), GROUP_ID.clone()]);
+        let actual = constraint_set.to_sanitized(
+            MediaTrackConstraintResolutionStrategy::BareToExact,
+            &supported_constraints,
+        );
+        let expected = SanitizedMediaTrackConstraintSet::from_iter([(
+            &FRAME_RATE,
+            ResolvedMediaTrackConstraint::from(ResolvedValueRangeConstraint::default().exact(30.0))
+                .into_sanitized()
+                .unwrap(),
+        )]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn merge() {
+        let base = MediaTrackConstraintSet::from_iter([
+            (
+                &WIDTH,
+                ResolvedValueRangeConstraint::<u64>::default()
+                    .min(640)
+                    .into(),
+            ),
+            (&FRAME_RATE, 30.into()),
+        ]);
+        let overrides = MediaTrackConstraintSet::from_iter([
+            (
+                &WIDTH,
+                ResolvedValueRangeConstraint::<u64>::default()
+                    .min(1280)
+                    .into(),
+            ),
+            (&DEVICE_ID, "device-id".into()),
+        ]);
+
+        let actual = base.merge(&overrides);
+
+        let expected = MediaTrackConstraintSet::from_iter([
+            (
+                &WIDTH,
+                ResolvedValueRangeConstraint::<u64>::default()
+                    .min(1280)
+                    .into(),
+            ),
+            (&FRAME_RATE, 30.into()),
+            (&DEVICE_ID, "device-id".into()),
+        ]);
+
+        assert_eq!(actual, expected);
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -197,4 +451,43 @@ mod serde_tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn mismatched_kind() {
+        let json = serde_json::json!({
+            "width": "wide",
+        });
+
+        let error = serde_json::from_value::<MediaTrackConstraintSet>(json).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "invalid value for constraint \"width\": expected number or range, got string"
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        let json = serde_json::json!({
+            "width": { "min": "wide" },
+        });
+
+        let error = serde_json::from_value::<MediaTrackConstraintSet>(json).unwrap_err();
+
+        assert!(error
+            .to_string()
+            .starts_with("invalid value for constraint \"width\": "));
+    }
+
+    #[test]
+    fn non_standard_property() {
+        let json = serde_json::json!({
+            "zoom": "wide",
+        });
+
+        let actual: MediaTrackConstraintSet = serde_json::from_value(json).unwrap();
+        let expected = MediaTrackConstraintSet::from_iter([("zoom", "wide".into())]);
+
+        assert_eq!(actual, expected);
+    }
 }
@@ -58,6 +58,10 @@ pub type SanitizedMandatoryMediaTrackConstraints =
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "T: Deserialize<'de> + crate::MediaTrackConstraintKind"))
+)]
 pub struct GenericMandatoryMediaTrackConstraints<T>(GenericMediaTrackConstraintSet<T>);
 
 impl<T> GenericMandatoryMediaTrackConstraints<T> {
@@ -70,6 +74,17 @@ impl<T> GenericMandatoryMediaTrackConstraints<T> {
     }
 }
 
+impl<T> GenericMandatoryMediaTrackConstraints<T>
+where
+    T: Clone,
+{
+    /// Returns mandatory constraints containing the constraints of both, `self` and `other`,
+    /// with constraints from `other` replacing those of `self` for the same property.
+    pub fn merge(&self, other: &Self) -> Self {
+        Self(self.0.merge(&other.0))
+    }
+}
+
 impl GenericMandatoryMediaTrackConstraints<ResolvedMediaTrackConstraint> {
     pub fn basic(&self) -> GenericMediaTrackConstraintSet<ResolvedMediaTrackConstraint> {
         self.basic_or_required(false)
@@ -145,7 +160,24 @@ impl MandatoryMediaTrackConstraints {
     }
 
     pub fn into_resolved(self) -> ResolvedMandatoryMediaTrackConstraints {
-        let strategy = MediaTrackConstraintResolutionStrategy::BareToIdeal;
+        self.into_resolved_with(MediaTrackConstraintResolutionStrategy::BareToIdeal)
+    }
+
+    /// Returns a resolved representation with bare values resolved using `strategy`
+    /// (rather than the spec's default of `BareToIdeal`).
+    pub fn to_resolved_with(
+        &self,
+        strategy: MediaTrackConstraintResolutionStrategy,
+    ) -> ResolvedMandatoryMediaTrackConstraints {
+        self.clone().into_resolved_with(strategy)
+    }
+
+    /// Consumes `self`, returning a resolved representation with bare values resolved
+    /// using `strategy` (rather than the spec's default of `BareToIdeal`).
+    pub fn into_resolved_with(
+        self,
+        strategy: MediaTrackConstraintResolutionStrategy,
+    ) -> ResolvedMandatoryMediaTrackConstraints {
         ResolvedMandatoryMediaTrackConstraints::new(self.0.into_resolved(strategy))
     }
 }
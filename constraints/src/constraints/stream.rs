@@ -29,6 +29,10 @@ pub type MediaStreamConstraints = GenericMediaStreamConstraints<MediaTrackConstr
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "T: Deserialize<'de> + crate::MediaTrackConstraintKind"))
+)]
 pub struct GenericMediaStreamConstraints<T> {
     #[cfg_attr(feature = "serde", serde(default))]
     pub audio: GenericBoolOrMediaTrackConstraints<T>,
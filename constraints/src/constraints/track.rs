@@ -1,10 +1,15 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 use super::advanced::GenericAdvancedMediaTrackConstraints;
+use super::constraint_set::SanitizationDropReason;
 use super::mandatory::GenericMandatoryMediaTrackConstraints;
 use crate::constraint::SanitizedMediaTrackConstraint;
-use crate::{MediaTrackConstraint, MediaTrackSupportedConstraints, ResolvedMediaTrackConstraint};
+use crate::{
+    MediaTrackConstraint, MediaTrackConstraintResolutionPolicy, MediaTrackProperty,
+    MediaTrackSupportedConstraints, ResolvedMediaTrackConstraint,
+};
 
 /// A boolean on/off flag or bare value or constraints for a [`MediaStreamTrack`][media_stream_track] object.
 ///
@@ -32,6 +37,10 @@ pub type BoolOrMediaTrackConstraints = GenericBoolOrMediaTrackConstraints<MediaT
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(untagged))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "T: Deserialize<'de> + crate::MediaTrackConstraintKind"))
+)]
 pub enum GenericBoolOrMediaTrackConstraints<T> {
     /// Boolean track selector.
     Bool(bool),
@@ -96,6 +105,10 @@ pub type SanitizedMediaTrackConstraints =
 /// [media_capture_and_streams_spec]: https://www.w3.org/TR/mediacapture-streams/
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "T: Deserialize<'de> + crate::MediaTrackConstraintKind"))
+)]
 pub struct GenericMediaTrackConstraints<T> {
     /// Mandatory (i.e required or optional basic) constraints, as defined in the [spec][spec].
     ///
@@ -128,23 +141,115 @@ impl<T> Default for GenericMediaTrackConstraints<T> {
     }
 }
 
+impl<T> GenericMediaTrackConstraints<T>
+where
+    T: Clone,
+{
+    /// Returns constraints containing the constraints of both, `self` and `other`.
+    ///
+    /// Mandatory constraints from `other` replace those of `self` for the same property.
+    ///
+    /// Advanced constraint sets from `other` get appended to those of `self`,
+    /// which thus retain their precedence (as advanced constraint sets
+    /// are applied in order of appearance).
+    pub fn merge(&self, other: &Self) -> Self {
+        Self {
+            mandatory: self.mandatory.merge(&other.mandatory),
+            advanced: self
+                .advanced
+                .iter()
+                .chain(other.advanced.iter())
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+impl<T> GenericMediaTrackConstraints<T> {
+    /// Returns the names of the mandatory constraints' properties
+    /// not contained in `supported_constraints`, if any.
+    ///
+    /// Sanitization silently drops such constraints, which would otherwise
+    /// let typos (e.g. `framerate` instead of `frameRate`) go unnoticed.
+    /// Advanced constraints are not validated, as they are optional by nature.
+    pub fn validate(
+        &self,
+        supported_constraints: &MediaTrackSupportedConstraints,
+    ) -> Result<(), Vec<String>> {
+        let unsupported: Vec<String> = self
+            .mandatory
+            .keys()
+            .filter(|property| !supported_constraints.contains(property))
+            .map(|property| property.name().to_owned())
+            .collect();
+
+        if unsupported.is_empty() {
+            Ok(())
+        } else {
+            Err(unsupported)
+        }
+    }
+}
+
 impl MediaTrackConstraints {
     pub fn to_resolved(&self) -> ResolvedMediaTrackConstraints {
         self.clone().into_resolved()
     }
 
     pub fn into_resolved(self) -> ResolvedMediaTrackConstraints {
+        self.into_resolved_with(MediaTrackConstraintResolutionPolicy::default())
+    }
+
+    /// Returns a resolved representation with bare values resolved using `policy`.
+    pub fn to_resolved_with(
+        &self,
+        policy: MediaTrackConstraintResolutionPolicy,
+    ) -> ResolvedMediaTrackConstraints {
+        self.clone().into_resolved_with(policy)
+    }
+
+    /// Consumes `self`, returning a resolved representation with bare values resolved using `policy`.
+    pub fn into_resolved_with(
+        self,
+        policy: MediaTrackConstraintResolutionPolicy,
+    ) -> ResolvedMediaTrackConstraints {
         let Self {
             mandatory,
             advanced,
         } = self;
         ResolvedMediaTrackConstraints {
-            mandatory: mandatory.into_resolved(),
-            advanced: advanced.into_resolved(),
+            mandatory: mandatory.into_resolved_with(policy.mandatory),
+            advanced: advanced.into_resolved_with(policy.advanced),
         }
     }
 }
 
+/// A report of the constraints dropped when sanitizing track constraints.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct SanitizationReport {
+    /// The dropped mandatory constraints' properties, along with the reason for dropping each.
+    pub mandatory: Vec<(MediaTrackProperty, SanitizationDropReason)>,
+    /// The dropped advanced constraints' properties, along with the reason for dropping each,
+    /// and the index of the advanced constraint set they were part of.
+    pub advanced: Vec<(usize, MediaTrackProperty, SanitizationDropReason)>,
+}
+
+/// An error type indicating a failure of sanitizing track constraints.
+#[derive(Error, Clone, Eq, PartialEq, Debug)]
+pub enum SanitizationError {
+    /// An error caused by non-empty constraints getting sanitized
+    /// against an empty set of supported constraints.
+    #[error("No supported constraints provided for sanitizing non-empty constraints")]
+    NoSupportedConstraints,
+}
+
+impl SanitizationReport {
+    /// Returns `true` if no constraints were dropped, otherwise `false`.
+    pub fn is_empty(&self) -> bool {
+        self.mandatory.is_empty() && self.advanced.is_empty()
+    }
+}
+
 impl ResolvedMediaTrackConstraints {
     pub fn to_sanitized(
         &self,
@@ -164,6 +269,98 @@ impl ResolvedMediaTrackConstraints {
             advanced,
         }
     }
+
+    /// Returns a corresponding sanitized representation,
+    /// along with a report of the constraints dropped by sanitization.
+    pub fn to_sanitized_with_report(
+        &self,
+        supported_constraints: &MediaTrackSupportedConstraints,
+    ) -> (SanitizedMediaTrackConstraints, SanitizationReport) {
+        let report = SanitizationReport {
+            mandatory: self
+                .mandatory
+                .dropped_by_sanitization(supported_constraints),
+            advanced: self
+                .advanced
+                .iter()
+                .enumerate()
+                .flat_map(|(index, constraint_set)| {
+                    constraint_set
+                        .dropped_by_sanitization(supported_constraints)
+                        .into_iter()
+                        .map(move |(property, reason)| (index, property, reason))
+                })
+                .collect(),
+        };
+
+        (self.to_sanitized(supported_constraints), report)
+    }
+
+    /// Returns a corresponding sanitized representation, or an error
+    /// if `supported_constraints` is empty while `self` is not
+    /// (which would silently sanitize away all of the constraints).
+    pub fn try_to_sanitized(
+        &self,
+        supported_constraints: &MediaTrackSupportedConstraints,
+    ) -> Result<SanitizedMediaTrackConstraints, SanitizationError> {
+        self.clone().try_into_sanitized(supported_constraints)
+    }
+
+    /// Consumes `self`, returning a corresponding sanitized representation, or an error
+    /// if `supported_constraints` is empty while `self` is not
+    /// (which would silently sanitize away all of the constraints).
+    pub fn try_into_sanitized(
+        self,
+        supported_constraints: &MediaTrackSupportedConstraints,
+    ) -> Result<SanitizedMediaTrackConstraints, SanitizationError> {
+        let is_empty = self
+            .mandatory
+            .values()
+            .chain(
+                self.advanced
+                    .iter()
+                    .flat_map(|constraint_set| constraint_set.values()),
+            )
+            .all(|constraint| constraint.is_empty());
+
+        if supported_constraints.is_empty() && !is_empty {
+            return Err(SanitizationError::NoSupportedConstraints);
+        }
+
+        Ok(self.into_sanitized(supported_constraints))
+    }
+}
+
+impl SanitizedMediaTrackConstraints {
+    /// Returns `true` if no effective constraints remain, neither mandatory nor advanced ones,
+    /// otherwise `false`.
+    ///
+    /// Selecting settings against empty constraints passes all possible settings,
+    /// which allows callers to skip the selection entirely.
+    pub fn is_empty(&self) -> bool {
+        self.mandatory.is_empty()
+            && self
+                .advanced
+                .iter()
+                .all(|constraint_set| constraint_set.is_empty())
+    }
+
+    /// Returns the names of the properties constrained by either mandatory
+    /// or advanced constraints, in order of their first appearance and without duplicates.
+    pub fn constrained_properties(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = vec![];
+        let properties = self.mandatory.keys().chain(
+            self.advanced
+                .iter()
+                .flat_map(|constraint_set| constraint_set.keys()),
+        );
+        for property in properties {
+            if !names.contains(&property.name()) {
+                names.push(property.name());
+            }
+        }
+        names
+    }
 }
 
 #[cfg(test)]
@@ -174,8 +371,9 @@ mod tests {
     use crate::constraints::mandatory::MandatoryMediaTrackConstraints;
     use crate::property::all::name::*;
     use crate::{
-        AdvancedMediaTrackConstraints, ResolvedAdvancedMediaTrackConstraints,
-        ResolvedMandatoryMediaTrackConstraints, ResolvedValueConstraint,
+        AdvancedMediaTrackConstraints, MediaTrackConstraintSet, MediaTrackProperty,
+        ResolvedAdvancedMediaTrackConstraints, ResolvedMandatoryMediaTrackConstraints,
+        ResolvedMediaTrackConstraintSet, ResolvedValueConstraint, ResolvedValueRangeConstraint,
     };
 
     type Subject = BoolOrMediaTrackConstraints;
@@ -283,6 +481,261 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    mod to_resolved_with {
+        use super::*;
+        use crate::MediaTrackConstraintResolutionStrategy;
+
+        fn subject() -> MediaTrackConstraints {
+            MediaTrackConstraints {
+                mandatory: MandatoryMediaTrackConstraints::from_iter([(&WIDTH, 1280.into())]),
+                advanced: AdvancedMediaTrackConstraints::new(vec![
+                    MediaTrackConstraintSet::from_iter([(&WIDTH, 1280.into())]),
+                ]),
+            }
+        }
+
+        #[test]
+        fn default() {
+            let actual = subject().to_resolved_with(Default::default());
+            let expected = ResolvedMediaTrackConstraints {
+                mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                    &WIDTH,
+                    ResolvedValueRangeConstraint::default().ideal(1280).into(),
+                )]),
+                advanced: ResolvedAdvancedMediaTrackConstraints::new(vec![
+                    ResolvedMediaTrackConstraintSet::from_iter([(
+                        &WIDTH,
+                        ResolvedValueRangeConstraint::default().exact(1280).into(),
+                    )]),
+                ]),
+            };
+
+            assert_eq!(actual, expected);
+            assert_eq!(subject().to_resolved(), expected);
+        }
+
+        #[test]
+        fn overridden() {
+            let policy = MediaTrackConstraintResolutionPolicy {
+                mandatory: MediaTrackConstraintResolutionStrategy::BareToExact,
+                advanced: MediaTrackConstraintResolutionStrategy::BareToIdeal,
+            };
+
+            let actual = subject().to_resolved_with(policy);
+            let expected = ResolvedMediaTrackConstraints {
+                mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                    &WIDTH,
+                    ResolvedValueRangeConstraint::default().exact(1280).into(),
+                )]),
+                advanced: ResolvedAdvancedMediaTrackConstraints::new(vec![
+                    ResolvedMediaTrackConstraintSet::from_iter([(
+                        &WIDTH,
+                        ResolvedValueRangeConstraint::default().ideal(1280).into(),
+                    )]),
+                ]),
+            };
+
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn to_sanitized_with_report() {
+        let subject = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([
+                (
+                    &WIDTH,
+                    ResolvedValueRangeConstraint::default().ideal(1280).into(),
+                ),
+                (
+                    &FRAME_RATE,
+                    ResolvedValueRangeConstraint::default().min(30.0).into(),
+                ),
+            ]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::new(vec![
+                ResolvedMediaTrackConstraintSet::from_iter([(
+                    &HEIGHT,
+                    ResolvedValueRangeConstraint::<u64>::default().into(),
+                )]),
+            ]),
+        };
+        let supported_constraints = MediaTrackSupportedConstraints::from_iter([&WIDTH, &HEIGHT]);
+
+        let (actual_constraints, actual_report) =
+            subject.to_sanitized_with_report(&supported_constraints);
+
+        assert_eq!(
+            actual_constraints,
+            subject.to_sanitized(&supported_constraints)
+        );
+        assert_eq!(
+            actual_report,
+            SanitizationReport {
+                mandatory: vec![(FRAME_RATE.clone(), SanitizationDropReason::Unsupported)],
+                advanced: vec![(0, HEIGHT.clone(), SanitizationDropReason::Empty)],
+            }
+        );
+    }
+
+    #[test]
+    fn validate() {
+        let subject = MediaTrackConstraints {
+            mandatory: MandatoryMediaTrackConstraints::from_iter([
+                (&WIDTH, 1280.into()),
+                (&MediaTrackProperty::from("framerate"), 30.into()),
+            ]),
+            advanced: AdvancedMediaTrackConstraints::new(vec![MediaTrackConstraintSet::from_iter(
+                [(&MediaTrackProperty::from("zoom"), 2.into())],
+            )]),
+        };
+        let supported_constraints =
+            MediaTrackSupportedConstraints::from_iter([&WIDTH, &FRAME_RATE]);
+
+        assert_eq!(
+            subject.validate(&supported_constraints),
+            Err(vec!["framerate".to_owned()])
+        );
+
+        let subject = MediaTrackConstraints {
+            mandatory: MandatoryMediaTrackConstraints::from_iter([(&FRAME_RATE, 30.into())]),
+            ..subject
+        };
+
+        assert_eq!(subject.validate(&supported_constraints), Ok(()));
+    }
+
+    #[test]
+    fn sanitized_is_empty() {
+        let subject = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &MediaTrackProperty::from("framerate"),
+                ResolvedValueRangeConstraint::default().exact(30).into(),
+            )]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::new(vec![
+                ResolvedMediaTrackConstraintSet::from_iter([(
+                    &MediaTrackProperty::from("zoom"),
+                    ResolvedValueRangeConstraint::default().min(2).into(),
+                )]),
+            ]),
+        };
+        let supported_constraints =
+            MediaTrackSupportedConstraints::from_iter([&WIDTH, &HEIGHT, &FRAME_RATE]);
+
+        // All of the constraints are unsupported, hence get sanitized away:
+        let sanitized = subject.to_sanitized(&supported_constraints);
+
+        assert!(sanitized.is_empty());
+        assert!(sanitized.constrained_properties().is_empty());
+
+        let subject = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([
+                (
+                    &WIDTH,
+                    ResolvedValueRangeConstraint::default().min(640).into(),
+                ),
+                (
+                    &MediaTrackProperty::from("framerate"),
+                    ResolvedValueRangeConstraint::default().exact(30).into(),
+                ),
+            ]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::new(vec![
+                ResolvedMediaTrackConstraintSet::from_iter([
+                    (
+                        &HEIGHT,
+                        ResolvedValueRangeConstraint::default().exact(480).into(),
+                    ),
+                    (
+                        &WIDTH,
+                        ResolvedValueRangeConstraint::default().exact(640).into(),
+                    ),
+                ]),
+            ]),
+        };
+
+        let sanitized = subject.to_sanitized(&supported_constraints);
+
+        assert!(!sanitized.is_empty());
+        assert_eq!(sanitized.constrained_properties(), vec!["width", "height"]);
+
+        assert!(SanitizedMediaTrackConstraints::default().is_empty());
+    }
+
+    #[test]
+    fn try_into_sanitized() {
+        let subject = ResolvedMediaTrackConstraints {
+            mandatory: ResolvedMandatoryMediaTrackConstraints::from_iter([(
+                &DEVICE_ID,
+                ResolvedValueConstraint::default()
+                    .exact("microphone".to_owned())
+                    .into(),
+            )]),
+            advanced: ResolvedAdvancedMediaTrackConstraints::default(),
+        };
+
+        let unsupported =
+            MediaTrackSupportedConstraints::from_iter(Vec::<MediaTrackProperty>::new());
+        let actual = subject.try_to_sanitized(&unsupported);
+
+        assert_eq!(actual, Err(SanitizationError::NoSupportedConstraints));
+
+        let actual =
+            subject.try_to_sanitized(&MediaTrackSupportedConstraints::from_iter([&DEVICE_ID]));
+
+        assert_eq!(
+            actual,
+            Ok(subject.to_sanitized(&MediaTrackSupportedConstraints::default()))
+        );
+
+        // Empty constraints are fine without any supported constraints:
+        let actual = ResolvedMediaTrackConstraints::default().try_into_sanitized(
+            &MediaTrackSupportedConstraints::from_iter(Vec::<MediaTrackProperty>::new()),
+        );
+
+        assert_eq!(actual, Ok(SanitizedMediaTrackConstraints::default()));
+    }
+
+    #[test]
+    fn merge() {
+        let base = MediaTrackConstraints {
+            mandatory: MandatoryMediaTrackConstraints::from_iter([(
+                &WIDTH,
+                ResolvedValueRangeConstraint::<u64>::default()
+                    .min(640)
+                    .into(),
+            )]),
+            advanced: AdvancedMediaTrackConstraints::new(vec![MediaTrackConstraintSet::from_iter(
+                [(&DEVICE_ID, "base".into())],
+            )]),
+        };
+        let overrides = MediaTrackConstraints {
+            mandatory: MandatoryMediaTrackConstraints::from_iter([(
+                &WIDTH,
+                ResolvedValueRangeConstraint::<u64>::default()
+                    .min(1280)
+                    .into(),
+            )]),
+            advanced: AdvancedMediaTrackConstraints::new(vec![MediaTrackConstraintSet::from_iter(
+                [(&DEVICE_ID, "override".into())],
+            )]),
+        };
+
+        let actual = base.merge(&overrides);
+        let expected = MediaTrackConstraints {
+            mandatory: MandatoryMediaTrackConstraints::from_iter([(
+                &WIDTH,
+                ResolvedValueRangeConstraint::<u64>::default()
+                    .min(1280)
+                    .into(),
+            )]),
+            advanced: AdvancedMediaTrackConstraints::new(vec![
+                MediaTrackConstraintSet::from_iter([(&DEVICE_ID, "base".into())]),
+                MediaTrackConstraintSet::from_iter([(&DEVICE_ID, "override".into())]),
+            ]),
+        };
+
+        assert_eq!(actual, expected);
+    }
 }
 
 #[cfg(feature = "serde")]
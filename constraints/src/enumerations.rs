@@ -1,97 +1,191 @@
-/// The directions that the camera can face, as seen from the user's perspective.
-///
-/// # Note
-/// The enumeration is not exhaustive and merely provides a list of known values.
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-pub enum FacingMode {
-    /// The source is facing toward the user (a self-view camera).
-    User,
-
-    /// The source is facing away from the user (viewing the environment).
-    Environment,
-
-    /// The source is facing to the left of the user.
-    Left,
-
-    /// The source is facing to the right of the user.
-    Right,
+use std::str::FromStr;
+
+use thiserror::Error;
+
+/// An error indicating a string not matching any value of an enumeration.
+#[derive(Error, Clone, Eq, PartialEq, Debug)]
+#[error("Unknown {enumeration} value: {value:?}")]
+pub struct ParseEnumerationError {
+    /// The name of the enumeration (e.g. `"FacingMode"`).
+    pub enumeration: &'static str,
+    /// The unknown value.
+    pub value: String,
 }
 
-impl FacingMode {
-    /// Returns `"user"`, the string-value of the `User` facing mode.
-    pub fn user() -> String {
-        Self::User.to_string()
-    }
+/// Generates an enumeration of known string-values, as used by the W3C spec,
+/// along with `all()`, `as_str()`, a `String` constructor per value, `Display` and `FromStr`.
+macro_rules! enumeration {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident ($singular:literal, $plural:literal) {
+            $(#[$first_meta:meta])*
+            $first:ident = $first_value:literal => $first_constructor:ident,
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $value:literal => $constructor:ident,
+            )*
+        }
+    ) => {
+        enumeration! {
+            @impl $first_value;
+            $(#[$meta])*
+            pub enum $name ($singular, $plural) {
+                $(#[$first_meta])*
+                $first = $first_value => $first_constructor,
+                $(
+                    $(#[$variant_meta])*
+                    $variant = $value => $constructor,
+                )*
+            }
+        }
+    };
+    (
+        @impl $example:literal;
+        $(#[$meta:meta])*
+        pub enum $name:ident ($singular:literal, $plural:literal) {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $value:literal => $constructor:ident,
+            )+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )+
+        }
 
-    /// Returns `"environment"`, the string-value of the `Environment` facing mode.
-    pub fn environment() -> String {
-        Self::Environment.to_string()
-    }
+        impl $name {
+            #[doc = concat!("Returns all known ", $plural, ".")]
+            pub fn all() -> [Self; 0 $(+ enumeration!(@one $variant))+] {
+                [$(Self::$variant),+]
+            }
 
-    /// Returns `"left"`, the string-value of the `Left` facing mode.
-    pub fn left() -> String {
-        Self::Left.to_string()
-    }
+            #[doc = concat!(
+                "Returns the string-value of the ", $singular,
+                " (e.g. `\"", $example, "\"`), as used by the W3C spec."
+            )]
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $value,)+
+                }
+            }
 
-    /// Returns `"right"`, the string-value of the `Right` facing mode.
-    pub fn right() -> String {
-        Self::Right.to_string()
-    }
-}
+            $(
+                #[doc = concat!(
+                    "Returns `\"", $value, "\"`, the string-value of the `",
+                    stringify!($variant), "` ", $singular, "."
+                )]
+                pub fn $constructor() -> String {
+                    Self::$variant.to_string()
+                }
+            )+
+        }
 
-impl std::fmt::Display for FacingMode {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::User => f.write_str("user"),
-            Self::Environment => f.write_str("environment"),
-            Self::Left => f.write_str("left"),
-            Self::Right => f.write_str("right"),
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
         }
-    }
+
+        impl FromStr for $name {
+            type Err = ParseEnumerationError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $($value => Ok(Self::$variant),)+
+                    _ => Err(ParseEnumerationError {
+                        enumeration: stringify!($name),
+                        value: s.to_owned(),
+                    }),
+                }
+            }
+        }
+    };
+    (@one $variant:ident) => {
+        1
+    };
 }
 
-/// The means by which the resolution can be derived by the client.
-///
-/// # Note
-/// The enumeration is not exhaustive and merely provides a list of known values.
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-pub enum ResizeMode {
-    /// This resolution and frame rate is offered by the camera, its driver, or the OS.
+enumeration! {
+    /// The directions that the camera can face, as seen from the user's perspective.
     ///
     /// # Note
-    /// The user agent MAY report this value to disguise concurrent use,
-    /// but only when the camera is in use in another browsing context.
-    ///
-    /// # Important
-    /// This value is a possible finger-printing surface.
-    None,
+    /// The enumeration is not exhaustive and merely provides a list of known values.
+    pub enum FacingMode("facing mode", "facing modes") {
+        /// The source is facing toward the user (a self-view camera).
+        User = "user" => user,
 
-    /// This resolution is downscaled and/or cropped from a higher camera resolution by the user agent,
-    /// or its frame rate is decimated by the User Agent.
-    ///
-    /// # Important
-    /// The media MUST NOT be upscaled, stretched or have fake data created that did not occur in the input source.
-    CropAndScale,
+        /// The source is facing away from the user (viewing the environment).
+        Environment = "environment" => environment,
+
+        /// The source is facing to the left of the user.
+        Left = "left" => left,
+
+        /// The source is facing to the right of the user.
+        Right = "right" => right,
+    }
 }
 
-impl ResizeMode {
-    /// Returns `"none"`, the string-value of the `None` resize mode.
-    pub fn none() -> String {
-        Self::None.to_string()
+enumeration! {
+    /// The means by which the resolution can be derived by the client.
+    ///
+    /// # Note
+    /// The enumeration is not exhaustive and merely provides a list of known values.
+    pub enum ResizeMode("resize mode", "resize modes") {
+        /// This resolution and frame rate is offered by the camera, its driver, or the OS.
+        ///
+        /// # Note
+        /// The user agent MAY report this value to disguise concurrent use,
+        /// but only when the camera is in use in another browsing context.
+        ///
+        /// # Important
+        /// This value is a possible finger-printing surface.
+        None = "none" => none,
+
+        /// This resolution is downscaled and/or cropped from a higher camera resolution by the user agent,
+        /// or its frame rate is decimated by the User Agent.
+        ///
+        /// # Important
+        /// The media MUST NOT be upscaled, stretched or have fake data created that did not occur in the input source.
+        CropAndScale = "crop-and-scale" => crop_and_scale,
     }
+}
 
-    /// Returns `"crop-and-scale"`, the string-value of the `CropAndScale` resize mode.
-    pub fn crop_and_scale() -> String {
-        Self::CropAndScale.to_string()
+enumeration! {
+    /// The types of display surface that can be captured, as seen from the user's perspective.
+    ///
+    /// # Note
+    /// The enumeration is not exhaustive and merely provides a list of known values.
+    pub enum DisplaySurface("display surface", "display surfaces") {
+        /// The source is a monitor, or a collection of monitors.
+        Monitor = "monitor" => monitor,
+
+        /// The source is a single window.
+        Window = "window" => window,
+
+        /// The source is a single browser tab.
+        Browser = "browser" => browser,
     }
 }
 
-impl std::fmt::Display for ResizeMode {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::None => f.write_str("none"),
-            Self::CropAndScale => f.write_str("crop-and-scale"),
-        }
+enumeration! {
+    /// The conditions under which the cursor is included in a captured display surface.
+    ///
+    /// # Note
+    /// The enumeration is not exhaustive and merely provides a list of known values.
+    pub enum CursorCapture("cursor capture mode", "cursor capture modes") {
+        /// The cursor is never included.
+        Never = "never" => never,
+
+        /// The cursor is always included.
+        Always = "always" => always,
+
+        /// The cursor is included while moving, and for a short while afterwards.
+        Motion = "motion" => motion,
     }
 }
 
@@ -114,6 +208,39 @@ mod tests {
             assert_eq!(FacingMode::left(), "left");
             assert_eq!(FacingMode::right(), "right");
         }
+
+        #[test]
+        fn as_str() {
+            assert_eq!(FacingMode::User.as_str(), "user");
+            assert_eq!(FacingMode::Environment.as_str(), "environment");
+            assert_eq!(FacingMode::Left.as_str(), "left");
+            assert_eq!(FacingMode::Right.as_str(), "right");
+
+            for facing_mode in FacingMode::all() {
+                assert_eq!(facing_mode.as_str(), facing_mode.to_string());
+            }
+        }
+
+        #[test]
+        fn from_str() {
+            for facing_mode in FacingMode::all() {
+                let actual = FacingMode::from_str(&facing_mode.to_string());
+
+                assert_eq!(actual, Ok(facing_mode));
+            }
+
+            assert_eq!(
+                FacingMode::from_str("environment").map(|mode| mode.to_string()),
+                Ok("environment".to_owned())
+            );
+            assert_eq!(
+                FacingMode::from_str("Environment"),
+                Err(ParseEnumerationError {
+                    enumeration: "FacingMode",
+                    value: "Environment".to_owned(),
+                })
+            );
+        }
     }
 
     mod resize_mode {
@@ -127,5 +254,86 @@ mod tests {
             assert_eq!(ResizeMode::none(), "none");
             assert_eq!(ResizeMode::crop_and_scale(), "crop-and-scale");
         }
+
+        #[test]
+        fn as_str() {
+            assert_eq!(ResizeMode::None.as_str(), "none");
+            assert_eq!(ResizeMode::CropAndScale.as_str(), "crop-and-scale");
+
+            assert_eq!(ResizeMode::none().as_str(), "none");
+
+            for resize_mode in ResizeMode::all() {
+                assert_eq!(resize_mode.as_str(), resize_mode.to_string());
+            }
+        }
+
+        #[test]
+        fn from_str() {
+            for resize_mode in ResizeMode::all() {
+                let actual = ResizeMode::from_str(&resize_mode.to_string());
+
+                assert_eq!(actual, Ok(resize_mode));
+            }
+
+            assert!(ResizeMode::from_str("crop").is_err());
+        }
+    }
+
+    mod display_surface {
+        use super::*;
+
+        #[test]
+        fn to_string() {
+            assert_eq!(DisplaySurface::Monitor.to_string(), "monitor");
+            assert_eq!(DisplaySurface::Window.to_string(), "window");
+            assert_eq!(DisplaySurface::Browser.to_string(), "browser");
+
+            assert_eq!(DisplaySurface::monitor(), "monitor");
+            assert_eq!(DisplaySurface::window(), "window");
+            assert_eq!(DisplaySurface::browser(), "browser");
+        }
+
+        #[test]
+        fn from_str() {
+            for display_surface in DisplaySurface::all() {
+                let actual = DisplaySurface::from_str(display_surface.as_str());
+
+                assert_eq!(actual, Ok(display_surface));
+            }
+
+            assert_eq!(
+                DisplaySurface::from_str("screen"),
+                Err(ParseEnumerationError {
+                    enumeration: "DisplaySurface",
+                    value: "screen".to_owned(),
+                })
+            );
+        }
+    }
+
+    mod cursor_capture {
+        use super::*;
+
+        #[test]
+        fn to_string() {
+            assert_eq!(CursorCapture::Never.to_string(), "never");
+            assert_eq!(CursorCapture::Always.to_string(), "always");
+            assert_eq!(CursorCapture::Motion.to_string(), "motion");
+
+            assert_eq!(CursorCapture::never(), "never");
+            assert_eq!(CursorCapture::always(), "always");
+            assert_eq!(CursorCapture::motion(), "motion");
+        }
+
+        #[test]
+        fn from_str() {
+            for cursor_capture in CursorCapture::all() {
+                let actual = CursorCapture::from_str(cursor_capture.as_str());
+
+                assert_eq!(actual, Ok(cursor_capture));
+            }
+
+            assert!(CursorCapture::from_str("hidden").is_err());
+        }
     }
 }
@@ -2,19 +2,32 @@
 //!
 //! [mediacapture_streams]: https://www.w3.org/TR/mediacapture-streams/
 
+use std::cmp::Ordering;
 use std::collections::HashMap;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::algorithms::{ConstraintFailureInfo, SettingFitnessDistanceErrorKind};
-use crate::MediaTrackProperty;
+use crate::{MediaTrackProperty, MediaTrackSetting};
 
 /// An error indicating one or more over-constrained settings.
+///
+/// When serialized it matches the shape of the W3C [`OverconstrainedError`][overconstrained_error]
+/// (i.e. `{ "constraint": …, "message": … }`).
+///
+/// [overconstrained_error]: https://www.w3.org/TR/mediacapture-streams/#overconstrainederror-interface
 #[derive(Error, Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct OverconstrainedError {
     /// The offending constraint's name.
     pub constraint: MediaTrackProperty,
     /// An error message, or `None` if exposure-mode was `Protected`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "core::option::Option::is_none")
+    )]
     pub message: Option<String>,
 }
 
@@ -50,19 +63,19 @@ impl OverconstrainedError {
 
         struct Violation {
             constraint: String,
-            settings: Vec<String>,
+            settings: Vec<(String, Option<MediaTrackSetting>)>,
         }
         let mut violators_by_kind: HashMap<SettingFitnessDistanceErrorKind, Violation> =
             HashMap::default();
 
-        for error in failure_info.errors {
+        for (error, setting) in failure_info.errors {
             let violation = violators_by_kind.entry(error.kind).or_insert(Violation {
                 constraint: error.constraint.clone(),
                 settings: vec![],
             });
             assert_eq!(violation.constraint, error.constraint);
-            if let Some(setting) = error.setting {
-                violation.settings.push(setting.clone());
+            if let Some(formatted_setting) = error.setting {
+                violation.settings.push((formatted_setting, setting));
             }
         }
 
@@ -82,7 +95,12 @@ impl OverconstrainedError {
                     return format!("{} (does not satisfy {})", kind_str, violation.constraint);
                 }
 
-                settings.sort();
+                sort_settings(&mut settings);
+
+                let settings: Vec<_> = settings
+                    .into_iter()
+                    .map(|(formatted_setting, _)| formatted_setting)
+                    .collect();
 
                 format!(
                     "{} ([{}] do not satisfy {})",
@@ -109,3 +127,77 @@ impl OverconstrainedError {
         }
     }
 }
+
+// Sorts numeric settings numerically (e.g. `[15, 30, 120]`)
+// and any other settings by value (e.g. `["bar", "foo"]`),
+// falling back to their formatted representations:
+fn sort_settings(settings: &mut [(String, Option<MediaTrackSetting>)]) {
+    fn kind_rank(setting: &MediaTrackSetting) -> u8 {
+        match setting {
+            MediaTrackSetting::Bool(_) => 0,
+            MediaTrackSetting::Integer(_) | MediaTrackSetting::Float(_) => 1,
+            MediaTrackSetting::String(_) => 2,
+        }
+    }
+
+    fn compare(lhs: &MediaTrackSetting, rhs: &MediaTrackSetting) -> Ordering {
+        match (lhs, rhs) {
+            (MediaTrackSetting::Bool(lhs), MediaTrackSetting::Bool(rhs)) => lhs.cmp(rhs),
+            (MediaTrackSetting::String(lhs), MediaTrackSetting::String(rhs)) => lhs.cmp(rhs),
+            _ => match (lhs.as_f64(), rhs.as_f64()) {
+                (Some(lhs), Some(rhs)) => lhs.total_cmp(&rhs),
+                _ => kind_rank(lhs).cmp(&kind_rank(rhs)),
+            },
+        }
+    }
+
+    settings.sort_by(|(lhs_formatted, lhs), (rhs_formatted, rhs)| {
+        let ordering = match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => compare(lhs, rhs),
+            (lhs, rhs) => lhs.is_none().cmp(&rhs.is_none()),
+        };
+        ordering.then_with(|| lhs_formatted.cmp(rhs_formatted))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sort_settings_numeric() {
+        let mut settings: Vec<_> = [f64::NAN, 120.0, 15.0, 30.0]
+            .into_iter()
+            .map(|setting| {
+                (
+                    format!("{setting:?}"),
+                    Some(MediaTrackSetting::Float(setting)),
+                )
+            })
+            .collect();
+        settings.push(("60".to_owned(), Some(MediaTrackSetting::Integer(60))));
+
+        sort_settings(&mut settings);
+
+        let actual: Vec<_> = settings.into_iter().map(|(setting, _)| setting).collect();
+        assert_eq!(actual, vec!["15.0", "30.0", "60", "120.0", "NaN"]);
+    }
+
+    #[test]
+    fn sort_settings_strings() {
+        let mut settings: Vec<_> = ["foo", "bar"]
+            .into_iter()
+            .map(|setting| {
+                (
+                    format!("{setting:?}"),
+                    Some(MediaTrackSetting::from(setting)),
+                )
+            })
+            .collect();
+
+        sort_settings(&mut settings);
+
+        let actual: Vec<_> = settings.into_iter().map(|(setting, _)| setting).collect();
+        assert_eq!(actual, vec!["\"bar\"", "\"foo\""]);
+    }
+}
@@ -18,6 +18,9 @@ mod setting;
 mod settings;
 mod supported_constraints;
 
+#[cfg(any(test, feature = "quickcheck"))]
+mod arbitrary;
+
 #[allow(unused_imports)]
 pub(crate) use self::{capabilities::MediaStreamCapabilities, settings::MediaStreamSettings};
 #[allow(unused_imports)]
@@ -25,22 +28,27 @@ pub use self::{
     capabilities::MediaTrackCapabilities,
     capability::MediaTrackCapability,
     constraint::{
-        MediaTrackConstraint, MediaTrackConstraintResolutionStrategy, ResolvedMediaTrackConstraint,
+        MediaTrackConstraint, MediaTrackConstraintKind, MediaTrackConstraintResolutionPolicy,
+        MediaTrackConstraintResolutionStrategy, ResolvedMediaTrackConstraint,
         ResolvedValueConstraint, ResolvedValueRangeConstraint, ResolvedValueSequenceConstraint,
-        SanitizedMediaTrackConstraint, ValueConstraint, ValueRangeConstraint,
-        ValueSequenceConstraint,
+        SanitizedMediaTrackConstraint, ValueConstraint, ValueExclusionConstraint,
+        ValueRangeConstraint, ValueSequenceConstraint,
     },
     constraints::{
         AdvancedMediaTrackConstraints, BoolOrMediaTrackConstraints, MandatoryMediaTrackConstraints,
         MediaStreamConstraints, MediaTrackConstraintSet, MediaTrackConstraints,
-        ResolvedAdvancedMediaTrackConstraints, ResolvedMandatoryMediaTrackConstraints,
-        ResolvedMediaTrackConstraintSet, ResolvedMediaTrackConstraints,
-        SanitizedMandatoryMediaTrackConstraints, SanitizedMediaTrackConstraintSet,
-        SanitizedMediaTrackConstraints,
+        MediaTrackConstraintsBuilder, ResolvedAdvancedMediaTrackConstraints,
+        ResolvedMandatoryMediaTrackConstraints, ResolvedMediaTrackConstraintSet,
+        ResolvedMediaTrackConstraints, SanitizationDropReason, SanitizationError,
+        SanitizationReport, SanitizedConstraintsCache, SanitizedMandatoryMediaTrackConstraints,
+        SanitizedMediaTrackConstraintSet, SanitizedMediaTrackConstraints,
     },
-    enumerations::{FacingMode, ResizeMode},
+    enumerations::{CursorCapture, DisplaySurface, FacingMode, ParseEnumerationError, ResizeMode},
     property::MediaTrackProperty,
-    setting::MediaTrackSetting,
+    setting::{MediaTrackSetting, MediaTrackSettingKind},
     settings::MediaTrackSettings,
     supported_constraints::MediaTrackSupportedConstraints,
 };
+
+#[cfg(feature = "regex")]
+pub use self::constraint::ValuePatternConstraint;
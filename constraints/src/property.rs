@@ -11,6 +11,11 @@ use std::fmt::Display;
 use serde::{Deserialize, Serialize};
 
 /// An identifier for a media track property.
+///
+/// Besides the standard properties (see [`all::names`]), properties may
+/// also identify custom, e.g. vendor-specific, properties (e.g. `"torch"`),
+/// which get treated just like standard properties, as long as they are
+/// listed in the [`MediaTrackSupportedConstraints`][crate::MediaTrackSupportedConstraints].
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
@@ -55,6 +60,53 @@ impl MediaTrackProperty {
     pub fn name(&self) -> &str {
         &self.0
     }
+
+    /// Returns `true` if the property is one of the standard properties,
+    /// otherwise (i.e. for custom properties) `false`.
+    pub fn is_standard(&self) -> bool {
+        all::info(self.name()).is_some()
+    }
+}
+
+/// The kind of value a media track property takes.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum MediaTrackPropertyValueKind {
+    /// A boolean value (e.g. `echoCancellation`).
+    Bool,
+    /// An integer value (e.g. `width`).
+    Integer,
+    /// A floating-point value (e.g. `frameRate`).
+    Float,
+    /// A free-form string value (e.g. `deviceId`).
+    String,
+    /// A string value from a known set of values (e.g. `facingMode`).
+    Enum,
+}
+
+/// Metadata about a standard media track property.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MediaTrackPropertyInfo {
+    /// The property (whose name is also its serialized name).
+    pub property: &'static MediaTrackProperty,
+    /// The kind of value the property takes.
+    pub kind: MediaTrackPropertyValueKind,
+    /// Whether the property gets constrained by ranges (`min`/`max`),
+    /// rather than by discrete values.
+    pub is_range: bool,
+}
+
+impl MediaTrackPropertyInfo {
+    const fn new(property: &'static MediaTrackProperty, kind: MediaTrackPropertyValueKind) -> Self {
+        let is_range = matches!(
+            kind,
+            MediaTrackPropertyValueKind::Integer | MediaTrackPropertyValueKind::Float
+        );
+        Self {
+            property,
+            kind,
+            is_range,
+        }
+    }
 }
 
 /// Standard properties that apply to both, audio and video device types.
@@ -215,7 +267,59 @@ pub mod video_only {
     }
 }
 
-/// The union of all standard properties (i.e. common + audio + video).
+/// Standard properties that apply only to display capture tracks,
+/// as defined in the ["Screen Capture"][screen_capture_spec] spec.
+///
+/// [screen_capture_spec]: https://www.w3.org/TR/screen-capture/
+pub mod screen_capture {
+    use super::*;
+
+    /// Names of screen capture properties.
+    pub mod name {
+        use super::*;
+
+        /// The type of display surface being captured (e.g. `"monitor"`),
+        /// as defined in the [spec][spec].
+        ///
+        /// [spec]: https://www.w3.org/TR/screen-capture/#dfn-displaysurface
+        pub static DISPLAY_SURFACE: MediaTrackProperty =
+            MediaTrackProperty::named("displaySurface");
+
+        /// Whether the captured display surface is a logical one (i.e. may not be
+        /// entirely visible on screen), as defined in the [spec][spec].
+        ///
+        /// [spec]: https://www.w3.org/TR/screen-capture/#dfn-logicalsurface
+        pub static LOGICAL_SURFACE: MediaTrackProperty =
+            MediaTrackProperty::named("logicalSurface");
+
+        /// The conditions under which the cursor is included in the captured display surface,
+        /// as defined in the [spec][spec].
+        ///
+        /// [spec]: https://www.w3.org/TR/screen-capture/#dfn-cursor
+        pub static CURSOR: MediaTrackProperty = MediaTrackProperty::named("cursor");
+
+        /// Whether audio captured from a browser tab is stopped from being played
+        /// out locally, as defined in the [spec][spec].
+        ///
+        /// [spec]: https://www.w3.org/TR/screen-capture/#dfn-suppresslocalaudioplayback
+        pub static SUPPRESS_LOCAL_AUDIO_PLAYBACK: MediaTrackProperty =
+            MediaTrackProperty::named("suppressLocalAudioPlayback");
+    }
+
+    /// Names of all screen capture properties.
+    pub fn names() -> Vec<&'static MediaTrackProperty> {
+        use self::name::*;
+
+        vec![
+            &DISPLAY_SURFACE,
+            &LOGICAL_SURFACE,
+            &CURSOR,
+            &SUPPRESS_LOCAL_AUDIO_PLAYBACK,
+        ]
+    }
+}
+
+/// The union of all standard properties (i.e. common + audio + video + screen capture).
 pub mod all {
     use super::*;
 
@@ -223,15 +327,49 @@ pub mod all {
     pub mod name {
         pub use super::audio_only::name::*;
         pub use super::common::name::*;
+        pub use super::screen_capture::name::*;
         pub use super::video_only::name::*;
     }
 
+    /// Returns metadata about the standard property named `name`,
+    /// or `None` if there is no such standard property.
+    pub fn info(name: &str) -> Option<MediaTrackPropertyInfo> {
+        use self::name::*;
+        use MediaTrackPropertyValueKind::*;
+
+        let (property, kind) = match name {
+            "deviceId" => (&DEVICE_ID, String),
+            "groupId" => (&GROUP_ID, String),
+            "autoGainControl" => (&AUTO_GAIN_CONTROL, Bool),
+            "channelCount" => (&CHANNEL_COUNT, Integer),
+            "echoCancellation" => (&ECHO_CANCELLATION, Bool),
+            "latency" => (&LATENCY, Float),
+            "noiseSuppression" => (&NOISE_SUPPRESSION, Bool),
+            "sampleRate" => (&SAMPLE_RATE, Integer),
+            "sampleSize" => (&SAMPLE_SIZE, Integer),
+            "aspectRatio" => (&ASPECT_RATIO, Float),
+            "facingMode" => (&FACING_MODE, Enum),
+            "frameRate" => (&FRAME_RATE, Float),
+            "height" => (&HEIGHT, Integer),
+            "width" => (&WIDTH, Integer),
+            "resizeMode" => (&RESIZE_MODE, Enum),
+            "displaySurface" => (&DISPLAY_SURFACE, Enum),
+            "logicalSurface" => (&LOGICAL_SURFACE, Bool),
+            "cursor" => (&CURSOR, Enum),
+            "suppressLocalAudioPlayback" => (&SUPPRESS_LOCAL_AUDIO_PLAYBACK, Bool),
+            _ => return None,
+        };
+
+        Some(MediaTrackPropertyInfo::new(property, kind))
+    }
+
     /// Names of all properties.
     pub fn names() -> Vec<&'static MediaTrackProperty> {
         let mut all = vec![];
         all.append(&mut self::common::names());
         all.append(&mut self::audio_only::names());
         all.append(&mut self::video_only::names());
+        all.append(&mut self::screen_capture::names());
         all
     }
 }
@@ -272,6 +410,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn is_standard() {
+        assert!(all::name::WIDTH.is_standard());
+        assert!(Subject::from("facingMode").is_standard());
+        assert!(!Subject::from("torch").is_standard());
+    }
+
+    mod info {
+        use super::*;
+        use crate::property::all::name::*;
+
+        #[test]
+        fn frame_rate() {
+            let actual = all::info(FRAME_RATE.name());
+            let expected = Some(MediaTrackPropertyInfo {
+                property: &FRAME_RATE,
+                kind: MediaTrackPropertyValueKind::Float,
+                is_range: true,
+            });
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn facing_mode() {
+            let info = all::info(FACING_MODE.name()).unwrap();
+
+            assert_eq!(info.kind, MediaTrackPropertyValueKind::Enum);
+            assert!(!info.is_range);
+        }
+
+        #[test]
+        fn screen_capture() {
+            let info = all::info(DISPLAY_SURFACE.name()).unwrap();
+            assert_eq!(info.kind, MediaTrackPropertyValueKind::Enum);
+
+            let info = all::info(SUPPRESS_LOCAL_AUDIO_PLAYBACK.name()).unwrap();
+            assert_eq!(info.kind, MediaTrackPropertyValueKind::Bool);
+            assert!(!info.is_range);
+        }
+
+        #[test]
+        fn all_names() {
+            for property in all::names() {
+                let info = all::info(property.name()).unwrap();
+
+                assert_eq!(info.property, property);
+            }
+        }
+
+        #[test]
+        fn unknown() {
+            assert_eq!(all::info("zoom"), None);
+        }
+    }
+
     #[test]
     fn name() {
         assert_eq!(Subject::named("string").name(), "string");
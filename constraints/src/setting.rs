@@ -25,6 +25,57 @@ pub enum MediaTrackSetting {
     String(String),
 }
 
+/// The kind of value held by a [`MediaTrackSetting`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum MediaTrackSettingKind {
+    /// A boolean value.
+    Bool,
+    /// An integer value.
+    Integer,
+    /// A floating-point value.
+    Float,
+    /// A string value.
+    String,
+}
+
+impl MediaTrackSetting {
+    /// Returns the kind of value held by the setting.
+    pub fn kind(&self) -> MediaTrackSettingKind {
+        match self {
+            Self::Bool(_) => MediaTrackSettingKind::Bool,
+            Self::Integer(_) => MediaTrackSettingKind::Integer,
+            Self::Float(_) => MediaTrackSettingKind::Float,
+            Self::String(_) => MediaTrackSettingKind::String,
+        }
+    }
+
+    /// Returns the setting's value if it is a boolean, or `None` otherwise.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(setting) => Some(*setting),
+            _ => None,
+        }
+    }
+
+    /// Returns the setting's value as a float if it is numeric (i.e. an integer or a float),
+    /// or `None` otherwise.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Integer(setting) => Some(*setting as f64),
+            Self::Float(setting) => Some(*setting),
+            _ => None,
+        }
+    }
+
+    /// Returns the setting's value if it is a string, or `None` otherwise.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(setting) => Some(setting),
+            _ => None,
+        }
+    }
+}
+
 impl std::fmt::Display for MediaTrackSetting {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -108,6 +159,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn kind() {
+        assert_eq!(Subject::from(true).kind(), MediaTrackSettingKind::Bool);
+        assert_eq!(Subject::from(42).kind(), MediaTrackSettingKind::Integer);
+        assert_eq!(Subject::from(4.2).kind(), MediaTrackSettingKind::Float);
+        assert_eq!(
+            Subject::from("string").kind(),
+            MediaTrackSettingKind::String
+        );
+    }
+
+    #[test]
+    fn as_value() {
+        let subject = Subject::from("string");
+        assert_eq!(subject.as_str(), Some("string"));
+        assert_eq!(subject.as_f64(), None);
+        assert_eq!(subject.as_bool(), None);
+
+        let subject = Subject::from(42);
+        assert_eq!(subject.as_str(), None);
+        assert_eq!(subject.as_f64(), Some(42.0));
+        assert_eq!(subject.as_bool(), None);
+
+        let subject = Subject::from(4.2);
+        assert_eq!(subject.as_f64(), Some(4.2));
+
+        let subject = Subject::from(true);
+        assert_eq!(subject.as_str(), None);
+        assert_eq!(subject.as_f64(), None);
+        assert_eq!(subject.as_bool(), Some(true));
+    }
+
     #[test]
     fn to_string() {
         assert_eq!(Subject::from(true).to_string(), "true");
@@ -1,10 +1,11 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::iter::FromIterator;
 use std::ops::{Deref, DerefMut};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::property::all::name::{ASPECT_RATIO, GROUP_ID, HEIGHT, WIDTH};
 use crate::{MediaTrackProperty, MediaTrackSetting};
 
 /// The settings of a [`MediaStreamTrack`][media_stream_track] object.
@@ -40,6 +41,67 @@ impl MediaTrackSettings {
     pub fn into_inner(self) -> HashMap<MediaTrackProperty, MediaTrackSetting> {
         self.0
     }
+
+    /// Returns `true` if the settings contain a value for `property`.
+    pub fn contains(&self, property: &MediaTrackProperty) -> bool {
+        self.0.contains_key(property)
+    }
+
+    /// Returns settings containing the values of both, `self` and `other`,
+    /// with values from `other` replacing those of `self` for the same property
+    /// (e.g. for layering device-reported values on top of platform defaults).
+    pub fn merge(&self, other: &Self) -> Self {
+        let mut merged = self.clone();
+        merged.extend(
+            other
+                .iter()
+                .map(|(property, setting)| (property.clone(), setting.clone())),
+        );
+        merged
+    }
+
+    /// Returns a copy of the settings with an `aspectRatio` of `width / height`,
+    /// if they specify a numeric, non-zero `width` and `height`, but lack an `aspectRatio`.
+    ///
+    /// This allows for device enumerations only providing `width` and `height`
+    /// to satisfy constraints on `aspectRatio`.
+    pub fn with_derived_aspect_ratio(&self) -> Self {
+        let mut settings = self.clone();
+
+        if self.contains(&ASPECT_RATIO) {
+            return settings;
+        }
+
+        let as_f64 = |setting: Option<&MediaTrackSetting>| match setting {
+            Some(MediaTrackSetting::Integer(value)) => Some(*value as f64),
+            Some(MediaTrackSetting::Float(value)) => Some(*value),
+            _ => None,
+        };
+
+        if let (Some(width), Some(height)) = (as_f64(self.get(&WIDTH)), as_f64(self.get(&HEIGHT))) {
+            if height != 0.0 {
+                settings.insert(ASPECT_RATIO.clone(), (width / height).into());
+            }
+        }
+
+        settings
+    }
+
+    /// Groups `settings` by their `groupId` (i.e. by the physical device they belong to),
+    /// with settings lacking a string-valued `groupId` grouped under `None`.
+    ///
+    /// Settings retain their relative order within each group.
+    pub fn group_by_group_id(settings: &[Self]) -> BTreeMap<Option<String>, Vec<&Self>> {
+        let mut groups: BTreeMap<Option<String>, Vec<&Self>> = BTreeMap::new();
+        for settings in settings {
+            let group_id = match settings.get(&GROUP_ID) {
+                Some(MediaTrackSetting::String(group_id)) => Some(group_id.clone()),
+                _ => None,
+            };
+            groups.entry(group_id).or_default().push(settings);
+        }
+        groups
+    }
 }
 
 impl Deref for MediaTrackSettings {
@@ -77,6 +139,15 @@ impl IntoIterator for MediaTrackSettings {
     }
 }
 
+impl<'a> IntoIterator for &'a MediaTrackSettings {
+    type Item = (&'a MediaTrackProperty, &'a MediaTrackSetting);
+    type IntoIter = std::collections::hash_map::Iter<'a, MediaTrackProperty, MediaTrackSetting>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,6 +173,66 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn merge() {
+        let base = Subject::from_iter([(&FRAME_RATE, 30.0.into()), (&WIDTH, 640.into())]);
+        let overrides = Subject::from_iter([(&WIDTH, 1280.into()), (&HEIGHT, 720.into())]);
+
+        let actual = base.merge(&overrides);
+
+        let expected = Subject::from_iter([
+            (&FRAME_RATE, 30.0.into()),
+            (&WIDTH, 1280.into()),
+            (&HEIGHT, 720.into()),
+        ]);
+
+        assert_eq!(actual, expected);
+
+        let actual = Subject::from_iter([(&FRAME_RATE, 30.0.into())])
+            .merge(&Subject::from_iter([(&WIDTH, 1280.into())]));
+
+        let expected = Subject::from_iter([(&FRAME_RATE, 30.0.into()), (&WIDTH, 1280.into())]);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn group_by_group_id() {
+        let settings = [
+            Subject::from_iter([(&DEVICE_ID, "camera".into()), (&GROUP_ID, "builtin".into())]),
+            Subject::from_iter([(&DEVICE_ID, "external".into()), (&GROUP_ID, "usb".into())]),
+            Subject::from_iter([
+                (&DEVICE_ID, "microphone".into()),
+                (&GROUP_ID, "builtin".into()),
+            ]),
+            Subject::from_iter([(&DEVICE_ID, "virtual".into())]),
+        ];
+
+        let actual = Subject::group_by_group_id(&settings);
+
+        let expected = BTreeMap::from_iter([
+            (None, vec![&settings[3]]),
+            (Some("builtin".to_owned()), vec![&settings[0], &settings[2]]),
+            (Some("usb".to_owned()), vec![&settings[1]]),
+        ]);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn group_by_group_id_single_group() {
+        let settings = [
+            Subject::from_iter([(&DEVICE_ID, "480p".into()), (&GROUP_ID, "builtin".into())]),
+            Subject::from_iter([(&DEVICE_ID, "720p".into()), (&GROUP_ID, "builtin".into())]),
+            Subject::from_iter([(&DEVICE_ID, "1080p".into()), (&GROUP_ID, "builtin".into())]),
+        ];
+
+        let actual = Subject::group_by_group_id(&settings);
+
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[&Some("builtin".to_owned())].len(), 3);
+    }
+
     #[test]
     fn into_iter() {
         let hash_map = HashMap::from_iter([
@@ -120,6 +251,83 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn into_iter_by_ref() {
+        let subject = Subject::from_iter([
+            (&DEVICE_ID, "480p".into()),
+            (&ASPECT_RATIO, 0.5625.into()),
+            (&FACING_MODE, "user".into()),
+            (&FRAME_RATE, 240.into()),
+            (&WIDTH, 720.into()),
+            (&HEIGHT, 480.into()),
+            (&RESIZE_MODE, "crop-and-scale".into()),
+        ]);
+
+        let mut actual = vec![];
+        for (property, _setting) in &subject {
+            actual.push(property.name());
+        }
+        actual.sort_unstable();
+
+        let expected = vec![
+            "aspectRatio",
+            "deviceId",
+            "facingMode",
+            "frameRate",
+            "height",
+            "resizeMode",
+            "width",
+        ];
+
+        assert_eq!(actual, expected);
+
+        let mut keys: Vec<_> = subject.keys().map(|property| property.name()).collect();
+        keys.sort_unstable();
+
+        assert_eq!(keys, expected);
+    }
+
+    #[test]
+    fn with_derived_aspect_ratio() {
+        let subject = Subject::from_iter([(&WIDTH, 1920.into()), (&HEIGHT, 1080.into())]);
+
+        let actual = subject.with_derived_aspect_ratio();
+
+        let expected = Subject::from_iter([
+            (&WIDTH, 1920.into()),
+            (&HEIGHT, 1080.into()),
+            (&ASPECT_RATIO, (1920.0 / 1080.0).into()),
+        ]);
+
+        assert_eq!(actual, expected);
+
+        // Existing aspect ratios are retained:
+        let subject = Subject::from_iter([
+            (&WIDTH, 1920.into()),
+            (&HEIGHT, 1080.into()),
+            (&ASPECT_RATIO, 1.5.into()),
+        ]);
+
+        assert_eq!(subject.with_derived_aspect_ratio(), subject);
+
+        // Missing or zero dimensions don't produce an aspect ratio:
+        for subject in [
+            Subject::from_iter([(&WIDTH, 1920.into())]),
+            Subject::from_iter([(&WIDTH, 1920.into()), (&HEIGHT, 0.into())]),
+            Subject::from_iter([(&WIDTH, 1920.into()), (&HEIGHT, "1080".into())]),
+        ] {
+            assert_eq!(subject.with_derived_aspect_ratio(), subject);
+        }
+    }
+
+    #[test]
+    fn contains() {
+        let subject = Subject::from_iter([(&DEVICE_ID, "device-id".into())]);
+
+        assert!(subject.contains(&DEVICE_ID));
+        assert!(!subject.contains(&GROUP_ID));
+    }
+
     #[test]
     fn deref_and_deref_mut() {
         let mut subject = Subject::default();
@@ -166,4 +374,18 @@ mod serde_tests {
 
         test_serde_symmetry!(subject: subject, json: json);
     }
+
+    #[test]
+    fn facing_mode() {
+        use crate::FacingMode;
+
+        for (facing_mode, name) in [(FacingMode::left(), "left"), (FacingMode::right(), "right")] {
+            let subject = Subject::from_iter([(&FACING_MODE, facing_mode.into())]);
+            let json = serde_json::json!({
+                "facingMode": name,
+            });
+
+            test_serde_symmetry!(subject: subject, json: json);
+        }
+    }
 }
@@ -9,7 +9,7 @@ use serde::{
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
-use crate::MediaTrackProperty;
+use crate::{MediaTrackProperty, MediaTrackSettings};
 
 /// The list of constraints recognized by a User Agent for controlling the
 /// capabilities of a [`MediaStreamTrack`][media_stream_track] object.
@@ -43,6 +43,16 @@ impl MediaTrackSupportedConstraints {
     pub fn into_inner(self) -> HashSet<MediaTrackProperty> {
         self.0
     }
+
+    /// Creates a supported constraints value from the union
+    /// of properties present in any of the given settings.
+    pub fn from_settings(settings: &[MediaTrackSettings]) -> Self {
+        Self::from_iter(
+            settings
+                .iter()
+                .flat_map(|settings| settings.keys().cloned()),
+        )
+    }
 }
 
 impl Deref for MediaTrackSupportedConstraints {
@@ -183,6 +193,21 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn from_settings() {
+        let settings = [
+            MediaTrackSettings::from_iter([(&DEVICE_ID, "a".into()), (&WIDTH, 1280.into())]),
+            MediaTrackSettings::from_iter([(&DEVICE_ID, "b".into()), (&FRAME_RATE, 30.into())]),
+            MediaTrackSettings::default(),
+        ];
+
+        let actual = Subject::from_settings(&settings);
+
+        let expected = Subject::from_iter([&DEVICE_ID, &WIDTH, &FRAME_RATE]);
+
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn deref_and_deref_mut() {
         let mut subject = Subject::default();
@@ -223,6 +248,10 @@ mod serde_tests {
             "height": true,
             "width": true,
             "resizeMode": true,
+            "displaySurface": true,
+            "logicalSurface": true,
+            "cursor": true,
+            "suppressLocalAudioPlayback": true,
         });
 
         test_serde_symmetry!(subject: subject, json: json);
@@ -0,0 +1,123 @@
+use bytes::{Bytes, BytesMut};
+use criterion::{criterion_group, criterion_main, Criterion};
+use rtcp::payload_feedbacks::receiver_estimated_maximum_bitrate::*;
+use rtcp::receiver_report::*;
+use rtcp::reception_report::*;
+use rtcp::sender_report::*;
+use rtcp::source_description::*;
+use rtcp::transport_feedbacks::transport_layer_nack::*;
+use util::marshal::{Marshal, MarshalSize, Unmarshal};
+
+fn reception_reports(n: u32) -> Vec<ReceptionReport> {
+    (0..n)
+        .map(|i| ReceptionReport {
+            ssrc: 0xbc5e9a40 + i,
+            fraction_lost: 0,
+            total_lost: i,
+            last_sequence_number: 0x46e1 + i,
+            jitter: 273,
+            last_sender_report: 0x9f36432,
+            delay: 150137,
+        })
+        .collect()
+}
+
+fn benchmark_packet<P>(c: &mut Criterion, name: &str, pkt: P)
+where
+    P: Marshal + MarshalSize + Unmarshal + PartialEq + std::fmt::Debug,
+{
+    let raw = pkt.marshal().unwrap();
+    let p = P::unmarshal(&mut raw.clone()).unwrap();
+    if pkt != p {
+        panic!("marshal or unmarshal not correct: \npkt: {pkt:?} \nvs \np: {p:?}");
+    }
+
+    let mut buf = BytesMut::with_capacity(pkt.marshal_size());
+    buf.resize(pkt.marshal_size(), 0);
+    c.bench_function(&format!("Benchmark {name} MarshalTo"), |b| {
+        b.iter(|| {
+            let _ = pkt.marshal_to(&mut buf).unwrap();
+        })
+    });
+
+    c.bench_function(&format!("Benchmark {name} Marshal"), |b| {
+        b.iter(|| {
+            let _ = pkt.marshal().unwrap();
+        })
+    });
+
+    c.bench_function(&format!("Benchmark {name} Unmarshal"), |b| {
+        b.iter(|| {
+            let buf = &mut raw.clone();
+            let _ = P::unmarshal(buf).unwrap();
+        })
+    });
+}
+
+fn benchmark_sender_report(c: &mut Criterion) {
+    let pkt = SenderReport {
+        ssrc: 0x902f9e2e,
+        ntp_time: 0xda8bd1fcdddda05a,
+        rtp_time: 0xaaf4edd5,
+        packet_count: 1,
+        octet_count: 2,
+        reports: reception_reports(31),
+        ..Default::default()
+    };
+    benchmark_packet(c, "SenderReport (31 reports)", pkt);
+}
+
+fn benchmark_receiver_report(c: &mut Criterion) {
+    let pkt = ReceiverReport {
+        ssrc: 0x902f9e2e,
+        reports: reception_reports(31),
+        ..Default::default()
+    };
+    benchmark_packet(c, "ReceiverReport (31 reports)", pkt);
+}
+
+fn benchmark_source_description(c: &mut Criterion) {
+    let pkt = SourceDescription {
+        chunks: vec![SourceDescriptionChunk {
+            source: 0x902f9e2e,
+            items: vec![SourceDescriptionItem {
+                sdes_type: SdesType::SdesCname,
+                text: Bytes::from_static(b"{9c00eb92-1afb-9d49-a47d-91f64eee69f5}"),
+            }],
+        }],
+    };
+    benchmark_packet(c, "SourceDescription", pkt);
+}
+
+fn benchmark_transport_layer_nack(c: &mut Criterion) {
+    let pkt = TransportLayerNack {
+        sender_ssrc: 0x902f9e2e,
+        media_ssrc: 0x4bc4fcb4,
+        nacks: (0..16)
+            .map(|i| NackPair {
+                packet_id: i * 17,
+                lost_packets: 0xaaaa,
+            })
+            .collect(),
+    };
+    benchmark_packet(c, "TransportLayerNack", pkt);
+}
+
+fn benchmark_receiver_estimated_maximum_bitrate(c: &mut Criterion) {
+    let pkt = ReceiverEstimatedMaximumBitrate {
+        sender_ssrc: 0x902f9e2e,
+        bitrate: 8927168.0,
+        ssrcs: vec![0x4bc4fcb4, 0x902f9e2e],
+    };
+    benchmark_packet(c, "ReceiverEstimatedMaximumBitrate", pkt);
+}
+
+criterion_group!(
+    benches,
+    benchmark_sender_report,
+    benchmark_receiver_report,
+    benchmark_source_description,
+    benchmark_transport_layer_nack,
+    benchmark_receiver_estimated_maximum_bitrate
+);
+criterion_main!(benches);
@@ -0,0 +1,181 @@
+use std::fmt;
+
+use bytes::{Buf, BufMut, Bytes};
+use util::marshal::{Marshal, MarshalSize};
+
+use crate::error::{Error, Result};
+use crate::packet::*;
+
+/// CachedPacket is a parsed RTCP packet that retains the bytes it was unmarshaled from.
+///
+/// As long as the packet is not accessed mutably, marshaling it returns the original bytes,
+/// rather than encoding the packet once more, which saves relays from paying the marshal cost
+/// of packets they forward unchanged.
+#[derive(Debug, Clone)]
+pub struct CachedPacket {
+    packet: Box<dyn Packet + Send + Sync>,
+    original: Option<Bytes>,
+}
+
+impl PartialEq for CachedPacket {
+    fn eq(&self, other: &Self) -> bool {
+        self.packet.equal(other.packet.as_ref()) && self.original == other.original
+    }
+}
+
+impl fmt::Display for CachedPacket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.packet)
+    }
+}
+
+impl CachedPacket {
+    /// new wraps a packet that has not been unmarshaled, and thus has no original bytes.
+    pub fn new(packet: Box<dyn Packet + Send + Sync>) -> Self {
+        CachedPacket {
+            packet,
+            original: None,
+        }
+    }
+
+    /// packet returns the parsed packet.
+    pub fn packet(&self) -> &(dyn Packet + Send + Sync) {
+        self.packet.as_ref()
+    }
+
+    /// packet_mut returns the parsed packet for modification,
+    /// discarding the original bytes, as they may no longer match the packet.
+    pub fn packet_mut(&mut self) -> &mut Box<dyn Packet + Send + Sync> {
+        self.original = None;
+        &mut self.packet
+    }
+
+    /// raw_bytes returns the bytes the packet was unmarshaled from,
+    /// or `None` if it has been modified since.
+    pub fn raw_bytes(&self) -> Option<&Bytes> {
+        self.original.as_ref()
+    }
+
+    /// into_packet returns the parsed packet, discarding the original bytes.
+    pub fn into_packet(self) -> Box<dyn Packet + Send + Sync> {
+        self.packet
+    }
+}
+
+impl MarshalSize for CachedPacket {
+    fn marshal_size(&self) -> usize {
+        match &self.original {
+            Some(original) => original.len(),
+            None => self.packet.marshal_size(),
+        }
+    }
+}
+
+impl Marshal for CachedPacket {
+    /// Marshal encodes the packet in binary, or copies the original bytes if unmodified.
+    fn marshal_to(&self, mut buf: &mut [u8]) -> std::result::Result<usize, util::Error> {
+        match &self.original {
+            Some(original) => {
+                if buf.remaining_mut() < original.len() {
+                    return Err(Error::BufferTooShort.into());
+                }
+                buf.put_slice(original);
+                Ok(original.len())
+            }
+            None => self.packet.marshal_to(buf),
+        }
+    }
+}
+
+/// unmarshal_cached takes an entire udp datagram (which may consist of multiple RTCP packets)
+/// and returns the unmarshaled packets it contains, each retaining its original bytes.
+///
+/// The original bytes are slices of `raw`, hence retaining them does not copy any data.
+pub fn unmarshal_cached(raw: &Bytes) -> Result<Vec<CachedPacket>> {
+    let mut raw_data = raw.clone();
+    let mut packets = vec![];
+
+    while raw_data.has_remaining() {
+        let offset = raw.len() - raw_data.remaining();
+        let packet = unmarshaller(&mut raw_data)?;
+        let end = raw.len() - raw_data.remaining();
+
+        packets.push(CachedPacket {
+            packet,
+            original: Some(raw.slice(offset..end)),
+        });
+    }
+
+    match packets.len() {
+        // Empty Packet
+        0 => Err(Error::InvalidHeader),
+
+        // Multiple Packet
+        _ => Ok(packets),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::payload_feedbacks::picture_loss_indication::PictureLossIndication;
+
+    #[test]
+    fn test_cached_packet_roundtrip() -> Result<()> {
+        let raw = Bytes::from_static(&[
+            // Goodbye with an empty reason, not reproduced by re-marshaling
+            0x81, 0xcb, 0x00, 0x02, // v=2, p=0, count=1, BYE, len=2
+            0x90, 0x2f, 0x9e, 0x2e, // source=0x902f9e2e
+            0x00, 0x00, 0x00, 0x00, // len=0, padding
+            // Picture Loss Indication
+            0x81, 0xce, 0x00, 0x02, // v=2, p=0, FMT=1, PSFB, len=2
+            0x90, 0x2f, 0x9e, 0x2e, // sender=0x902f9e2e
+            0x90, 0x2f, 0x9e, 0x2e, // media=0x902f9e2e
+        ]);
+
+        let packets = unmarshal_cached(&raw)?;
+        assert_eq!(packets.len(), 2);
+
+        let mut out = vec![];
+        for packet in &packets {
+            assert!(packet.raw_bytes().is_some());
+            out.extend_from_slice(&packet.marshal()?);
+        }
+        assert_eq!(
+            out, raw,
+            "unmodified packets should re-marshal to the input bytes"
+        );
+
+        // Re-marshaling the parsed packet does not reproduce the empty reason:
+        assert_ne!(packets[0].packet().marshal()?, raw.slice(..12));
+
+        let mut packet = packets[1].clone();
+        let mut pli = match packet
+            .packet()
+            .as_any()
+            .downcast_ref::<PictureLossIndication>()
+        {
+            Some(pli) => pli.clone(),
+            None => panic!("Unmarshal(pli) = {packet:?}, want PictureLossIndication"),
+        };
+        pli.media_ssrc = 0x1234_5678;
+        *packet.packet_mut() = Box::new(pli);
+        assert_eq!(packet.raw_bytes(), None);
+        assert_eq!(
+            packet.marshal()?,
+            Bytes::from_static(&[
+                0x81, 0xce, 0x00, 0x02, // v=2, p=0, FMT=1, PSFB, len=2
+                0x90, 0x2f, 0x9e, 0x2e, // sender=0x902f9e2e
+                0x12, 0x34, 0x56, 0x78, // media=0x12345678
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cached_packet_empty() {
+        let result = unmarshal_cached(&Bytes::new());
+        assert_eq!(result, Err(Error::InvalidHeader));
+    }
+}
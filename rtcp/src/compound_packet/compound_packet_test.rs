@@ -1,6 +1,7 @@
 use super::*;
 use crate::goodbye::Goodbye;
 use crate::payload_feedbacks::picture_loss_indication::PictureLossIndication;
+use crate::reception_report::ReceptionReport;
 
 // An RTCP packet from a packet dump
 const REAL_PACKET: [u8; 116] = [
@@ -268,6 +269,47 @@ fn test_cname() {
     }
 }
 
+#[test]
+fn test_compound_packet_sdes_cname() {
+    let compound_packet = CompoundPacket(vec![
+        Box::<ReceiverReport>::default(),
+        Box::<PictureLossIndication>::default(),
+        Box::new(SourceDescription {
+            chunks: vec![
+                SourceDescriptionChunk {
+                    source: 1234,
+                    items: vec![
+                        SourceDescriptionItem {
+                            sdes_type: SdesType::SdesName,
+                            text: Bytes::from_static(b"name"),
+                        },
+                        SourceDescriptionItem {
+                            sdes_type: SdesType::SdesCname,
+                            text: Bytes::from_static(b"cname"),
+                        },
+                    ],
+                },
+                SourceDescriptionChunk {
+                    source: 5678,
+                    items: vec![SourceDescriptionItem {
+                        sdes_type: SdesType::SdesCname,
+                        text: Bytes::from_static(b"other"),
+                    }],
+                },
+            ],
+        }),
+    ]);
+    assert_eq!(compound_packet.sdes_cname(), Some("cname"));
+
+    let compound_packet = CompoundPacket(vec![
+        Box::<ReceiverReport>::default(),
+        Box::<SourceDescription>::default(),
+    ]);
+    assert_eq!(compound_packet.sdes_cname(), None);
+
+    assert_eq!(CompoundPacket::default().sdes_cname(), None);
+}
+
 #[test]
 fn test_compound_packet_roundtrip() {
     let cname = SourceDescription {
@@ -327,3 +369,219 @@ fn test_compound_packet_roundtrip() {
         )
     }
 }
+
+#[test]
+fn test_compound_packet_from_packets() -> Result<()> {
+    let cname = || -> Box<dyn Packet + Send + Sync> {
+        Box::new(SourceDescription {
+            chunks: vec![SourceDescriptionChunk {
+                source: 1234,
+                items: vec![SourceDescriptionItem {
+                    sdes_type: SdesType::SdesCname,
+                    text: Bytes::from_static(b"cname"),
+                }],
+            }],
+        })
+    };
+    // A reason of a single byte requires padding:
+    let padded_bye = || -> Box<dyn Packet + Send + Sync> {
+        Box::new(Goodbye::new(vec![1234], Some("x".to_owned())).unwrap())
+    };
+
+    let compound = CompoundPacket::from_packets(vec![
+        Box::<ReceiverReport>::default(),
+        cname(),
+        padded_bye(),
+    ])?;
+    assert_eq!(compound.len(), 3);
+
+    let result = CompoundPacket::from_packets(vec![]);
+    assert_eq!(Error::EmptyCompound, result.unwrap_err());
+
+    let result = CompoundPacket::from_packets(vec![cname(), Box::<ReceiverReport>::default()]);
+    assert_eq!(Error::BadFirstPacket, result.unwrap_err());
+
+    let result = CompoundPacket::from_packets(vec![Box::<SenderReport>::default()]);
+    assert_eq!(Error::MissingCname, result.unwrap_err());
+
+    let result = CompoundPacket::from_packets(vec![
+        Box::<ReceiverReport>::default(),
+        cname(),
+        padded_bye(),
+        Box::<PictureLossIndication>::default(),
+    ]);
+    assert_eq!(Error::WrongPadding, result.unwrap_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_compound_packet_push() -> Result<()> {
+    let sr = SenderReport {
+        ssrc: 0x902f9e2e,
+        ntp_time: 0xda8bd1fcdddda05a,
+        rtp_time: 0xaaf4edd5,
+        packet_count: 1,
+        octet_count: 2,
+        ..Default::default()
+    };
+    let sdes = SourceDescription {
+        chunks: vec![SourceDescriptionChunk {
+            source: 0x902f9e2e,
+            items: vec![SourceDescriptionItem {
+                sdes_type: SdesType::SdesCname,
+                text: Bytes::from_static(b"cname"),
+            }],
+        }],
+    };
+    let bye = Goodbye {
+        sources: vec![0x902f9e2e],
+        reason: Bytes::from_static(b"leaving"),
+    };
+
+    let mut compound = CompoundPacket::default();
+    assert!(compound.is_empty());
+
+    compound.push(Box::new(sr.clone()));
+    compound.push(Box::new(sdes.clone()));
+    compound.push(Box::new(bye.clone()));
+    assert_eq!(compound.len(), 3);
+    assert!(!compound.is_empty());
+
+    let data = compound.marshal()?;
+    assert_eq!(
+        data.len(),
+        sr.marshal_size() + sdes.marshal_size() + bye.marshal_size()
+    );
+
+    let actual = CompoundPacket::unmarshal(&mut data.clone())?;
+    assert_eq!(actual.len(), 3);
+    assert!(
+        actual.0[0].equal(&sr),
+        "first packet = {:?}, want {sr:?}",
+        actual.0[0]
+    );
+    assert!(
+        actual.0[1].equal(&sdes),
+        "second packet = {:?}, want {sdes:?}",
+        actual.0[1]
+    );
+    assert!(
+        actual.0[2].equal(&bye),
+        "third packet = {:?}, want {bye:?}",
+        actual.0[2]
+    );
+    assert_eq!(actual, compound);
+
+    Ok(())
+}
+
+#[test]
+fn test_compound_packet_builder_trims_reports() -> Result<()> {
+    let mut builder = CompoundPacketBuilder::new(0x902f9e2e, Bytes::from_static(b"cname"))
+        .sender_info(0xda8bd1fcdddda05a, 0xaaf4edd5, 1, 2)
+        .packet(Box::new(PictureLossIndication {
+            sender_ssrc: 0x902f9e2e,
+            media_ssrc: 0xbc5e9a40,
+        }))
+        .mtu(1200)
+        .target_size(1000);
+    for ssrc in 0..100 {
+        builder = builder.report(ReceptionReport {
+            ssrc,
+            ..Default::default()
+        });
+    }
+
+    let compound = builder.build()?;
+    let data = compound.marshal()?;
+    assert!(
+        data.len() <= 1000,
+        "marshaled size {} exceeds limit",
+        data.len()
+    );
+
+    let parsed = CompoundPacket::unmarshal(&mut data.clone())?;
+    assert_eq!(parsed, compound);
+
+    // SR(28) + SDES(16) + PLI(12) + 31 blocks, then RR(8) + 8 more blocks:
+    assert_eq!(data.len(), 28 + 16 + 12 + 31 * 24 + 8 + 8 * 24);
+    assert_eq!(compound.len(), 4);
+
+    let sr = compound.0[0]
+        .as_any()
+        .downcast_ref::<SenderReport>()
+        .expect("first packet should be a SenderReport");
+    let rr = compound.0[1]
+        .as_any()
+        .downcast_ref::<ReceiverReport>()
+        .expect("second packet should be a ReceiverReport");
+    let kept: Vec<u32> = sr
+        .reports
+        .iter()
+        .chain(&rr.reports)
+        .map(|r| r.ssrc)
+        .collect();
+    assert_eq!(
+        kept,
+        (0..39).collect::<Vec<_>>(),
+        "highest priority reports kept"
+    );
+
+    assert!(compound.0[2].as_any().is::<SourceDescription>());
+    assert!(compound.0[3].as_any().is::<PictureLossIndication>());
+
+    Ok(())
+}
+
+#[test]
+fn test_compound_packet_builder() -> Result<()> {
+    let compound = CompoundPacketBuilder::new(1234, Bytes::from_static(b"cname"))
+        .report(ReceptionReport::default())
+        .build()?;
+
+    assert_eq!(compound.len(), 2);
+    assert_eq!(compound.cname()?, Bytes::from_static(b"cname"));
+    let rr = compound.0[0]
+        .as_any()
+        .downcast_ref::<ReceiverReport>()
+        .expect("first packet should be a ReceiverReport");
+    assert_eq!(rr.ssrc, 1234);
+    assert_eq!(rr.reports.len(), 1);
+
+    let result = CompoundPacketBuilder::new(1234, Bytes::from_static(b"cname"))
+        .mtu(20)
+        .build();
+    assert_eq!(Error::CompoundTooLarge, result.unwrap_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_unmarshal_srtcp() -> Result<()> {
+    const AUTH_TAG_LEN: usize = 10;
+
+    let mut raw = REAL_PACKET.to_vec();
+    raw.extend_from_slice(&[0x80, 0x00, 0x00, 0x2a]); // E=1, index=42
+    raw.extend_from_slice(&[0xaa; AUTH_TAG_LEN]); // auth tag
+    let raw = Bytes::from(raw);
+
+    assert_eq!(srtcp_index(&raw, AUTH_TAG_LEN)?, 0x8000_002a);
+    assert_eq!(srtcp_index(&raw, AUTH_TAG_LEN)? & 0x7fff_ffff, 42);
+
+    let actual = CompoundPacket::unmarshal_srtcp(&raw, AUTH_TAG_LEN)?;
+    let expected = CompoundPacket::unmarshal(&mut Bytes::from_static(&REAL_PACKET))?;
+    assert_eq!(actual, expected);
+
+    // The trailer is not a valid RTCP packet on its own:
+    let result = CompoundPacket::unmarshal(&mut raw.clone());
+    assert!(result.is_err(), "Unmarshal(srtcp) = {result:?}, want error");
+
+    let short = raw.slice(..HEADER_LENGTH + AUTH_TAG_LEN);
+    let got = srtcp_index(&short, AUTH_TAG_LEN);
+    assert_eq!(Error::PacketTooShort, got.unwrap_err());
+    let got = CompoundPacket::unmarshal_srtcp(&short, AUTH_TAG_LEN);
+    assert_eq!(Error::PacketTooShort, got.unwrap_err());
+
+    Ok(())
+}
@@ -11,12 +11,30 @@ use crate::error::Error;
 use crate::header::*;
 use crate::packet::*;
 use crate::receiver_report::*;
+use crate::reception_report::*;
 use crate::sender_report::*;
 use crate::source_description::*;
 use crate::util::*;
 
 type Result<T> = std::result::Result<T, util::Error>;
 
+/// SRTCP_INDEX_LENGTH is the length of the E-flag and SRTCP index trailing an SRTCP packet.
+pub const SRTCP_INDEX_LENGTH: usize = 4;
+
+/// srtcp_index returns the 32-bit word trailing the compound packet of an SRTCP packet,
+/// ahead of its `auth_tag_len` bytes of authentication tag, as per RFC 3711, Section 3.4.
+/// The most significant bit is the E-flag, indicating whether the payload is encrypted,
+/// while the remaining 31 bits are the SRTCP index.
+pub fn srtcp_index(raw: &Bytes, auth_tag_len: usize) -> Result<u32> {
+    let trailer_len = SRTCP_INDEX_LENGTH + auth_tag_len;
+    if raw.len() < HEADER_LENGTH + trailer_len {
+        return Err(Error::PacketTooShort.into());
+    }
+
+    let mut index = raw.slice(raw.len() - trailer_len..);
+    Ok(index.get_u32())
+}
+
 /// A CompoundPacket is a collection of RTCP packets transmitted as a single packet with
 /// the underlying protocol (for example UDP).
 ///
@@ -53,6 +71,12 @@ impl Packet for CompoundPacket {
         }
     }
 
+    fn destination_ssrc_into(&self, out: &mut Vec<u32>) {
+        if let Some(packet) = self.0.first() {
+            packet.destination_ssrc_into(out);
+        }
+    }
+
     fn raw_size(&self) -> usize {
         let mut l = 0;
         for packet in &self.0 {
@@ -120,6 +144,41 @@ impl Unmarshal for CompoundPacket {
 }
 
 impl CompoundPacket {
+    /// from_packets creates a CompoundPacket from the given packets,
+    /// failing if they do not form an RFC-compliant CompoundPacket.
+    ///
+    /// Beyond the checks performed by `validate`, only the last packet may be padded,
+    /// as per RFC 3550, Section 6.4.1.
+    pub fn from_packets(packets: Vec<Box<dyn Packet + Send + Sync>>) -> Result<Self> {
+        let compound = CompoundPacket(packets);
+        compound.validate()?;
+
+        if let Some((_, init)) = compound.0.split_last() {
+            if init.iter().any(|pkt| pkt.header().padding) {
+                return Err(Error::WrongPadding.into());
+            }
+        }
+
+        Ok(compound)
+    }
+
+    /// push appends a packet to the end of this CompoundPacket.
+    ///
+    /// The packet is not validated until the CompoundPacket gets marshaled (or validated).
+    pub fn push(&mut self, packet: Box<dyn Packet + Send + Sync>) {
+        self.0.push(packet);
+    }
+
+    /// len returns the number of packets contained in this CompoundPacket.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// is_empty returns true if this CompoundPacket contains no packets.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
     /// Validate returns an error if this is not an RFC-compliant CompoundPacket.
     pub fn validate(&self) -> Result<()> {
         if self.0.is_empty() {
@@ -170,6 +229,18 @@ impl CompoundPacket {
         Err(Error::MissingCname.into())
     }
 
+    /// unmarshal_srtcp decodes the CompoundPacket of a (decrypted) SRTCP packet,
+    /// ignoring the trailing SRTCP index and `auth_tag_len` bytes of authentication tag.
+    pub fn unmarshal_srtcp(raw: &Bytes, auth_tag_len: usize) -> Result<Self> {
+        let trailer_len = SRTCP_INDEX_LENGTH + auth_tag_len;
+        if raw.len() < HEADER_LENGTH + trailer_len {
+            return Err(Error::PacketTooShort.into());
+        }
+
+        let mut compound = raw.slice(..raw.len() - trailer_len);
+        Self::unmarshal(&mut compound)
+    }
+
     /// CNAME returns the CNAME that *must* be present in every CompoundPacket
     pub fn cname(&self) -> Result<Bytes> {
         if self.0.is_empty() {
@@ -192,4 +263,170 @@ impl CompoundPacket {
 
         Err(Error::MissingCname.into())
     }
+
+    /// sdes_cname returns the CNAME of the first chunk of the first SourceDescription
+    /// in this compound packet, or `None` if there is no such CNAME, or it is not valid UTF-8.
+    ///
+    /// Unlike `cname`, this does not validate the order of the packets.
+    pub fn sdes_cname(&self) -> Option<&str> {
+        self.0
+            .iter()
+            .find_map(|pkt| pkt.as_any().downcast_ref::<SourceDescription>())
+            .and_then(|sdes| sdes.chunks.first())
+            .and_then(SourceDescriptionChunk::cname)
+    }
+}
+
+/// CompoundPacketBuilder assembles a [`CompoundPacket`] that stays within a size limit,
+/// consisting of a SenderReport (or ReceiverReport), a SourceDescription with a CNAME,
+/// and any number of additional (e.g. feedback) packets.
+///
+/// The limit is the smaller one of the MTU (i.e. the space left for RTCP in a datagram)
+/// and the target average RTCP packet size (as used for computing the RTCP interval).
+/// Reception reports are prioritized in the order in which they were added. Those that don't fit
+/// get dropped, lowest priority first, while all other packets are mandatory.
+/// Reports exceeding the 31 blocks of the first packet overflow into additional ReceiverReports.
+#[derive(Debug, Default, Clone)]
+pub struct CompoundPacketBuilder {
+    ssrc: u32,
+    cname: Bytes,
+    sender_info: Option<(u64, u32, u32, u32)>,
+    reports: Vec<ReceptionReport>,
+    packets: Vec<Box<dyn Packet + Send + Sync>>,
+    mtu: Option<usize>,
+    target_size: Option<usize>,
+}
+
+impl CompoundPacketBuilder {
+    /// new returns a builder for compound packets sent by `ssrc`, identified by `cname`.
+    pub fn new(ssrc: u32, cname: Bytes) -> Self {
+        CompoundPacketBuilder {
+            ssrc,
+            cname,
+            ..Default::default()
+        }
+    }
+
+    /// sender_info turns the first packet into a SenderReport, carrying the given sender info.
+    pub fn sender_info(
+        mut self,
+        ntp_time: u64,
+        rtp_time: u32,
+        packet_count: u32,
+        octet_count: u32,
+    ) -> Self {
+        self.sender_info = Some((ntp_time, rtp_time, packet_count, octet_count));
+        self
+    }
+
+    /// report appends a reception report block, with lower priority than those added before.
+    pub fn report(mut self, report: ReceptionReport) -> Self {
+        self.reports.push(report);
+        self
+    }
+
+    /// packet appends a packet (e.g. feedback) to follow the SourceDescription.
+    pub fn packet(mut self, packet: Box<dyn Packet + Send + Sync>) -> Self {
+        self.packets.push(packet);
+        self
+    }
+
+    /// mtu limits the size of the compound packet to `mtu` bytes.
+    pub fn mtu(mut self, mtu: usize) -> Self {
+        self.mtu = Some(mtu);
+        self
+    }
+
+    /// target_size limits the size of the compound packet to the target average RTCP size.
+    pub fn target_size(mut self, target_size: usize) -> Self {
+        self.target_size = Some(target_size);
+        self
+    }
+
+    /// build returns the CompoundPacket, or [`Error::CompoundTooLarge`] if its mandatory
+    /// packets alone exceed the size limit.
+    pub fn build(self) -> Result<CompoundPacket> {
+        let limit = match (self.mtu, self.target_size) {
+            (Some(mtu), Some(target_size)) => mtu.min(target_size),
+            (Some(limit), None) | (None, Some(limit)) => limit,
+            (None, None) => usize::MAX,
+        };
+
+        let sdes = SourceDescription {
+            chunks: vec![SourceDescriptionChunk {
+                source: self.ssrc,
+                items: vec![SourceDescriptionItem {
+                    sdes_type: SdesType::SdesCname,
+                    text: self.cname.clone(),
+                }],
+            }],
+        };
+
+        let mut first = self.first_packet(vec![]);
+        let overflow_size = ReceiverReport {
+            ssrc: self.ssrc,
+            ..Default::default()
+        }
+        .marshal_size();
+
+        let mandatory_size = first.marshal_size()
+            + sdes.marshal_size()
+            + self.packets.iter().map(|p| p.marshal_size()).sum::<usize>();
+        if mandatory_size > limit {
+            return Err(Error::CompoundTooLarge.into());
+        }
+
+        // Every COUNT_MAX reports another ReceiverReport is needed to hold them:
+        let mut size = mandatory_size;
+        let mut count = 0;
+        for i in 0..self.reports.len() {
+            let mut report_size = RECEPTION_REPORT_LENGTH;
+            if i > 0 && i % COUNT_MAX == 0 {
+                report_size += overflow_size;
+            }
+            if size + report_size > limit {
+                break;
+            }
+            size += report_size;
+            count += 1;
+        }
+
+        let mut chunks = self.reports[..count].chunks(COUNT_MAX);
+
+        if let Some(chunk) = chunks.next() {
+            first = self.first_packet(chunk.to_vec());
+        }
+
+        let mut packets = vec![first];
+        for chunk in chunks {
+            packets.push(Box::new(ReceiverReport {
+                ssrc: self.ssrc,
+                reports: chunk.to_vec(),
+                ..Default::default()
+            }));
+        }
+        packets.push(Box::new(sdes));
+        packets.extend(self.packets);
+
+        Ok(CompoundPacket(packets))
+    }
+
+    fn first_packet(&self, reports: Vec<ReceptionReport>) -> Box<dyn Packet + Send + Sync> {
+        match self.sender_info {
+            Some((ntp_time, rtp_time, packet_count, octet_count)) => Box::new(SenderReport {
+                ssrc: self.ssrc,
+                ntp_time,
+                rtp_time,
+                packet_count,
+                octet_count,
+                reports,
+                ..Default::default()
+            }),
+            None => Box::new(ReceiverReport {
+                ssrc: self.ssrc,
+                reports,
+                ..Default::default()
+            }),
+        }
+    }
 }
@@ -0,0 +1,96 @@
+//! Encoding and decoding of the lists of 32-bit source identifiers (SSRCs/CSRCs)
+//! carried by packets referencing (contributing) sources, e.g. Goodbye or REMB packets.
+//!
+//! SenderReports and ReceiverReports do not carry CSRCs themselves: a mixer reports
+//! the sources it mixed via the CSRC lists of its RTP packets, and the packets
+//! referencing those sources (e.g. a mixer's BYE for a contributing source) list them as below.
+
+use bytes::{Buf, BufMut};
+
+use crate::error::Error;
+
+type Result<T> = std::result::Result<T, util::Error>;
+
+/// CSRC_LENGTH is the length of a single SSRC/CSRC identifier in a list.
+pub const CSRC_LENGTH: usize = 4;
+
+/// csrc_list_size returns the size of a list of `count` SSRC/CSRC identifiers.
+pub fn csrc_list_size(count: usize) -> usize {
+    count * CSRC_LENGTH
+}
+
+/// marshal_csrc_list encodes `csrcs` as consecutive 32-bit big-endian identifiers,
+/// returning the number of bytes written.
+///
+/// Fails with `Error::BufferTooShort` if `buf` is too small to hold them all.
+pub fn marshal_csrc_list(mut buf: &mut [u8], csrcs: &[u32]) -> Result<usize> {
+    let size = csrc_list_size(csrcs.len());
+    if buf.remaining_mut() < size {
+        return Err(Error::BufferTooShort.into());
+    }
+
+    for csrc in csrcs {
+        buf.put_u32(*csrc);
+    }
+
+    Ok(size)
+}
+
+/// unmarshal_csrc_list decodes a list of `count` consecutive 32-bit big-endian identifiers.
+///
+/// Fails with `Error::PacketTooShort` if `buf` is too short to contain them all,
+/// without consuming any of it.
+pub fn unmarshal_csrc_list<B>(buf: &mut B, count: usize) -> Result<Vec<u32>>
+where
+    B: Buf,
+{
+    if buf.remaining() < csrc_list_size(count) {
+        return Err(Error::PacketTooShort.into());
+    }
+
+    let mut csrcs = Vec::with_capacity(count);
+    for _ in 0..count {
+        csrcs.push(buf.get_u32());
+    }
+
+    Ok(csrcs)
+}
+
+#[cfg(test)]
+mod test {
+    use bytes::Bytes;
+
+    use super::*;
+
+    #[test]
+    fn test_csrc_list_round_trip() -> Result<()> {
+        let csrcs = vec![0x902f9e2e, 0x01020304, 0xffffffff];
+
+        let mut buf = vec![0u8; csrc_list_size(csrcs.len())];
+        let n = marshal_csrc_list(&mut buf, &csrcs)?;
+        assert_eq!(n, 12);
+        assert_eq!(
+            buf,
+            vec![0x90, 0x2f, 0x9e, 0x2e, 0x01, 0x02, 0x03, 0x04, 0xff, 0xff, 0xff, 0xff]
+        );
+
+        let mut raw = Bytes::from(buf);
+        let decoded = unmarshal_csrc_list(&mut raw, csrcs.len())?;
+        assert_eq!(decoded, csrcs);
+        assert!(raw.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csrc_list_too_short() {
+        let mut buf = vec![0u8; 11];
+        let result = marshal_csrc_list(&mut buf, &[1, 2, 3]);
+        assert_eq!(Error::BufferTooShort, result.unwrap_err());
+
+        let mut raw = Bytes::from(buf);
+        let result = unmarshal_csrc_list(&mut raw, 3);
+        assert_eq!(Error::PacketTooShort, result.unwrap_err());
+        assert_eq!(raw.len(), 11);
+    }
+}
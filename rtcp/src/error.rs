@@ -1,3 +1,5 @@
+use std::fmt;
+
 use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -18,6 +20,9 @@ pub enum Error {
     /// Packet contains empty compound.
     #[error("Empty compound packet")]
     EmptyCompound,
+    /// Mandatory packets of a compound exceed its size limit.
+    #[error("Compound packet exceeds size limit")]
+    CompoundTooLarge,
     /// Invalid first packet in compound packets. First packet
     /// should either be a SenderReport packet or ReceiverReport
     #[error("First packet in compound must be SR or RR")]
@@ -31,6 +36,9 @@ pub enum Error {
     /// Too many reports.
     #[error("Too many reports")]
     TooManyReports,
+    /// Reception report count exceeds the number of blocks fitting in the packet.
+    #[error("Reception report count does not match packet length")]
+    InvalidReportCount,
     /// Too many chunks.
     #[error("Too many chunks")]
     TooManyChunks,
@@ -52,6 +60,12 @@ pub enum Error {
     /// SDES type is missing.
     #[error("SDES item missing type")]
     SdesMissingType,
+    /// SDES PRIV item prefix doesn't fit in the item.
+    #[error("SDES PRIV item prefix length exceeds item length")]
+    SdesInvalidPrivatePrefix,
+    /// SDES unknown item type is one of the known item types.
+    #[error("SDES unknown item type must not be a known item type")]
+    SdesInvalidUnknownType,
     /// Reason is too long.
     #[error("Reason must be < 255 octets long")]
     ReasonTooLong,
@@ -88,6 +102,12 @@ pub enum Error {
     /// Packet status chunk is not 2 bytes.
     #[error("Packet status chunk must be 2 bytes")]
     PacketStatusChunkLength,
+    /// RAMS TLV value is too long.
+    #[error("RAMS TLV value must be < 65536 octets long")]
+    RamsTlvTooLong,
+    /// RAMS message is too long for the length field of its header.
+    #[error("RAMS message must be at most 262144 octets long")]
+    RamsTooLong,
     #[error("Invalid bitrate")]
     InvalidBitrate,
     #[error("Wrong chunk type")]
@@ -102,6 +122,53 @@ pub enum Error {
 
     #[error("{0}")]
     Other(String),
+
+    /// An error annotated with the context it occurred in (e.g. the packet being unmarshaled),
+    /// whose `source()` is the annotated error.
+    #[error(transparent)]
+    Context(ContextError),
+}
+
+/// ContextError is an error annotated with the context it occurred in, as created by [`Error::context`].
+///
+/// Unlike a `#[source]` field, its `source()` is the annotated `Error` itself (rather than its box),
+/// so that it can be downcast to `Error` when walking the chain of sources.
+#[derive(Debug, PartialEq)]
+pub struct ContextError {
+    pub context: String,
+    pub source: Box<Error>,
+}
+
+impl fmt::Display for ContextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.context)
+    }
+}
+
+impl std::error::Error for ContextError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&*self.source)
+    }
+}
+
+impl Error {
+    /// context wraps the error, annotating it with `context`,
+    /// while retaining it as the source of the returned error.
+    pub fn context<C: Into<String>>(self, context: C) -> Self {
+        Error::Context(ContextError {
+            context: context.into(),
+            source: Box::new(self),
+        })
+    }
+
+    /// root_cause returns the innermost error wrapped by `context`,
+    /// or the error itself if it has not been annotated.
+    pub fn root_cause(&self) -> &Error {
+        match self {
+            Error::Context(ContextError { source, .. }) => source.root_cause(),
+            err => err,
+        }
+    }
 }
 
 impl From<Error> for util::Error {
@@ -118,3 +185,54 @@ impl PartialEq<util::Error> for Error {
         false
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::error::Error as StdError;
+
+    use super::*;
+
+    #[test]
+    fn test_error_context_source() {
+        let err = Error::PacketTooShort
+            .context("unmarshaling report block")
+            .context("unmarshaling sender report");
+        assert_eq!(err.to_string(), "unmarshaling sender report");
+
+        let mut chain = vec![];
+        let mut source: Option<&(dyn StdError + 'static)> = Some(&err);
+        while let Some(err) = source {
+            chain.push(err.to_string());
+            source = err.source();
+        }
+        assert_eq!(
+            chain,
+            vec![
+                "unmarshaling sender report",
+                "unmarshaling report block",
+                "Packet status chunk must be 2 bytes",
+            ]
+        );
+
+        // Every source in the chain downcasts to `Error`:
+        let mut sources = vec![];
+        let mut source = err.source();
+        while let Some(err) = source {
+            sources.push(err.downcast_ref::<Error>());
+            source = err.source();
+        }
+        assert_eq!(
+            sources,
+            vec![
+                Some(&Error::PacketTooShort.context("unmarshaling report block")),
+                Some(&Error::PacketTooShort),
+            ]
+        );
+        assert_eq!(err.root_cause(), &Error::PacketTooShort);
+        assert_eq!(Error::WrongType.root_cause(), &Error::WrongType);
+
+        // Crossing the `util::Error` boundary of `Marshal`/`Unmarshal` retains the context:
+        let err: util::Error = Error::PacketTooShort.context("unmarshaling goodbye").into();
+        assert_eq!(Error::PacketTooShort.context("unmarshaling goodbye"), err);
+    }
+}
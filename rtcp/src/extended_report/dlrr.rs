@@ -1,6 +1,6 @@
 use super::*;
 
-const DLRR_REPORT_LENGTH: u16 = 12;
+const DLRR_REPORT_LENGTH: usize = 12;
 
 /// DLRRReport encodes a single report inside a DLRRReportBlock.
 #[derive(Debug, Default, PartialEq, Eq, Clone)]
@@ -126,15 +126,15 @@ impl Unmarshal for DLRRReportBlock {
         }
 
         let xr_header = XRHeader::unmarshal(raw_packet)?;
-        let block_length = xr_header.block_length * 4;
-        if block_length % DLRR_REPORT_LENGTH != 0 || raw_packet.remaining() < block_length as usize
+        let block_length = xr_header.block_length as usize * 4;
+        let reports_len = block_length / DLRR_REPORT_LENGTH;
+        if reports_len * DLRR_REPORT_LENGTH != block_length || raw_packet.remaining() < block_length
         {
             return Err(error::Error::PacketTooShort.into());
         }
 
-        let mut offset = 0;
         let mut reports = vec![];
-        while offset < block_length {
+        for _ in 0..reports_len {
             let ssrc = raw_packet.get_u32();
             let last_rr = raw_packet.get_u32();
             let dlrr = raw_packet.get_u32();
@@ -143,7 +143,6 @@ impl Unmarshal for DLRRReportBlock {
                 last_rr,
                 dlrr,
             });
-            offset += DLRR_REPORT_LENGTH;
         }
 
         Ok(DLRRReportBlock { reports })
@@ -25,7 +25,7 @@ pub use vm::VoIPMetricsReportBlock;
 use crate::error;
 use crate::header::{Header, PacketType, HEADER_LENGTH, SSRC_LENGTH};
 use crate::packet::Packet;
-use crate::util::{get_padding_size, put_padding};
+use crate::util::{get_padding_size, put_padding, HexBytes};
 
 type Result<T> = std::result::Result<T, util::Error>;
 
@@ -257,6 +257,8 @@ impl Unmarshal for ExtendedReport {
         }
 
         let header = Header::unmarshal(raw_packet)?;
+
+        header.check_packet_size(raw_packet_len)?;
         if header.packet_type != PacketType::ExtendedReport {
             return Err(error::Error::WrongType.into());
         }
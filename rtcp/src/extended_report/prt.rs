@@ -1,6 +1,6 @@
 use super::*;
 
-const PRT_REPORT_BLOCK_MIN_LENGTH: u16 = 8;
+const PRT_REPORT_BLOCK_MIN_LENGTH: usize = 8;
 
 /// PacketReceiptTimesReportBlock represents a Packet Receipt Times
 /// report block, as described in RFC 3611 section 4.3.
@@ -61,7 +61,7 @@ impl Packet for PacketReceiptTimesReportBlock {
     }
 
     fn raw_size(&self) -> usize {
-        XR_HEADER_LENGTH + PRT_REPORT_BLOCK_MIN_LENGTH as usize + self.receipt_time.len() * 4
+        XR_HEADER_LENGTH + PRT_REPORT_BLOCK_MIN_LENGTH + self.receipt_time.len() * 4
     }
 
     fn as_any(&self) -> &(dyn Any + Send + Sync) {
@@ -118,10 +118,10 @@ impl Unmarshal for PacketReceiptTimesReportBlock {
         }
 
         let xr_header = XRHeader::unmarshal(raw_packet)?;
-        let block_length = xr_header.block_length * 4;
+        let block_length = xr_header.block_length as usize * 4;
         if block_length < PRT_REPORT_BLOCK_MIN_LENGTH
             || (block_length - PRT_REPORT_BLOCK_MIN_LENGTH) % 4 != 0
-            || raw_packet.remaining() < block_length as usize
+            || raw_packet.remaining() < block_length
         {
             return Err(error::Error::PacketTooShort.into());
         }
@@ -1,6 +1,6 @@
 use super::*;
 
-const RLE_REPORT_BLOCK_MIN_LENGTH: u16 = 8;
+const RLE_REPORT_BLOCK_MIN_LENGTH: usize = 8;
 
 /// ChunkType enumerates the three kinds of chunks described in RFC 3611 section 4.1.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -156,7 +156,7 @@ impl Packet for RLEReportBlock {
     }
 
     fn raw_size(&self) -> usize {
-        XR_HEADER_LENGTH + RLE_REPORT_BLOCK_MIN_LENGTH as usize + self.chunks.len() * 2
+        XR_HEADER_LENGTH + RLE_REPORT_BLOCK_MIN_LENGTH + self.chunks.len() * 2
     }
 
     fn as_any(&self) -> &(dyn Any + Send + Sync) {
@@ -213,10 +213,10 @@ impl Unmarshal for RLEReportBlock {
         }
 
         let xr_header = XRHeader::unmarshal(raw_packet)?;
-        let block_length = xr_header.block_length * 4;
+        let block_length = xr_header.block_length as usize * 4;
         if block_length < RLE_REPORT_BLOCK_MIN_LENGTH
             || (block_length - RLE_REPORT_BLOCK_MIN_LENGTH) % 2 != 0
-            || raw_packet.remaining() < block_length as usize
+            || raw_packet.remaining() < block_length
         {
             return Err(error::Error::PacketTooShort.into());
         }
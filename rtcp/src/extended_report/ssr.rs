@@ -1,6 +1,6 @@
 use super::*;
 
-const SSR_REPORT_BLOCK_LENGTH: u16 = 4 + 2 * 2 + 4 * 6 + 4;
+const SSR_REPORT_BLOCK_LENGTH: usize = 4 + 2 * 2 + 4 * 6 + 4;
 
 /// StatisticsSummaryReportBlock encodes a Statistics Summary Report
 /// Block as described in RFC 3611, section 4.6.
@@ -122,7 +122,7 @@ impl Packet for StatisticsSummaryReportBlock {
     }
 
     fn raw_size(&self) -> usize {
-        XR_HEADER_LENGTH + SSR_REPORT_BLOCK_LENGTH as usize
+        XR_HEADER_LENGTH + SSR_REPORT_BLOCK_LENGTH
     }
 
     fn as_any(&self) -> &(dyn Any + Send + Sync) {
@@ -186,9 +186,8 @@ impl Unmarshal for StatisticsSummaryReportBlock {
         }
 
         let xr_header = XRHeader::unmarshal(raw_packet)?;
-        let block_length = xr_header.block_length * 4;
-        if block_length != SSR_REPORT_BLOCK_LENGTH || raw_packet.remaining() < block_length as usize
-        {
+        let block_length = xr_header.block_length as usize * 4;
+        if block_length != SSR_REPORT_BLOCK_LENGTH || raw_packet.remaining() < block_length {
             return Err(error::Error::PacketTooShort.into());
         }
 
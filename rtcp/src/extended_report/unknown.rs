@@ -2,11 +2,19 @@ use super::*;
 
 /// UnknownReportBlock is used to store bytes for any report block
 /// that has an unknown Report Block Type.
-#[derive(Debug, Default, PartialEq, Eq, Clone)]
+#[derive(Default, PartialEq, Eq, Clone)]
 pub struct UnknownReportBlock {
     pub bytes: Bytes,
 }
 
+impl fmt::Debug for UnknownReportBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnknownReportBlock")
+            .field("bytes", &HexBytes(&self.bytes))
+            .finish()
+    }
+}
+
 impl fmt::Display for UnknownReportBlock {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{self:?}")
@@ -86,12 +94,12 @@ impl Unmarshal for UnknownReportBlock {
         }
 
         let xr_header = XRHeader::unmarshal(raw_packet)?;
-        let block_length = xr_header.block_length * 4;
-        if raw_packet.remaining() < block_length as usize {
+        let block_length = xr_header.block_length as usize * 4;
+        if raw_packet.remaining() < block_length {
             return Err(error::Error::PacketTooShort.into());
         }
 
-        let bytes = raw_packet.copy_to_bytes(block_length as usize);
+        let bytes = raw_packet.copy_to_bytes(block_length);
 
         Ok(UnknownReportBlock { bytes })
     }
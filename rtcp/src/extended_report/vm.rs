@@ -1,6 +1,6 @@
 use super::*;
 
-const VM_REPORT_BLOCK_LENGTH: u16 = 4 + 4 + 2 * 4 + 10 + 2 * 3;
+const VM_REPORT_BLOCK_LENGTH: usize = 4 + 4 + 2 * 4 + 10 + 2 * 3;
 
 /// VoIPMetricsReportBlock encodes a VoIP Metrics Report Block as described
 /// in RFC 3611, section 4.7.
@@ -79,7 +79,7 @@ impl Packet for VoIPMetricsReportBlock {
     }
 
     fn raw_size(&self) -> usize {
-        XR_HEADER_LENGTH + VM_REPORT_BLOCK_LENGTH as usize
+        XR_HEADER_LENGTH + VM_REPORT_BLOCK_LENGTH
     }
 
     fn as_any(&self) -> &(dyn Any + Send + Sync) {
@@ -152,9 +152,8 @@ impl Unmarshal for VoIPMetricsReportBlock {
         }
 
         let xr_header = XRHeader::unmarshal(raw_packet)?;
-        let block_length = xr_header.block_length * 4;
-        if block_length != VM_REPORT_BLOCK_LENGTH || raw_packet.remaining() < block_length as usize
-        {
+        let block_length = xr_header.block_length as usize * 4;
+        if block_length != VM_REPORT_BLOCK_LENGTH || raw_packet.remaining() < block_length {
             return Err(error::Error::PacketTooShort.into());
         }
 
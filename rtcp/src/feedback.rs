@@ -0,0 +1,218 @@
+use bytes::{Buf, BufMut};
+use util::marshal::{Marshal, Unmarshal};
+
+use crate::error::Error;
+use crate::header::*;
+use crate::util::*;
+
+type Result<T> = std::result::Result<T, util::Error>;
+
+/// FEEDBACK_HEADER_LENGTH is the length of the common header of feedback messages,
+/// i.e. the RTCP header followed by the SSRC of the packet sender and of the media source.
+pub const FEEDBACK_HEADER_LENGTH: usize = HEADER_LENGTH + SSRC_LENGTH * 2;
+
+/// FeedbackMessage identifies the type of an RTCP feedback message
+/// by its packet type (PT) and feedback message type (FMT).
+/// IETF RFC 4585, Section 6.1
+/// https://tools.ietf.org/html/rfc4585#section-6.1
+pub trait FeedbackMessage {
+    /// PT of the message, either TransportSpecificFeedback or PayloadSpecificFeedback
+    const PACKET_TYPE: PacketType;
+    /// FMT of the message, carried in the count field of the header
+    const FORMAT: u8;
+}
+
+/// FeedbackHeader is the common header shared by all RTCP feedback messages,
+/// which is followed by the message specific feedback control information (FCI).
+///
+///  0                   1                   2                   3
+///  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |V=2|P|   FMT   |       PT      |          length               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |                  SSRC of packet sender                        |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |                  SSRC of media source                         |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// :            Feedback Control Information (FCI)                 :
+#[derive(Debug, PartialEq, Eq, Default, Clone)]
+pub struct FeedbackHeader {
+    pub header: Header,
+    /// SSRC of sender
+    pub sender_ssrc: u32,
+    /// SSRC of the media source
+    pub media_ssrc: u32,
+}
+
+impl FeedbackHeader {
+    /// new returns the header of a feedback message of type `F`,
+    /// whose unpadded size, including the header, is `raw_size`.
+    pub fn new<F: FeedbackMessage + ?Sized>(
+        sender_ssrc: u32,
+        media_ssrc: u32,
+        raw_size: usize,
+    ) -> Self {
+        let padding_size = get_padding_size(raw_size);
+        FeedbackHeader {
+            header: Header {
+                padding: padding_size != 0,
+                count: F::FORMAT,
+                packet_type: F::PACKET_TYPE,
+                length: (((raw_size + padding_size) / 4) - 1) as u16,
+            },
+            sender_ssrc,
+            media_ssrc,
+        }
+    }
+
+    /// fci_length returns the length of the FCI following this header,
+    /// as declared by the header's length field (i.e. including any padding).
+    pub fn fci_length(&self) -> usize {
+        (self.header.length as usize * 4).saturating_sub(SSRC_LENGTH * 2)
+    }
+
+    /// marshal_to encodes the header, returning the number of bytes written.
+    pub fn marshal_to(&self, mut buf: &mut [u8]) -> Result<usize> {
+        if buf.remaining_mut() < FEEDBACK_HEADER_LENGTH {
+            return Err(Error::BufferTooShort.into());
+        }
+
+        let n = self.header.marshal_to(buf)?;
+        buf = &mut buf[n..];
+
+        buf.put_u32(self.sender_ssrc);
+        buf.put_u32(self.media_ssrc);
+
+        Ok(FEEDBACK_HEADER_LENGTH)
+    }
+
+    /// unmarshal decodes the header of a feedback message of type `F`,
+    /// leaving the FCI in `raw_packet`.
+    ///
+    /// Fails if the packet is shorter than the length declared by its header,
+    /// or if its PT and FMT don't match those of `F`.
+    pub fn unmarshal<F, B>(raw_packet: &mut B) -> Result<Self>
+    where
+        F: FeedbackMessage + ?Sized,
+        B: Buf,
+    {
+        let raw_packet_len = raw_packet.remaining();
+        if raw_packet_len < FEEDBACK_HEADER_LENGTH {
+            return Err(Error::PacketTooShort.into());
+        }
+
+        let header = Header::unmarshal(raw_packet)?;
+
+        header.check_packet_size(raw_packet_len)?;
+        if (header.length as usize * 4) < SSRC_LENGTH * 2 {
+            return Err(Error::PacketTooShort.into());
+        }
+
+        if header.packet_type != F::PACKET_TYPE || header.count != F::FORMAT {
+            return Err(Error::WrongType.into());
+        }
+
+        let sender_ssrc = raw_packet.get_u32();
+        let media_ssrc = raw_packet.get_u32();
+
+        Ok(FeedbackHeader {
+            header,
+            sender_ssrc,
+            media_ssrc,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bytes::Bytes;
+
+    use super::*;
+    use crate::packet::Packet;
+    use crate::payload_feedbacks::full_intra_request::*;
+    use crate::payload_feedbacks::picture_loss_indication::*;
+    use crate::payload_feedbacks::receiver_estimated_maximum_bitrate::*;
+    use crate::payload_feedbacks::slice_loss_indication::*;
+    use crate::transport_feedbacks::rapid_resynchronization_request::*;
+
+    fn pt_and_fmt<F: FeedbackMessage>() -> (PacketType, u8) {
+        (F::PACKET_TYPE, F::FORMAT)
+    }
+
+    #[test]
+    fn test_feedback_message_pt_and_fmt() {
+        type PtAndFmt = (PacketType, u8);
+
+        let tests: Vec<(&str, PtAndFmt, Box<dyn Packet + Send + Sync>, PtAndFmt)> = vec![
+            (
+                "PLI",
+                pt_and_fmt::<PictureLossIndication>(),
+                Box::<PictureLossIndication>::default(),
+                (PacketType::PayloadSpecificFeedback, FORMAT_PLI),
+            ),
+            (
+                "SLI",
+                pt_and_fmt::<SliceLossIndication>(),
+                Box::<SliceLossIndication>::default(),
+                (PacketType::PayloadSpecificFeedback, FORMAT_SLI),
+            ),
+            (
+                "FIR",
+                pt_and_fmt::<FullIntraRequest>(),
+                Box::<FullIntraRequest>::default(),
+                (PacketType::PayloadSpecificFeedback, FORMAT_FIR),
+            ),
+            (
+                "REMB",
+                pt_and_fmt::<ReceiverEstimatedMaximumBitrate>(),
+                Box::<ReceiverEstimatedMaximumBitrate>::default(),
+                (PacketType::PayloadSpecificFeedback, FORMAT_REMB),
+            ),
+            (
+                "RRR",
+                pt_and_fmt::<RapidResynchronizationRequest>(),
+                Box::<RapidResynchronizationRequest>::default(),
+                (PacketType::TransportSpecificFeedback, FORMAT_RRR),
+            ),
+        ];
+
+        for (name, actual, packet, expected) in tests {
+            assert_eq!(actual, expected, "{name}: PT/FMT mismatch");
+
+            let h = packet.header();
+            assert_eq!(
+                (h.packet_type, h.count),
+                expected,
+                "{name}: header PT/FMT mismatch"
+            );
+        }
+    }
+
+    #[test]
+    fn test_feedback_header_roundtrip() -> Result<()> {
+        let fh = FeedbackHeader::new::<FullIntraRequest>(0x902f9e2e, 0x1234, 22);
+        assert!(fh.header.padding);
+        assert_eq!(fh.header.length, 5);
+        assert_eq!(fh.fci_length(), 12);
+
+        let mut buf = vec![0u8; 24];
+        assert_eq!(fh.marshal_to(&mut buf)?, FEEDBACK_HEADER_LENGTH);
+
+        let raw = Bytes::from(buf);
+
+        let mut data = raw.clone();
+        let decoded = FeedbackHeader::unmarshal::<FullIntraRequest, _>(&mut data)?;
+        assert_eq!(decoded, fh);
+        assert_eq!(data.remaining(), decoded.fci_length());
+
+        let mut data = raw.clone();
+        let result = FeedbackHeader::unmarshal::<PictureLossIndication, _>(&mut data);
+        assert_eq!(Error::WrongType, result.unwrap_err());
+
+        let mut data = raw.slice(..16);
+        let result = FeedbackHeader::unmarshal::<FullIntraRequest, _>(&mut data);
+        assert_eq!(Error::PacketTooShort, result.unwrap_err());
+
+        Ok(())
+    }
+}
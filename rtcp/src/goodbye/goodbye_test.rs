@@ -6,7 +6,7 @@ fn test_goodbye_unmarshal() {
         (
             "valid",
             Bytes::from_static(&[
-                0x81, 0xcb, 0x00, 0x0c, // v=2, p=0, count=1, BYE, len=12
+                0x81, 0xcb, 0x00, 0x02, // v=2, p=0, count=1, BYE, len=2
                 0x90, 0x2f, 0x9e, 0x2e, // ssrc=0x902f9e2e
                 0x03, 0x46, 0x4f, 0x4f, // len=3, text=FOO
             ]),
@@ -19,7 +19,7 @@ fn test_goodbye_unmarshal() {
         (
             "invalid octet count",
             Bytes::from_static(&[
-                0x81, 0xcb, 0x00, 0x0c, // v=2, p=0, count=1, BYE, len=12
+                0x81, 0xcb, 0x00, 0x02, // v=2, p=0, count=1, BYE, len=2
                 0x90, 0x2f, 0x9e, 0x2e, // ssrc=0x902f9e2e
                 0x04, 0x46, 0x4f, 0x4f, // len=4, text=FOO
             ]),
@@ -32,7 +32,7 @@ fn test_goodbye_unmarshal() {
         (
             "wrong type",
             Bytes::from_static(&[
-                0x81, 0xca, 0x00, 0x0c, // v=2, p=0, count=1, SDES, len=12
+                0x81, 0xca, 0x00, 0x02, // v=2, p=0, count=1, SDES, len=2
                 0x90, 0x2f, 0x9e, 0x2e, // ssrc=0x902f9e2e
                 0x03, 0x46, 0x4f, 0x4f, // len=3, text=FOO
             ]),
@@ -45,7 +45,7 @@ fn test_goodbye_unmarshal() {
         (
             "short reason",
             Bytes::from_static(&[
-                0x81, 0xcb, 0x00, 0x0c, // v=2, p=0, count=1, BYE, len=12
+                0x81, 0xcb, 0x00, 0x02, // v=2, p=0, count=1, BYE, len=2
                 0x90, 0x2f, 0x9e, 0x2e, // ssrc=0x902f9e2e
                 0x01, 0x46, 0x00, 0x00, // len=3, text=F + padding
             ]),
@@ -83,8 +83,8 @@ fn test_goodbye_unmarshal() {
         (
             "empty packet",
             Bytes::from_static(&[
-                // v=2, p=0, count=0, BYE, len=4
-                0x80, 0xcb, 0x00, 0x04,
+                // v=2, p=0, count=0, BYE, len=0
+                0x80, 0xcb, 0x00, 0x00,
             ]),
             Goodbye {
                 sources: vec![],
@@ -223,3 +223,85 @@ fn test_goodbye_round_trip() {
         }
     }
 }
+
+#[test]
+fn test_goodbye_new() -> Result<()> {
+    let bye = Goodbye::new(
+        vec![0x902f9e2e, 0x01020304],
+        Some("camera malfunction".to_owned()),
+    )?;
+    assert_eq!(bye.reason(), Some("camera malfunction"));
+    assert_eq!(bye.to_string(), "BYE 902f9e2e, 1020304: camera malfunction");
+
+    let mut data = bye.marshal()?;
+    let decoded = Goodbye::unmarshal(&mut data)?;
+    assert_eq!(decoded, bye);
+    assert_eq!(decoded.reason(), Some("camera malfunction"));
+
+    let bye = Goodbye::new(vec![0x902f9e2e], None)?;
+    assert_eq!(bye.reason(), None);
+    assert_eq!(bye.to_string(), "BYE 902f9e2e");
+
+    let result = Goodbye::new(vec![0; COUNT_MAX + 1], None);
+    assert_eq!(Error::TooManySources, result.unwrap_err());
+
+    let result = Goodbye::new(vec![], Some("x".repeat(SDES_MAX_OCTET_COUNT + 1)));
+    assert_eq!(Error::ReasonTooLong, result.unwrap_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_goodbye_destination_ssrcs() -> Result<()> {
+    let bye = Goodbye::new(vec![0x01020304, 0x05060708, 0x090a0b0c], None)?;
+
+    assert_eq!(bye.destination_ssrcs(), &bye.destination_ssrc()[..]);
+    assert_eq!(
+        bye.destination_ssrcs(),
+        &[0x01020304, 0x05060708, 0x090a0b0c]
+    );
+
+    // Appending reuses the buffer, rather than replacing its contents:
+    let mut out = vec![0x1234];
+    bye.destination_ssrc_into(&mut out);
+    assert_eq!(out, vec![0x1234, 0x01020304, 0x05060708, 0x090a0b0c]);
+
+    Ok(())
+}
+
+#[test]
+fn test_goodbye_set_reason() -> Result<()> {
+    let mut bye = Goodbye::new(vec![0x902f9e2e], Some("camera malfunction".to_owned()))?;
+
+    // The strict setter rejects overly long reasons, keeping the current one:
+    let result = bye.set_reason(&"x".repeat(SDES_MAX_OCTET_COUNT + 1));
+    assert_eq!(Error::ReasonTooLong, result.unwrap_err());
+    assert_eq!(bye.reason(), Some("camera malfunction"));
+
+    bye.set_reason(&"x".repeat(SDES_MAX_OCTET_COUNT))?;
+    assert_eq!(bye.reason.len(), SDES_MAX_OCTET_COUNT);
+
+    bye.set_reason("")?;
+    assert_eq!(bye.reason(), None);
+
+    // 100 three-byte characters, i.e. 300 bytes, with no character ending at byte 255:
+    let reason = "€".repeat(100);
+    bye.set_reason_truncating(&reason);
+    assert_eq!(bye.reason(), Some("€".repeat(85).as_str()));
+    assert_eq!(bye.reason.len(), 255);
+
+    let reason = format!("a{}", "€".repeat(100));
+    bye.set_reason_truncating(&reason);
+    let truncated = bye.reason().expect("Expected valid UTF-8.");
+    assert_eq!(truncated, format!("a{}", "€".repeat(84)));
+    assert_eq!(truncated.len(), 253);
+
+    bye.set_reason_truncating("short");
+    assert_eq!(bye.reason(), Some("short"));
+
+    let mut data = bye.marshal()?;
+    let decoded = Goodbye::unmarshal(&mut data)?;
+    assert_eq!(decoded.reason(), Some("short"));
+
+    Ok(())
+}
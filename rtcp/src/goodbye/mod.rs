@@ -7,6 +7,7 @@ use std::fmt;
 use bytes::{Buf, BufMut, Bytes};
 use util::marshal::{Marshal, MarshalSize, Unmarshal};
 
+use crate::csrc::*;
 use crate::error::Error;
 use crate::header::*;
 use crate::packet::*;
@@ -25,13 +26,76 @@ pub struct Goodbye {
 
 impl fmt::Display for Goodbye {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut out = "Goodbye:\n\tSources:\n".to_string();
-        for s in &self.sources {
-            out += format!("\t{}\n", *s).as_str();
+        write!(f, "BYE")?;
+        for (i, source) in self.sources.iter().enumerate() {
+            let separator = if i == 0 { " " } else { ", " };
+            write!(f, "{separator}{source:x}")?;
+        }
+        match self.reason() {
+            Some(reason) => write!(f, ": {reason}"),
+            None if !self.reason.is_empty() => write!(f, ": {:?}", self.reason),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Goodbye {
+    /// new creates a Goodbye packet for the given sources and optional reason for leaving.
+    ///
+    /// Fails if there are more than 31 sources, or if the reason exceeds 255 bytes.
+    pub fn new(sources: Vec<u32>, reason: Option<String>) -> Result<Self> {
+        if sources.len() > COUNT_MAX {
+            return Err(Error::TooManySources.into());
+        }
+
+        let reason = reason.map(Bytes::from).unwrap_or_default();
+        if reason.len() > SDES_MAX_OCTET_COUNT {
+            return Err(Error::ReasonTooLong.into());
+        }
+
+        Ok(Goodbye { sources, reason })
+    }
+
+    /// destination_ssrcs returns the SSRC values that this packet refers to,
+    /// i.e. the same values as `destination_ssrc`, without allocating.
+    pub fn destination_ssrcs(&self) -> &[u32] {
+        &self.sources
+    }
+
+    /// reason returns the reason for leaving,
+    /// or `None` if there is none, or if it is not valid UTF-8.
+    pub fn reason(&self) -> Option<&str> {
+        if self.reason.is_empty() {
+            return None;
+        }
+        std::str::from_utf8(&self.reason).ok()
+    }
+
+    /// set_reason sets the reason for leaving, or clears it if `reason` is empty.
+    ///
+    /// Fails if the reason exceeds 255 bytes, leaving the current reason unchanged.
+    /// Use `set_reason_truncating` to shorten overly long reasons instead.
+    pub fn set_reason(&mut self, reason: &str) -> Result<()> {
+        if reason.len() > SDES_MAX_OCTET_COUNT {
+            return Err(Error::ReasonTooLong.into());
         }
-        out += format!("\tReason: {:?}\n", self.reason).as_str();
 
-        write!(f, "{out}")
+        self.reason = Bytes::copy_from_slice(reason.as_bytes());
+        Ok(())
+    }
+
+    /// set_reason_truncating sets the reason for leaving, or clears it if `reason` is empty.
+    ///
+    /// Reasons exceeding 255 bytes get truncated to the longest prefix of at most 255 bytes
+    /// that ends on a character boundary, keeping the reason valid UTF-8.
+    /// Use `set_reason` to reject overly long reasons instead.
+    pub fn set_reason_truncating(&mut self, reason: &str) {
+        let mut len = reason.len().min(SDES_MAX_OCTET_COUNT);
+        while !reason.is_char_boundary(len) {
+            len -= 1;
+        }
+
+        self.reason = Bytes::copy_from_slice(&reason.as_bytes()[..len]);
     }
 }
 
@@ -51,8 +115,12 @@ impl Packet for Goodbye {
         self.sources.to_vec()
     }
 
+    fn destination_ssrc_into(&self, out: &mut Vec<u32>) {
+        out.extend_from_slice(&self.sources);
+    }
+
     fn raw_size(&self) -> usize {
-        let srcs_length = self.sources.len() * SSRC_LENGTH;
+        let srcs_length = csrc_list_size(self.sources.len());
         let reason_length = self.reason.len() + 1;
 
         HEADER_LENGTH + srcs_length + reason_length
@@ -115,9 +183,8 @@ impl Marshal for Goodbye {
         let n = h.marshal_to(buf)?;
         buf = &mut buf[n..];
 
-        for source in &self.sources {
-            buf.put_u32(*source);
-        }
+        let n = marshal_csrc_list(buf, &self.sources)?;
+        buf = &mut buf[n..];
 
         buf.put_u8(self.reason.len() as u8);
         if !self.reason.is_empty() {
@@ -154,6 +221,8 @@ impl Unmarshal for Goodbye {
         let raw_packet_len = raw_packet.remaining();
 
         let header = Header::unmarshal(raw_packet)?;
+
+        header.check_packet_size(raw_packet_len)?;
         if header.packet_type != PacketType::Goodbye {
             return Err(Error::WrongType.into());
         }
@@ -168,10 +237,7 @@ impl Unmarshal for Goodbye {
             return Err(Error::PacketTooShort.into());
         }
 
-        let mut sources = Vec::with_capacity(header.count as usize);
-        for _ in 0..header.count {
-            sources.push(raw_packet.get_u32());
-        }
+        let sources = unmarshal_csrc_list(raw_packet, header.count as usize)?;
 
         let reason = if reason_offset < raw_packet_len {
             let reason_len = raw_packet.get_u8() as usize;
@@ -35,6 +35,12 @@ pub const FORMAT_REMB: u8 = 15;
 /// Transport and Payload specific feedback messages overload the count field to act as a message type. those are listed here.
 /// https://tools.ietf.org/html/draft-holmer-rmcat-transport-wide-cc-extensions-01#page-5
 pub const FORMAT_TCC: u8 = 15;
+/// Transport and Payload specific feedback messages overload the count field to act as a message type. those are listed here.
+/// https://tools.ietf.org/html/rfc6679#section-6.1
+pub const FORMAT_ECN: u8 = 8;
+/// Transport and Payload specific feedback messages overload the count field to act as a message type. those are listed here.
+/// https://tools.ietf.org/html/rfc6285#section-7
+pub const FORMAT_RAMS: u8 = 6;
 
 impl std::fmt::Display for PacketType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -98,6 +104,44 @@ pub struct Header {
     pub length: u16,
 }
 
+impl Header {
+    /// Returns a new header, whose version gets fixed to `RTP_VERSION` upon marshaling.
+    ///
+    /// Fails if `count` does not fit into the 5 bits of the count field.
+    pub fn new(
+        padding: bool,
+        count: u8,
+        packet_type: PacketType,
+        length: u16,
+    ) -> Result<Self, Error> {
+        if count as usize > COUNT_MAX {
+            return Err(Error::InvalidHeader);
+        }
+
+        Ok(Header {
+            padding,
+            count,
+            packet_type,
+            length,
+        })
+    }
+
+    /// Returns the size in bytes of the packet as declared by `length`,
+    /// including the header and any padding.
+    pub fn packet_size(&self) -> usize {
+        (self.length as usize + 1) * 4
+    }
+
+    /// Returns an error if a buffer of `raw_packet_len` bytes (including the header)
+    /// is too short to hold the packet as declared by `length`.
+    pub(crate) fn check_packet_size(&self, raw_packet_len: usize) -> Result<(), Error> {
+        if self.packet_size() > raw_packet_len {
+            return Err(Error::PacketTooShort);
+        }
+        Ok(())
+    }
+}
+
 /// Marshal encodes the Header in binary
 impl MarshalSize for Header {
     fn marshal_size(&self) -> usize {
@@ -312,4 +356,21 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_header_new() -> Result<(), util::Error> {
+        let result = Header::new(false, 32, PacketType::ReceiverReport, 7);
+        assert_eq!(result, Err(Error::InvalidHeader));
+
+        let header = Header::new(true, 31, PacketType::SenderReport, 4)?;
+        assert_eq!(
+            header.marshal()?,
+            Bytes::from_static(&[
+                // v=2, p=1, count=31, SR, len=4
+                0xbf, 0xc8, 0x00, 0x04,
+            ])
+        );
+
+        Ok(())
+    }
 }
@@ -41,9 +41,12 @@
 //!     // ...
 //!```
 
+pub mod cached_packet;
 pub mod compound_packet;
+pub mod csrc;
 mod error;
 pub mod extended_report;
+pub mod feedback;
 pub mod goodbye;
 pub mod header;
 pub mod packet;
@@ -55,5 +58,6 @@ pub mod sender_report;
 pub mod source_description;
 pub mod transport_feedbacks;
 mod util;
+pub mod visitor;
 
 pub use error::Error;
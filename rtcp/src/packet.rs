@@ -1,9 +1,11 @@
 use std::any::Any;
 use std::fmt;
 
-use bytes::{Buf, BufMut, Bytes, BytesMut};
+use bytes::{Buf, Bytes, BytesMut};
 use util::marshal::{Marshal, Unmarshal};
 
+use crate::cached_packet::CachedPacket;
+use crate::compound_packet::CompoundPacket;
 use crate::error::{Error, Result};
 use crate::extended_report::ExtendedReport;
 use crate::goodbye::*;
@@ -16,6 +18,8 @@ use crate::raw_packet::*;
 use crate::receiver_report::*;
 use crate::sender_report::*;
 use crate::source_description::*;
+use crate::transport_feedbacks::ecn_feedback::*;
+use crate::transport_feedbacks::rapid_acquisition::*;
 use crate::transport_feedbacks::rapid_resynchronization_request::*;
 use crate::transport_feedbacks::transport_layer_cc::*;
 use crate::transport_feedbacks::transport_layer_nack::*;
@@ -25,6 +29,12 @@ use crate::transport_feedbacks::transport_layer_nack::*;
 pub trait Packet: Marshal + Unmarshal + fmt::Display + fmt::Debug {
     fn header(&self) -> Header;
     fn destination_ssrc(&self) -> Vec<u32>;
+    /// destination_ssrc_into appends the SSRC values that this packet refers to to `out`,
+    /// which allows callers on hot paths to reuse a buffer across packets,
+    /// rather than allocating one per call to `destination_ssrc`.
+    fn destination_ssrc_into(&self, out: &mut Vec<u32>) {
+        out.extend(self.destination_ssrc());
+    }
     fn raw_size(&self) -> usize;
     fn as_any(&self) -> &(dyn Any + Send + Sync);
     fn equal(&self, other: &(dyn Packet + Send + Sync)) -> bool;
@@ -45,14 +55,79 @@ impl Clone for Box<dyn Packet + Send + Sync> {
 
 /// marshal takes an array of Packets and serializes them to a single buffer
 pub fn marshal(packets: &[Box<dyn Packet + Send + Sync>]) -> Result<Bytes> {
-    let mut out = BytesMut::new();
+    marshal_all(packets)
+}
+
+/// marshal_all serializes the given packets in order into a single buffer (e.g. a compound packet),
+/// which is the inverse of `unmarshal`.
+///
+/// The buffer is allocated once up front, rather than marshaling each packet into its own.
+/// Like `Marshal::marshal`, it fails if a packet's output size differs from its `marshal_size()`.
+pub fn marshal_all(packets: &[Box<dyn Packet + Send + Sync>]) -> Result<Bytes> {
+    let size = packets.iter().map(|p| p.marshal_size()).sum();
+    let mut out = BytesMut::with_capacity(size);
+    out.resize(size, 0);
+
+    let mut offset = 0;
     for p in packets {
-        let data = p.marshal()?;
-        out.put(data);
+        let l = p.marshal_size();
+        let n = p.marshal_to(&mut out[offset..offset + l])?;
+        if n != l {
+            return Err(
+                util::Error::Other(format!("marshal_to output size {n}, but expect {l}")).into(),
+            );
+        }
+        offset += n;
     }
+
     Ok(out.freeze())
 }
 
+/// Implements `TryFrom<&T>` for `Bytes` and `Vec<u8>` for each of the given packet types,
+/// delegating to `Marshal::marshal`.
+macro_rules! impl_try_from_packet {
+    ($($packet:ty),* $(,)?) => {
+        $(
+            impl TryFrom<&$packet> for Bytes {
+                type Error = util::Error;
+
+                fn try_from(packet: &$packet) -> std::result::Result<Self, Self::Error> {
+                    packet.marshal()
+                }
+            }
+
+            impl TryFrom<&$packet> for Vec<u8> {
+                type Error = util::Error;
+
+                fn try_from(packet: &$packet) -> std::result::Result<Self, Self::Error> {
+                    Ok(packet.marshal()?.to_vec())
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_packet!(
+    CachedPacket,
+    CompoundPacket,
+    EcnFeedback,
+    ExtendedReport,
+    FullIntraRequest,
+    Goodbye,
+    PictureLossIndication,
+    RapidAcquisitionInformation,
+    RapidAcquisitionRequest,
+    RapidResynchronizationRequest,
+    RawPacket,
+    ReceiverEstimatedMaximumBitrate,
+    ReceiverReport,
+    SenderReport,
+    SliceLossIndication,
+    SourceDescription,
+    TransportLayerCc,
+    TransportLayerNack,
+);
+
 /// Unmarshal takes an entire udp datagram (which may consist of multiple RTCP packets) and
 /// returns the unmarshaled packets it contains.
 ///
@@ -79,6 +154,16 @@ where
     }
 }
 
+/// Unmarshal takes an entire udp datagram (which may consist of multiple RTCP packets) from a plain slice
+/// and returns the unmarshaled packets it contains.
+///
+/// Malformed or truncated input of any kind is reported as an error, rather than causing a panic,
+/// which makes this a suitable entry point for fuzzing the parser.
+pub fn unmarshal_bytes(raw: &[u8]) -> Result<Vec<Box<dyn Packet + Send + Sync>>> {
+    let mut raw = raw;
+    unmarshal(&mut raw)
+}
+
 /// unmarshaller is a factory which pulls the first RTCP packet from a bytestream,
 /// and returns it's parsed representation, and the amount of data that was processed.
 pub(crate) fn unmarshaller<B>(raw_data: &mut B) -> Result<Box<dyn Packet + Send + Sync>>
@@ -104,6 +189,19 @@ where
             FORMAT_TLN => Box::new(TransportLayerNack::unmarshal(&mut in_packet)?),
             FORMAT_RRR => Box::new(RapidResynchronizationRequest::unmarshal(&mut in_packet)?),
             FORMAT_TCC => Box::new(TransportLayerCc::unmarshal(&mut in_packet)?),
+            FORMAT_ECN => Box::new(EcnFeedback::unmarshal(&mut in_packet)?),
+            FORMAT_RAMS => {
+                let mut in_packet = in_packet.copy_to_bytes(in_packet.remaining());
+                match peek_rams_sfmt(&in_packet) {
+                    Some(SFMT_RAMS_REQUEST) => {
+                        Box::new(RapidAcquisitionRequest::unmarshal(&mut in_packet)?)
+                    }
+                    Some(SFMT_RAMS_INFORMATION) => {
+                        Box::new(RapidAcquisitionInformation::unmarshal(&mut in_packet)?)
+                    }
+                    _ => Box::new(RawPacket::unmarshal(&mut in_packet)?),
+                }
+            }
             _ => Box::new(RawPacket::unmarshal(&mut in_packet)?),
         },
         PacketType::PayloadSpecificFeedback => match h.count {
@@ -122,7 +220,9 @@ where
 
 #[cfg(test)]
 mod test {
-    use bytes::Bytes;
+    use bytes::{BufMut, Bytes};
+
+    use util::marshal::MarshalSize;
 
     use super::*;
     use crate::reception_report::*;
@@ -273,4 +373,567 @@ mod test {
 
         Ok(())
     }
+
+    /// Tiny xorshift generator so the randomized tests below stay
+    /// deterministic and free of extra dev-dependencies.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: usize) -> usize {
+            (self.next() % n as u64) as usize
+        }
+
+        fn bytes(&mut self, max_len: usize) -> Bytes {
+            let len = self.below(max_len + 1);
+            (0..len).map(|_| self.next() as u8).collect()
+        }
+    }
+
+    fn random_reception_reports(rng: &mut Rng) -> Vec<ReceptionReport> {
+        (0..rng.below(COUNT_MAX + 1))
+            .map(|_| ReceptionReport {
+                ssrc: rng.next() as u32,
+                fraction_lost: rng.next() as u8,
+                total_lost: rng.next() as u32 & 0xFFFFFF,
+                last_sequence_number: rng.next() as u32,
+                jitter: rng.next() as u32,
+                last_sender_report: rng.next() as u32,
+                delay: rng.next() as u32,
+            })
+            .collect()
+    }
+
+    fn random_packets(rng: &mut Rng) -> Vec<Box<dyn Packet + Send + Sync>> {
+        use crate::extended_report::*;
+
+        let sdes_types = [
+            SdesType::SdesCname,
+            SdesType::SdesName,
+            SdesType::SdesEmail,
+            SdesType::SdesPhone,
+            SdesType::SdesLocation,
+            SdesType::SdesTool,
+            SdesType::SdesNote,
+            SdesType::SdesUnknown(42),
+        ];
+
+        let small_deltas = rng.below(16);
+        let large_deltas = rng.below(16);
+        let mut recv_deltas: Vec<RecvDelta> = (0..small_deltas)
+            .map(|_| RecvDelta {
+                type_tcc_packet: SymbolTypeTcc::PacketReceivedSmallDelta,
+                delta: rng.below(256) as i64 * 250,
+            })
+            .collect();
+        recv_deltas.extend((0..large_deltas).map(|_| RecvDelta {
+            type_tcc_packet: SymbolTypeTcc::PacketReceivedLargeDelta,
+            delta: (rng.below(65536) as i64 - 32768) * 250,
+        }));
+
+        vec![
+            Box::new(SenderReport {
+                ssrc: rng.next() as u32,
+                ntp_time: rng.next(),
+                rtp_time: rng.next() as u32,
+                packet_count: rng.next() as u32,
+                octet_count: rng.next() as u32,
+                reports: random_reception_reports(rng),
+                profile_extensions: rng.bytes(16),
+            }),
+            Box::new(ReceiverReport {
+                ssrc: rng.next() as u32,
+                reports: random_reception_reports(rng),
+                profile_extensions: rng.bytes(16),
+            }),
+            Box::new(SourceDescription {
+                chunks: (0..rng.below(COUNT_MAX + 1))
+                    .map(|_| SourceDescriptionChunk {
+                        source: rng.next() as u32,
+                        items: (0..rng.below(5))
+                            .map(|_| SourceDescriptionItem {
+                                sdes_type: sdes_types[rng.below(sdes_types.len())],
+                                text: rng.bytes(SDES_MAX_OCTET_COUNT),
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+            }),
+            Box::new(Goodbye {
+                sources: (0..rng.below(COUNT_MAX + 1))
+                    .map(|_| rng.next() as u32)
+                    .collect(),
+                reason: rng.bytes(255),
+            }),
+            Box::new(PictureLossIndication {
+                sender_ssrc: rng.next() as u32,
+                media_ssrc: rng.next() as u32,
+            }),
+            Box::new(SliceLossIndication {
+                sender_ssrc: rng.next() as u32,
+                media_ssrc: rng.next() as u32,
+                sli_entries: (0..rng.below(8))
+                    .map(|_| SliEntry {
+                        first: rng.next() as u16 & 0x1FFF,
+                        number: rng.next() as u16 & 0x1FFF,
+                        picture: rng.next() as u8 & 0x3F,
+                    })
+                    .collect(),
+            }),
+            Box::new(FullIntraRequest {
+                sender_ssrc: rng.next() as u32,
+                media_ssrc: rng.next() as u32,
+                fir: (0..rng.below(8))
+                    .map(|_| FirEntry {
+                        ssrc: rng.next() as u32,
+                        sequence_number: rng.next() as u8,
+                    })
+                    .collect(),
+            }),
+            Box::new(ReceiverEstimatedMaximumBitrate {
+                sender_ssrc: rng.next() as u32,
+                bitrate: (rng.next() % 1_000_000_000) as f32,
+                ssrcs: (0..rng.below(8)).map(|_| rng.next() as u32).collect(),
+            }),
+            Box::new(RapidResynchronizationRequest {
+                sender_ssrc: rng.next() as u32,
+                media_ssrc: rng.next() as u32,
+            }),
+            Box::new(RapidAcquisitionRequest {
+                sender_ssrc: rng.next() as u32,
+                media_ssrc: rng.next() as u32,
+                tlvs: (0..rng.below(4))
+                    .map(|_| RamsTlv {
+                        tlv_type: rng.next() as u8,
+                        value: rng.bytes(16),
+                    })
+                    .collect(),
+            }),
+            Box::new(EcnFeedback {
+                sender_ssrc: rng.next() as u32,
+                media_ssrc: rng.next() as u32,
+                extended_highest_seq: rng.next() as u32,
+                ect0_counter: rng.next() as u32,
+                ect1_counter: rng.next() as u32,
+                ecn_ce_counter: rng.next() as u16,
+                not_ect_counter: rng.next() as u16,
+                lost_packets: rng.next() as u16,
+                duplication_counter: rng.next() as u16,
+            }),
+            Box::new(TransportLayerNack {
+                sender_ssrc: rng.next() as u32,
+                media_ssrc: rng.next() as u32,
+                nacks: (0..rng.below(8))
+                    .map(|_| NackPair {
+                        packet_id: rng.next() as u16,
+                        lost_packets: rng.next() as u16,
+                    })
+                    .collect(),
+            }),
+            Box::new(TransportLayerCc {
+                sender_ssrc: rng.next() as u32,
+                media_ssrc: rng.next() as u32,
+                base_sequence_number: rng.next() as u16,
+                packet_status_count: (small_deltas + large_deltas) as u16,
+                reference_time: rng.next() as u32 & 0xFFFFFF,
+                fb_pkt_count: rng.next() as u8,
+                packet_chunks: vec![
+                    PacketStatusChunk::RunLengthChunk(RunLengthChunk {
+                        type_tcc: StatusChunkTypeTcc::RunLengthChunk,
+                        packet_status_symbol: SymbolTypeTcc::PacketReceivedSmallDelta,
+                        run_length: small_deltas as u16,
+                    }),
+                    PacketStatusChunk::RunLengthChunk(RunLengthChunk {
+                        type_tcc: StatusChunkTypeTcc::RunLengthChunk,
+                        packet_status_symbol: SymbolTypeTcc::PacketReceivedLargeDelta,
+                        run_length: large_deltas as u16,
+                    }),
+                ],
+                recv_deltas,
+            }),
+            Box::new(ExtendedReport {
+                sender_ssrc: rng.next() as u32,
+                reports: vec![
+                    Box::new(RLEReportBlock {
+                        is_loss_rle: rng.below(2) == 0,
+                        ssrc: rng.next() as u32,
+                        chunks: (0..rng.below(4) * 2).map(|_| Chunk(0)).collect(),
+                        ..Default::default()
+                    }),
+                    Box::new(PacketReceiptTimesReportBlock {
+                        ssrc: rng.next() as u32,
+                        receipt_time: (0..rng.below(8)).map(|_| rng.next() as u32).collect(),
+                        ..Default::default()
+                    }),
+                    Box::new(ReceiverReferenceTimeReportBlock {
+                        ntp_timestamp: rng.next(),
+                    }),
+                    Box::new(DLRRReportBlock {
+                        reports: (0..rng.below(4))
+                            .map(|_| DLRRReport {
+                                ssrc: rng.next() as u32,
+                                last_rr: rng.next() as u32,
+                                dlrr: rng.next() as u32,
+                            })
+                            .collect(),
+                    }),
+                    Box::new(StatisticsSummaryReportBlock {
+                        ssrc: rng.next() as u32,
+                        ..Default::default()
+                    }),
+                    Box::new(VoIPMetricsReportBlock {
+                        ssrc: rng.next() as u32,
+                        ..Default::default()
+                    }),
+                ],
+            }),
+        ]
+    }
+
+    #[test]
+    fn test_packet_try_into_bytes() -> Result<()> {
+        let sr = SenderReport {
+            ssrc: 0x902f9e2e,
+            ntp_time: 0xda8bd1fcdddda05a,
+            rtp_time: 0xaaf4edd5,
+            packet_count: 1,
+            octet_count: 2,
+            ..Default::default()
+        };
+
+        let bytes: Bytes = (&sr).try_into()?;
+        assert_eq!(bytes, sr.marshal()?);
+        let vec: Vec<u8> = (&sr).try_into()?;
+        assert_eq!(vec, bytes);
+
+        let pli = PictureLossIndication {
+            sender_ssrc: 0x902f9e2e,
+            media_ssrc: 0x1234,
+        };
+
+        let bytes = Bytes::try_from(&pli)?;
+        assert_eq!(bytes, pli.marshal()?);
+        let vec = Vec::<u8>::try_from(&pli)?;
+        assert_eq!(vec, bytes);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_packet_marshal_all() -> Result<()> {
+        let packets: Vec<Box<dyn Packet + Send + Sync>> = vec![
+            Box::new(SenderReport {
+                ssrc: 0x902f9e2e,
+                ntp_time: 0xda8bd1fcdddda05a,
+                rtp_time: 0xaaf4edd5,
+                packet_count: 1,
+                octet_count: 2,
+                ..Default::default()
+            }),
+            Box::new(SourceDescription {
+                chunks: vec![SourceDescriptionChunk {
+                    source: 0x902f9e2e,
+                    items: vec![SourceDescriptionItem {
+                        sdes_type: SdesType::SdesCname,
+                        text: Bytes::from_static(b"{9c00eb92-1afb-9d49-a47d-91f64eee69f5}"),
+                    }],
+                }],
+            }),
+            Box::new(Goodbye {
+                sources: vec![0x902f9e2e],
+                reason: Bytes::from_static(b"FOO"),
+            }),
+        ];
+
+        let data = marshal_all(&packets)?;
+
+        let mut expected = BytesMut::new();
+        for p in &packets {
+            expected.put(p.marshal()?);
+        }
+        assert_eq!(data, expected.freeze());
+
+        let unmarshaled = unmarshal(&mut data.clone())?;
+        assert_eq!(unmarshaled, packets);
+
+        assert!(marshal_all(&[])?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_packet_marshal_all_size_mismatch() {
+        // A packet writing fewer bytes than its marshal size claims:
+        #[derive(Debug, Clone, PartialEq)]
+        struct ShortPacket;
+
+        impl fmt::Display for ShortPacket {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "ShortPacket")
+            }
+        }
+
+        impl Packet for ShortPacket {
+            fn header(&self) -> Header {
+                Header::default()
+            }
+            fn destination_ssrc(&self) -> Vec<u32> {
+                vec![]
+            }
+            fn raw_size(&self) -> usize {
+                HEADER_LENGTH * 2
+            }
+            fn as_any(&self) -> &(dyn Any + Send + Sync) {
+                self
+            }
+            fn equal(&self, other: &(dyn Packet + Send + Sync)) -> bool {
+                other.as_any().downcast_ref::<ShortPacket>() == Some(self)
+            }
+            fn cloned(&self) -> Box<dyn Packet + Send + Sync> {
+                Box::new(self.clone())
+            }
+        }
+
+        impl MarshalSize for ShortPacket {
+            fn marshal_size(&self) -> usize {
+                self.raw_size()
+            }
+        }
+
+        impl Marshal for ShortPacket {
+            fn marshal_to(&self, buf: &mut [u8]) -> std::result::Result<usize, util::Error> {
+                self.header().marshal_to(buf)
+            }
+        }
+
+        impl Unmarshal for ShortPacket {
+            fn unmarshal<B>(_raw_packet: &mut B) -> std::result::Result<Self, util::Error>
+            where
+                B: Buf,
+            {
+                Ok(ShortPacket)
+            }
+        }
+
+        let packets: Vec<Box<dyn Packet + Send + Sync>> = vec![Box::new(ShortPacket)];
+        assert_eq!(
+            marshal_all(&packets).unwrap_err(),
+            Error::Util(util::Error::Other(
+                "marshal_to output size 4, but expect 8".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_packet_try_into_bytes_error() {
+        let remb = ReceiverEstimatedMaximumBitrate {
+            bitrate: -1.0,
+            ..Default::default()
+        };
+
+        let result: std::result::Result<Bytes, util::Error> = (&remb).try_into();
+        assert_eq!(Error::InvalidBitrate, result.unwrap_err());
+    }
+
+    #[test]
+    fn test_packet_unmarshal_bytes_truncated() {
+        let sr = SenderReport {
+            ssrc: 0x902f9e2e,
+            ntp_time: 0xda8bd1fcdddda05a,
+            rtp_time: 0xaaf4edd5,
+            packet_count: 1,
+            octet_count: 2,
+            reports: vec![ReceptionReport {
+                ssrc: 0xbc5e9a40,
+                last_sequence_number: 0x46e1,
+                ..Default::default()
+            }],
+            profile_extensions: Bytes::from_static(&[0x1, 0x2, 0x3]),
+        };
+        let sdes = SourceDescription {
+            chunks: vec![SourceDescriptionChunk {
+                source: 0x902f9e2e,
+                items: vec![SourceDescriptionItem {
+                    sdes_type: SdesType::SdesCname,
+                    text: Bytes::from_static(b"{9c00eb92-1afb-9d49-a47d-91f64eee69f5}"),
+                }],
+            }],
+        };
+
+        for data in [sr.marshal().unwrap(), sdes.marshal().unwrap()] {
+            assert!(unmarshal_bytes(&data).is_ok());
+            for len in 0..data.len() {
+                assert!(
+                    unmarshal_bytes(&data[..len]).is_err(),
+                    "Unmarshal of {len} out of {} bytes should fail",
+                    data.len()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_packet_unmarshal_bytes_malformed() {
+        let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+
+        for _ in 0..50 {
+            for packet in random_packets(&mut rng) {
+                let data = packet.marshal().unwrap();
+                for len in 0..data.len() {
+                    let _ = unmarshal_bytes(&data[..len]);
+                }
+                for _ in 0..64 {
+                    let mut data = data.to_vec();
+                    let i = rng.below(data.len());
+                    data[i] = rng.next() as u8;
+                    let j = rng.below(data.len());
+                    data[j] = rng.next() as u8;
+                    // must either succeed or fail, but never panic:
+                    let _ = unmarshal_bytes(&data);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_packet_destination_ssrc_into_matches_destination_ssrc() {
+        let mut rng = Rng(0x8C2B_D1F0_3A65_4E97);
+
+        let mut out = vec![];
+        for _ in 0..50 {
+            let packets = random_packets(&mut rng);
+            for packet in &packets {
+                out.clear();
+                packet.destination_ssrc_into(&mut out);
+                assert_eq!(
+                    out,
+                    packet.destination_ssrc(),
+                    "destination SSRC mismatch for {packet:?}"
+                );
+            }
+
+            let compound = CompoundPacket(packets);
+            out.clear();
+            compound.destination_ssrc_into(&mut out);
+            assert_eq!(out, compound.destination_ssrc());
+        }
+    }
+
+    #[test]
+    fn test_packet_marshal_size_matches_marshal() -> Result<()> {
+        let mut rng = Rng(0x2545_F491_4F6C_DD1D);
+
+        for _ in 0..200 {
+            for packet in random_packets(&mut rng) {
+                let size = packet.marshal_size();
+                let data = packet.marshal()?;
+                assert_eq!(size, data.len(), "marshal_size mismatch for {packet:?}");
+
+                let h = packet.header();
+                assert_eq!(
+                    (h.length as usize + 1) * 4,
+                    size,
+                    "header length mismatch for {packet:?}"
+                );
+            }
+
+            // a compound packet has to start with a report followed by a CNAME
+            let mut packets = random_packets(&mut rng);
+            packets.insert(
+                1,
+                Box::new(SourceDescription {
+                    chunks: vec![SourceDescriptionChunk {
+                        source: rng.next() as u32,
+                        items: vec![SourceDescriptionItem {
+                            sdes_type: SdesType::SdesCname,
+                            text: rng.bytes(SDES_MAX_OCTET_COUNT),
+                        }],
+                    }],
+                }),
+            );
+            let compound = CompoundPacket(packets);
+            assert_eq!(compound.marshal_size(), compound.marshal()?.len());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_packet_header_matches_marshaled_header() -> Result<()> {
+        let packets: Vec<Box<dyn Packet + Send + Sync>> = vec![
+            Box::new(SenderReport {
+                ssrc: 0x902f9e2e,
+                reports: vec![ReceptionReport::default(); 2],
+                ..Default::default()
+            }),
+            Box::new(ReceiverReport {
+                ssrc: 0x902f9e2e,
+                reports: vec![ReceptionReport::default()],
+                profile_extensions: Bytes::from_static(&[0x01, 0x02, 0x03, 0x04]),
+            }),
+            Box::new(SourceDescription {
+                chunks: vec![SourceDescriptionChunk {
+                    source: 0x902f9e2e,
+                    items: vec![SourceDescriptionItem {
+                        sdes_type: SdesType::SdesCname,
+                        text: Bytes::from_static(b"cname"),
+                    }],
+                }],
+            }),
+            Box::new(Goodbye {
+                sources: vec![0x902f9e2e, 0x01020304],
+                reason: Bytes::from_static(b"bye"),
+            }),
+            Box::new(ExtendedReport::default()),
+            Box::new(PictureLossIndication::default()),
+            Box::new(SliceLossIndication {
+                sli_entries: vec![SliEntry::default(); 3],
+                ..Default::default()
+            }),
+            Box::new(FullIntraRequest {
+                fir: vec![FirEntry::default(); 2],
+                ..Default::default()
+            }),
+            Box::new(ReceiverEstimatedMaximumBitrate {
+                bitrate: 8927168.0,
+                ssrcs: vec![0x902f9e2e],
+                ..Default::default()
+            }),
+            Box::new(TransportLayerNack {
+                nacks: vec![NackPair::new(42)],
+                ..Default::default()
+            }),
+            Box::new(RapidResynchronizationRequest::default()),
+            Box::new(EcnFeedback::default()),
+            Box::new(TransportLayerCc::default()),
+            Box::new(RapidAcquisitionRequest::default()),
+            Box::new(RapidAcquisitionInformation::default()),
+            Box::new(RawPacket(Bytes::from_static(&[
+                0x81, 0xcc, 0x00, 0x01, // v=2, p=0, count=1, APP, len=1
+                0x90, 0x2f, 0x9e, 0x2e, // ssrc=0x902f9e2e
+            ]))),
+        ];
+
+        for packet in packets {
+            let h = packet.header();
+            let size = packet.marshal_size();
+            assert_eq!(
+                h.length as usize,
+                size / 4 - 1,
+                "header length mismatch for {packet:?}"
+            );
+
+            let data = packet.marshal()?;
+            let marshaled = Header::unmarshal(&mut data.clone())?;
+            assert_eq!(h, marshaled, "header mismatch for {packet:?}");
+        }
+
+        Ok(())
+    }
 }
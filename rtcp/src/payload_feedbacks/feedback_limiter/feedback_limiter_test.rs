@@ -0,0 +1,39 @@
+use super::*;
+
+#[test]
+fn test_feedback_limiter() {
+    let interval = Duration::from_millis(500);
+    let mut limiter = FeedbackLimiter::new(interval);
+    assert_eq!(limiter.min_interval(), interval);
+
+    let now = Instant::now();
+    assert!(limiter.should_send(now), "the first message should be sent");
+    assert!(
+        !limiter.should_send(now + Duration::from_millis(10)),
+        "a message within the interval should be suppressed"
+    );
+    assert!(
+        !limiter.should_send(now + Duration::from_millis(499)),
+        "a message within the interval should be suppressed"
+    );
+    assert!(
+        limiter.should_send(now + interval),
+        "a message after the interval should be sent"
+    );
+    assert!(
+        !limiter.should_send(now + interval + Duration::from_millis(10)),
+        "the interval should restart with the last message sent"
+    );
+}
+
+#[test]
+fn test_feedback_limiter_reset() {
+    let mut limiter = FeedbackLimiter::new(Duration::from_secs(1));
+
+    let now = Instant::now();
+    assert!(limiter.should_send(now));
+    assert!(!limiter.should_send(now));
+
+    limiter.reset();
+    assert!(limiter.should_send(now));
+}
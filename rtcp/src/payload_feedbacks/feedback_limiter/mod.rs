@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod feedback_limiter_test;
+
+use std::time::{Duration, Instant};
+
+/// FeedbackLimiter limits the rate at which keyframe related feedback messages
+/// (i.e. PictureLossIndication, SliceLossIndication or FullIntraRequest) get sent,
+/// as requesting keyframes too frequently wastes bandwidth and triggers keyframe storms.
+///
+/// It is meant to be used per media source, since the interval applies to all requests.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct FeedbackLimiter {
+    min_interval: Duration,
+    last_sent: Option<Instant>,
+}
+
+impl FeedbackLimiter {
+    /// new returns a limiter allowing at most one message per `min_interval`.
+    pub fn new(min_interval: Duration) -> Self {
+        FeedbackLimiter {
+            min_interval,
+            last_sent: None,
+        }
+    }
+
+    /// min_interval returns the minimum interval between two messages.
+    pub fn min_interval(&self) -> Duration {
+        self.min_interval
+    }
+
+    /// should_send returns whether a message may be sent at `now`,
+    /// in which case `now` is recorded as the time the last message has been sent.
+    pub fn should_send(&mut self, now: Instant) -> bool {
+        match self.last_sent {
+            Some(last_sent) if now.saturating_duration_since(last_sent) < self.min_interval => {
+                false
+            }
+            _ => {
+                self.last_sent = Some(now);
+                true
+            }
+        }
+    }
+
+    /// reset forgets about the last message sent, allowing the next one to be sent right away
+    /// (e.g. after the media source has changed).
+    pub fn reset(&mut self) {
+        self.last_sent = None;
+    }
+}
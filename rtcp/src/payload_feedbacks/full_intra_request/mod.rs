@@ -8,6 +8,7 @@ use bytes::{Buf, BufMut};
 use util::marshal::{Marshal, MarshalSize, Unmarshal};
 
 use crate::error::Error;
+use crate::feedback::*;
 use crate::header::*;
 use crate::packet::*;
 use crate::util::*;
@@ -43,14 +44,20 @@ impl fmt::Display for FullIntraRequest {
     }
 }
 
+impl FullIntraRequest {
+    fn feedback_header(&self) -> FeedbackHeader {
+        FeedbackHeader::new::<Self>(self.sender_ssrc, self.media_ssrc, self.raw_size())
+    }
+}
+
+impl FeedbackMessage for FullIntraRequest {
+    const PACKET_TYPE: PacketType = PacketType::PayloadSpecificFeedback;
+    const FORMAT: u8 = FORMAT_FIR;
+}
+
 impl Packet for FullIntraRequest {
     fn header(&self) -> Header {
-        Header {
-            padding: get_padding_size(self.raw_size()) != 0,
-            count: FORMAT_FIR,
-            packet_type: PacketType::PayloadSpecificFeedback,
-            length: ((self.marshal_size() / 4) - 1) as u16,
-        }
+        self.feedback_header().header
     }
 
     /// destination_ssrc returns an array of SSRC values that this packet refers to.
@@ -97,13 +104,10 @@ impl Marshal for FullIntraRequest {
             return Err(Error::BufferTooShort.into());
         }
 
-        let h = self.header();
+        let h = self.feedback_header();
         let n = h.marshal_to(buf)?;
         buf = &mut buf[n..];
 
-        buf.put_u32(self.sender_ssrc);
-        buf.put_u32(self.media_ssrc);
-
         for fir in self.fir.iter() {
             buf.put_u32(fir.ssrc);
             buf.put_u8(fir.sequence_number);
@@ -111,7 +115,7 @@ impl Marshal for FullIntraRequest {
             buf.put_u16(0);
         }
 
-        if h.padding {
+        if h.header.padding {
             put_padding(buf, self.raw_size());
         }
 
@@ -126,27 +130,14 @@ impl Unmarshal for FullIntraRequest {
         Self: Sized,
         B: Buf,
     {
-        let raw_packet_len = raw_packet.remaining();
-        if raw_packet_len < (HEADER_LENGTH + SSRC_LENGTH) {
-            return Err(Error::PacketTooShort.into());
-        }
-
-        let h = Header::unmarshal(raw_packet)?;
-
-        if raw_packet_len < (HEADER_LENGTH + (4 * h.length) as usize) {
-            return Err(Error::PacketTooShort.into());
-        }
-
-        if h.packet_type != PacketType::PayloadSpecificFeedback || h.count != FORMAT_FIR {
-            return Err(Error::WrongType.into());
-        }
-
-        let sender_ssrc = raw_packet.get_u32();
-        let media_ssrc = raw_packet.get_u32();
+        let h = FeedbackHeader::unmarshal::<Self, _>(raw_packet)?;
 
-        let mut i = HEADER_LENGTH + FIR_OFFSET;
+        let mut i = 0;
         let mut fir = vec![];
-        while i < HEADER_LENGTH + (h.length * 4) as usize {
+        while i < h.fci_length() {
+            if raw_packet.remaining() < 8 {
+                return Err(Error::PacketTooShort.into());
+            }
             fir.push(FirEntry {
                 ssrc: raw_packet.get_u32(),
                 sequence_number: raw_packet.get_u8(),
@@ -158,14 +149,14 @@ impl Unmarshal for FullIntraRequest {
         }
 
         if
-        /*h.padding &&*/
+        /*h.header.padding &&*/
         raw_packet.has_remaining() {
             raw_packet.advance(raw_packet.remaining());
         }
 
         Ok(FullIntraRequest {
-            sender_ssrc,
-            media_ssrc,
+            sender_ssrc: h.sender_ssrc,
+            media_ssrc: h.media_ssrc,
             fir,
         })
     }
@@ -0,0 +1,52 @@
+use bytes::Bytes;
+use util::marshal::Unmarshal;
+
+use super::*;
+
+#[test]
+fn test_keyframe_request_pli() -> Result<()> {
+    let request = KeyframeRequest::pli(0x902f9e2e, 0x4bc4fcb4);
+    assert_eq!(request.media_ssrc(), 0x4bc4fcb4);
+
+    let data = request.marshal()?;
+    assert_eq!(
+        data,
+        Bytes::from_static(&[
+            0x81, 0xce, 0x00, 0x02, // v=2, p=0, FMT=1, PSFB, len=2
+            0x90, 0x2f, 0x9e, 0x2e, // sender=0x902f9e2e
+            0x4b, 0xc4, 0xfc, 0xb4, // media=0x4bc4fcb4
+        ])
+    );
+
+    let pli = PictureLossIndication::unmarshal(&mut data.clone())?;
+    assert_eq!(KeyframeRequest::from(pli), request);
+
+    Ok(())
+}
+
+#[test]
+fn test_keyframe_request_fir() -> Result<()> {
+    let request = KeyframeRequest::fir(0x902f9e2e, 0x4bc4fcb4, 0x42);
+    assert_eq!(request.media_ssrc(), 0x4bc4fcb4);
+
+    let data = request.marshal()?;
+    assert_eq!(
+        data,
+        Bytes::from_static(&[
+            0x84, 0xce, 0x00, 0x04, // v=2, p=0, FMT=4, PSFB, len=4
+            0x90, 0x2f, 0x9e, 0x2e, // sender=0x902f9e2e
+            0x00, 0x00, 0x00, 0x00, // media=0x0
+            0x4b, 0xc4, 0xfc, 0xb4, // ssrc=0x4bc4fcb4
+            0x42, 0x00, 0x00, 0x00, // Seqno=0x42
+        ])
+    );
+
+    let fir = FullIntraRequest::unmarshal(&mut data.clone())?;
+    assert_eq!(KeyframeRequest::from(fir), request);
+
+    let packet = request.into_packet();
+    assert_eq!(packet.destination_ssrc(), vec![0x4bc4fcb4]);
+    assert_eq!(packet.marshal()?, data);
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod keyframe_request_test;
+
+use std::fmt;
+
+use util::marshal::{Marshal, MarshalSize};
+
+use crate::packet::*;
+use crate::payload_feedbacks::full_intra_request::*;
+use crate::payload_feedbacks::picture_loss_indication::*;
+
+type Result<T> = std::result::Result<T, util::Error>;
+
+/// A KeyframeRequest asks the sender of a video stream for a keyframe,
+/// encoded either as a PictureLossIndication or as a FullIntraRequest,
+/// depending on which of the two the sender supports.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum KeyframeRequest {
+    Pli(PictureLossIndication),
+    Fir(FullIntraRequest),
+}
+
+impl KeyframeRequest {
+    /// pli returns a keyframe request encoded as a PictureLossIndication.
+    pub fn pli(sender_ssrc: u32, media_ssrc: u32) -> Self {
+        KeyframeRequest::Pli(PictureLossIndication {
+            sender_ssrc,
+            media_ssrc,
+        })
+    }
+
+    /// fir returns a keyframe request encoded as a FullIntraRequest,
+    /// with `sequence_number` being the command sequence number of the request,
+    /// which has to be incremented for every new request of the same media source.
+    ///
+    /// As per RFC 5104, Section 4.3.1.2, the media source is carried by the FCI entry,
+    /// while the media SSRC of the common feedback header is set to 0.
+    pub fn fir(sender_ssrc: u32, media_ssrc: u32, sequence_number: u8) -> Self {
+        KeyframeRequest::Fir(FullIntraRequest {
+            sender_ssrc,
+            media_ssrc: 0,
+            fir: vec![FirEntry {
+                ssrc: media_ssrc,
+                sequence_number,
+            }],
+        })
+    }
+
+    /// media_ssrc returns the SSRC of the media source a keyframe is requested for.
+    pub fn media_ssrc(&self) -> u32 {
+        match self {
+            KeyframeRequest::Pli(pli) => pli.media_ssrc,
+            KeyframeRequest::Fir(fir) => fir.fir.first().map_or(fir.media_ssrc, |e| e.ssrc),
+        }
+    }
+
+    /// into_packet returns the request as a boxed packet, e.g. for sending it along others.
+    pub fn into_packet(self) -> Box<dyn Packet + Send + Sync> {
+        match self {
+            KeyframeRequest::Pli(pli) => Box::new(pli),
+            KeyframeRequest::Fir(fir) => Box::new(fir),
+        }
+    }
+}
+
+impl From<PictureLossIndication> for KeyframeRequest {
+    fn from(pli: PictureLossIndication) -> Self {
+        KeyframeRequest::Pli(pli)
+    }
+}
+
+impl From<FullIntraRequest> for KeyframeRequest {
+    fn from(fir: FullIntraRequest) -> Self {
+        KeyframeRequest::Fir(fir)
+    }
+}
+
+impl fmt::Display for KeyframeRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyframeRequest::Pli(pli) => write!(f, "{pli}"),
+            KeyframeRequest::Fir(fir) => write!(f, "{fir}"),
+        }
+    }
+}
+
+impl MarshalSize for KeyframeRequest {
+    fn marshal_size(&self) -> usize {
+        match self {
+            KeyframeRequest::Pli(pli) => pli.marshal_size(),
+            KeyframeRequest::Fir(fir) => fir.marshal_size(),
+        }
+    }
+}
+
+impl Marshal for KeyframeRequest {
+    /// Marshal encodes the request as the packet it wraps.
+    fn marshal_to(&self, buf: &mut [u8]) -> Result<usize> {
+        match self {
+            KeyframeRequest::Pli(pli) => pli.marshal_to(buf),
+            KeyframeRequest::Fir(fir) => fir.marshal_to(buf),
+        }
+    }
+}
@@ -1,4 +1,6 @@
+pub mod feedback_limiter;
 pub mod full_intra_request;
+pub mod keyframe_request;
 pub mod picture_loss_indication;
 pub mod receiver_estimated_maximum_bitrate;
 pub mod slice_loss_indication;
@@ -8,6 +8,7 @@ use bytes::{Buf, BufMut};
 use util::marshal::{Marshal, MarshalSize, Unmarshal};
 
 use crate::error::Error;
+use crate::feedback::*;
 use crate::header::*;
 use crate::packet::*;
 use crate::util::*;
@@ -35,15 +36,21 @@ impl fmt::Display for PictureLossIndication {
     }
 }
 
+impl PictureLossIndication {
+    fn feedback_header(&self) -> FeedbackHeader {
+        FeedbackHeader::new::<Self>(self.sender_ssrc, self.media_ssrc, self.raw_size())
+    }
+}
+
+impl FeedbackMessage for PictureLossIndication {
+    const PACKET_TYPE: PacketType = PacketType::PayloadSpecificFeedback;
+    const FORMAT: u8 = FORMAT_PLI;
+}
+
 impl Packet for PictureLossIndication {
     /// Header returns the Header associated with this packet.
     fn header(&self) -> Header {
-        Header {
-            padding: get_padding_size(self.raw_size()) != 0,
-            count: FORMAT_PLI,
-            packet_type: PacketType::PayloadSpecificFeedback,
-            length: ((self.marshal_size() / 4) - 1) as u16,
-        }
+        self.feedback_header().header
     }
 
     /// destination_ssrc returns an array of SSRC values that this packet refers to.
@@ -92,14 +99,11 @@ impl Marshal for PictureLossIndication {
             return Err(Error::BufferTooShort.into());
         }
 
-        let h = self.header();
+        let h = self.feedback_header();
         let n = h.marshal_to(buf)?;
         buf = &mut buf[n..];
 
-        buf.put_u32(self.sender_ssrc);
-        buf.put_u32(self.media_ssrc);
-
-        if h.padding {
+        if h.header.padding {
             put_padding(buf, self.raw_size());
         }
 
@@ -114,28 +118,17 @@ impl Unmarshal for PictureLossIndication {
         Self: Sized,
         B: Buf,
     {
-        let raw_packet_len = raw_packet.remaining();
-        if raw_packet_len < (HEADER_LENGTH + (SSRC_LENGTH * 2)) {
-            return Err(Error::PacketTooShort.into());
-        }
-
-        let h = Header::unmarshal(raw_packet)?;
-        if h.packet_type != PacketType::PayloadSpecificFeedback || h.count != FORMAT_PLI {
-            return Err(Error::WrongType.into());
-        }
-
-        let sender_ssrc = raw_packet.get_u32();
-        let media_ssrc = raw_packet.get_u32();
+        let h = FeedbackHeader::unmarshal::<Self, _>(raw_packet)?;
 
         if
-        /*h.padding &&*/
+        /*h.header.padding &&*/
         raw_packet.has_remaining() {
             raw_packet.advance(raw_packet.remaining());
         }
 
         Ok(PictureLossIndication {
-            sender_ssrc,
-            media_ssrc,
+            sender_ssrc: h.sender_ssrc,
+            media_ssrc: h.media_ssrc,
         })
     }
 }
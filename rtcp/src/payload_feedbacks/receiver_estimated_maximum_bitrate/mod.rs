@@ -7,7 +7,9 @@ use std::fmt;
 use bytes::{Buf, BufMut};
 use util::marshal::{Marshal, MarshalSize, Unmarshal};
 
+use crate::csrc::*;
 use crate::error::Error;
+use crate::feedback::*;
 use crate::header::*;
 use crate::packet::*;
 use crate::util::*;
@@ -30,11 +32,16 @@ pub struct ReceiverEstimatedMaximumBitrate {
 
 const REMB_OFFSET: usize = 16;
 
+/// BITRATE_MAX is the largest bitrate representable by an 18-bit mantissa
+/// and a 6-bit exponent, i.e. 0x3FFFF * 2^63.
+const BITRATE_MAX: f32 = 2.417_842_4e24; //0x3FFFFp+63;
+
 /// Keep a table of powers to units for fast conversion.
 const BIT_UNITS: [&str; 7] = ["b", "Kb", "Mb", "Gb", "Tb", "Pb", "Eb"];
 const UNIQUE_IDENTIFIER: [u8; 4] = [b'R', b'E', b'M', b'B'];
 
-/// String prints the REMB packet in a human-readable format.
+/// String prints the REMB packet in a human-readable format,
+/// e.g. `REMB 2.50 Mbps for [4bc4fcb4, 4bc4fcb5]`.
 impl fmt::Display for ReceiverEstimatedMaximumBitrate {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // Do some unit conversions because b/s is far too difficult to read.
@@ -42,30 +49,38 @@ impl fmt::Display for ReceiverEstimatedMaximumBitrate {
         let mut powers = 0;
 
         // Keep dividing the bitrate until it's under 1000
-        while bitrate >= 1000.0 && powers < BIT_UNITS.len() {
+        while bitrate >= 1000.0 && powers < BIT_UNITS.len() - 1 {
             bitrate /= 1000.0;
             powers += 1;
         }
 
         let unit = BIT_UNITS[powers];
 
-        write!(
-            f,
-            "ReceiverEstimatedMaximumBitrate {:x} {:.2} {}/s",
-            self.sender_ssrc, bitrate, unit,
-        )
+        write!(f, "REMB {bitrate:.2} {unit}ps for [")?;
+        for (i, ssrc) in self.ssrcs.iter().enumerate() {
+            let separator = if i == 0 { "" } else { ", " };
+            write!(f, "{separator}{ssrc:x}")?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl ReceiverEstimatedMaximumBitrate {
+    fn feedback_header(&self) -> FeedbackHeader {
+        // the SSRC of the media source is always zero
+        FeedbackHeader::new::<Self>(self.sender_ssrc, 0, self.raw_size())
     }
 }
 
+impl FeedbackMessage for ReceiverEstimatedMaximumBitrate {
+    const PACKET_TYPE: PacketType = PacketType::PayloadSpecificFeedback;
+    const FORMAT: u8 = FORMAT_REMB;
+}
+
 impl Packet for ReceiverEstimatedMaximumBitrate {
     /// Header returns the Header associated with this packet.
     fn header(&self) -> Header {
-        Header {
-            padding: get_padding_size(self.raw_size()) != 0,
-            count: FORMAT_REMB,
-            packet_type: PacketType::PayloadSpecificFeedback,
-            length: ((self.marshal_size() / 4) - 1) as u16,
-        }
+        self.feedback_header().header
     }
 
     /// destination_ssrc returns an array of SSRC values that this packet refers to.
@@ -74,7 +89,7 @@ impl Packet for ReceiverEstimatedMaximumBitrate {
     }
 
     fn raw_size(&self) -> usize {
-        HEADER_LENGTH + REMB_OFFSET + self.ssrcs.len() * 4
+        HEADER_LENGTH + REMB_OFFSET + csrc_list_size(self.ssrcs.len())
     }
 
     fn as_any(&self) -> &(dyn Any + Send + Sync) {
@@ -104,8 +119,6 @@ impl MarshalSize for ReceiverEstimatedMaximumBitrate {
 impl Marshal for ReceiverEstimatedMaximumBitrate {
     /// Marshal serializes the packet and returns a byte slice.
     fn marshal_to(&self, mut buf: &mut [u8]) -> Result<usize> {
-        const BITRATE_MAX: f32 = 2.417_842_4e24; //0x3FFFFp+63;
-
         /*
             0                   1                   2                   3
             0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
@@ -125,17 +138,19 @@ impl Marshal for ReceiverEstimatedMaximumBitrate {
            |  ...                                                          |
         */
 
+        // Negative, NaN and unrepresentably large bitrates would otherwise get mangled:
+        if !(0.0..=BITRATE_MAX).contains(&self.bitrate) {
+            return Err(Error::InvalidBitrate.into());
+        }
+
         if buf.remaining_mut() < self.marshal_size() {
             return Err(Error::BufferTooShort.into());
         }
 
-        let h = self.header();
+        let h = self.feedback_header();
         let n = h.marshal_to(buf)?;
         buf = &mut buf[n..];
 
-        buf.put_u32(self.sender_ssrc);
-        buf.put_u32(0); // always zero
-
         buf.put_slice(&UNIQUE_IDENTIFIER);
 
         // Write the length of the ssrcs to follow at the end
@@ -143,13 +158,6 @@ impl Marshal for ReceiverEstimatedMaximumBitrate {
 
         let mut exp = 0;
         let mut bitrate = self.bitrate;
-        if bitrate >= BITRATE_MAX {
-            bitrate = BITRATE_MAX
-        }
-
-        if bitrate < 0.0 {
-            return Err(Error::InvalidBitrate.into());
-        }
 
         while bitrate >= (1 << 18) as f32 {
             bitrate /= 2.0;
@@ -170,11 +178,10 @@ impl Marshal for ReceiverEstimatedMaximumBitrate {
         buf.put_u8(mantissa as u8);
 
         // Write the SSRCs at the very end.
-        for ssrc in &self.ssrcs {
-            buf.put_u32(*ssrc);
-        }
+        let n = marshal_csrc_list(buf, &self.ssrcs)?;
+        buf = &mut buf[n..];
 
-        if h.padding {
+        if h.header.padding {
             put_padding(buf, self.raw_size());
         }
 
@@ -214,16 +221,8 @@ impl Unmarshal for ReceiverEstimatedMaximumBitrate {
            +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
            |  ...                                                          |
         */
-        let header = Header::unmarshal(raw_packet)?;
-
-        if header.packet_type != PacketType::PayloadSpecificFeedback || header.count != FORMAT_REMB
-        {
-            return Err(Error::WrongType.into());
-        }
-
-        let sender_ssrc = raw_packet.get_u32();
-        let media_ssrc = raw_packet.get_u32();
-        if media_ssrc != 0 {
+        let h = FeedbackHeader::unmarshal::<Self, _>(raw_packet)?;
+        if h.media_ssrc != 0 {
             return Err(Error::SsrcMustBeZero.into());
         }
 
@@ -266,19 +265,20 @@ impl Unmarshal for ReceiverEstimatedMaximumBitrate {
         // bitrate = mantissa * 2^exp
         let bitrate = f32::from_bits(((exp as u32) << 23) | (mantissa & MANTISSA_MAX));
 
-        let mut ssrcs = vec![];
-        for _i in 0..ssrcs_len {
-            ssrcs.push(raw_packet.get_u32());
+        if raw_packet.remaining() < ssrcs_len * 4 {
+            return Err(Error::SsrcNumAndLengthMismatch.into());
         }
 
+        let ssrcs = unmarshal_csrc_list(raw_packet, ssrcs_len)?;
+
         if
-        /*header.padding &&*/
+        /*h.header.padding &&*/
         raw_packet.has_remaining() {
             raw_packet.advance(raw_packet.remaining());
         }
 
         Ok(ReceiverEstimatedMaximumBitrate {
-            sender_ssrc,
+            sender_ssrc: h.sender_ssrc,
             //media_ssrc,
             bitrate,
             ssrcs,
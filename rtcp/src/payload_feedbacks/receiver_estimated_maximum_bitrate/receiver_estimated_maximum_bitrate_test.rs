@@ -86,7 +86,7 @@ fn test_receiver_estimated_maximum_bitrate_truncate() {
 fn test_receiver_estimated_maximum_bitrate_overflow() {
     // Marshal a packet with the maximum possible bitrate.
     let packet = ReceiverEstimatedMaximumBitrate {
-        bitrate: f32::MAX,
+        bitrate: f32::from_bits(0x67FFFFC0),
         ..Default::default()
     };
 
@@ -119,3 +119,50 @@ fn test_receiver_estimated_maximum_bitrate_overflow() {
     let packet = ReceiverEstimatedMaximumBitrate::unmarshal(&mut input).unwrap();
     assert_eq!(packet.bitrate, f32::from_bits(0x62800000));
 }
+
+#[test]
+fn test_receiver_estimated_maximum_bitrate_invalid_bitrate() {
+    for bitrate in [f32::MAX, f32::INFINITY, f32::NAN, -1.0] {
+        let packet = ReceiverEstimatedMaximumBitrate {
+            bitrate,
+            ..Default::default()
+        };
+        assert_eq!(
+            Error::InvalidBitrate,
+            packet.marshal().unwrap_err(),
+            "bitrate {bitrate}"
+        );
+    }
+
+    // The next larger bitrate than the maximum one:
+    let packet = ReceiverEstimatedMaximumBitrate {
+        bitrate: f32::from_bits(0x67FFFFC0 + 1),
+        ..Default::default()
+    };
+    assert_eq!(Error::InvalidBitrate, packet.marshal().unwrap_err());
+}
+
+#[test]
+fn test_receiver_estimated_maximum_bitrate_display() {
+    let packet = ReceiverEstimatedMaximumBitrate {
+        sender_ssrc: 1,
+        bitrate: 2_500_000.0,
+        ssrcs: vec![0x4bc4fcb4, 0x4bc4fcb5],
+    };
+    assert_eq!(
+        packet.to_string(),
+        "REMB 2.50 Mbps for [4bc4fcb4, 4bc4fcb5]"
+    );
+
+    let packet = ReceiverEstimatedMaximumBitrate {
+        bitrate: 640.0,
+        ..Default::default()
+    };
+    assert_eq!(packet.to_string(), "REMB 640.00 bps for []");
+
+    let packet = ReceiverEstimatedMaximumBitrate {
+        bitrate: 1_250_000_000.0,
+        ..Default::default()
+    };
+    assert_eq!(packet.to_string(), "REMB 1.25 Gbps for []");
+}
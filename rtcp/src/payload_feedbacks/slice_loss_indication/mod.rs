@@ -8,6 +8,7 @@ use bytes::{Buf, BufMut};
 use util::marshal::{Marshal, MarshalSize, Unmarshal};
 
 use crate::error::Error;
+use crate::feedback::*;
 use crate::header::*;
 use crate::packet::*;
 use crate::util::*;
@@ -50,15 +51,21 @@ impl fmt::Display for SliceLossIndication {
     }
 }
 
+impl SliceLossIndication {
+    fn feedback_header(&self) -> FeedbackHeader {
+        FeedbackHeader::new::<Self>(self.sender_ssrc, self.media_ssrc, self.raw_size())
+    }
+}
+
+impl FeedbackMessage for SliceLossIndication {
+    const PACKET_TYPE: PacketType = PacketType::PayloadSpecificFeedback;
+    const FORMAT: u8 = FORMAT_SLI;
+}
+
 impl Packet for SliceLossIndication {
     /// Header returns the Header associated with this packet.
     fn header(&self) -> Header {
-        Header {
-            padding: get_padding_size(self.raw_size()) != 0,
-            count: FORMAT_SLI,
-            packet_type: PacketType::TransportSpecificFeedback,
-            length: ((self.marshal_size() / 4) - 1) as u16,
-        }
+        self.feedback_header().header
     }
 
     /// destination_ssrc returns an array of SSRC values that this packet refers to.
@@ -104,13 +111,10 @@ impl Marshal for SliceLossIndication {
             return Err(Error::BufferTooShort.into());
         }
 
-        let h = self.header();
+        let h = self.feedback_header();
         let n = h.marshal_to(buf)?;
         buf = &mut buf[n..];
 
-        buf.put_u32(self.sender_ssrc);
-        buf.put_u32(self.media_ssrc);
-
         for s in &self.sli_entries {
             let sli = ((s.first as u32 & 0x1FFF) << 19)
                 | ((s.number as u32 & 0x1FFF) << 6)
@@ -119,7 +123,7 @@ impl Marshal for SliceLossIndication {
             buf.put_u32(sli);
         }
 
-        if h.padding {
+        if h.header.padding {
             put_padding(buf, self.raw_size());
         }
 
@@ -134,27 +138,11 @@ impl Unmarshal for SliceLossIndication {
         Self: Sized,
         B: Buf,
     {
-        let raw_packet_len = raw_packet.remaining();
-        if raw_packet_len < (HEADER_LENGTH + SSRC_LENGTH) {
-            return Err(Error::PacketTooShort.into());
-        }
-
-        let h = Header::unmarshal(raw_packet)?;
-
-        if raw_packet_len < (HEADER_LENGTH + (4 * h.length as usize)) {
-            return Err(Error::PacketTooShort.into());
-        }
-
-        if h.packet_type != PacketType::TransportSpecificFeedback || h.count != FORMAT_SLI {
-            return Err(Error::WrongType.into());
-        }
-
-        let sender_ssrc = raw_packet.get_u32();
-        let media_ssrc = raw_packet.get_u32();
+        let h = FeedbackHeader::unmarshal::<Self, _>(raw_packet)?;
 
-        let mut i = HEADER_LENGTH + SLI_OFFSET;
+        let mut i = 0;
         let mut sli_entries = vec![];
-        while i < HEADER_LENGTH + h.length as usize * 4 {
+        while i < h.fci_length() {
             let sli = raw_packet.get_u32();
             sli_entries.push(SliEntry {
                 first: ((sli >> 19) & 0x1FFF) as u16,
@@ -166,14 +154,14 @@ impl Unmarshal for SliceLossIndication {
         }
 
         if
-        /*h.padding &&*/
+        /*h.header.padding &&*/
         raw_packet.has_remaining() {
             raw_packet.advance(raw_packet.remaining());
         }
 
         Ok(SliceLossIndication {
-            sender_ssrc,
-            media_ssrc,
+            sender_ssrc: h.sender_ssrc,
+            media_ssrc: h.media_ssrc,
             sli_entries,
         })
     }
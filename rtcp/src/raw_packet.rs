@@ -11,9 +11,28 @@ use crate::util::*;
 
 /// RawPacket represents an unparsed RTCP packet. It's returned by Unmarshal when
 /// a packet with an unknown type is encountered.
-#[derive(Debug, PartialEq, Eq, Default, Clone)]
+#[derive(PartialEq, Eq, Default, Clone)]
 pub struct RawPacket(pub Bytes);
 
+impl fmt::Debug for RawPacket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RawPacket")
+            .field(&HexBytes(&self.0))
+            .finish()
+    }
+}
+
+impl RawPacket {
+    /// parse_header parses the common header of the packet, e.g. for routing
+    /// packets of unknown type by their packet type without parsing their payload.
+    ///
+    /// Unlike `Packet::header`, this fails rather than returning a default header
+    /// if the packet is too short for a header or of an unsupported version.
+    pub fn parse_header(&self) -> Result<Header, util::Error> {
+        Header::unmarshal(&mut self.0.clone())
+    }
+}
+
 impl fmt::Display for RawPacket {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "RawPacket: {self:?}")
@@ -23,10 +42,7 @@ impl fmt::Display for RawPacket {
 impl Packet for RawPacket {
     /// Header returns the Header associated with this packet.
     fn header(&self) -> Header {
-        match Header::unmarshal(&mut self.0.clone()) {
-            Ok(h) => h,
-            Err(_) => Header::default(),
-        }
+        self.parse_header().unwrap_or_default()
     }
 
     /// destination_ssrc returns an array of SSRC values that this packet refers to.
@@ -65,7 +81,7 @@ impl MarshalSize for RawPacket {
 impl Marshal for RawPacket {
     /// Marshal encodes the packet in binary.
     fn marshal_to(&self, mut buf: &mut [u8]) -> Result<usize, util::Error> {
-        let h = Header::unmarshal(&mut self.0.clone())?;
+        let h = self.parse_header()?;
         buf.put(self.0.clone());
         if h.padding {
             put_padding(buf, self.raw_size());
@@ -88,6 +104,8 @@ impl Unmarshal for RawPacket {
 
         let h = Header::unmarshal(raw_packet)?;
 
+        h.check_packet_size(raw_packet_len)?;
+
         let raw_hdr = h.marshal()?;
         let raw_body = raw_packet.copy_to_bytes(raw_packet.remaining());
         let mut raw = BytesMut::new();
@@ -101,6 +119,18 @@ impl Unmarshal for RawPacket {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::sender_report::SenderReport;
+
+    #[test]
+    fn test_raw_packet_debug() {
+        let packet = RawPacket(Bytes::from_static(&[
+            0x81, 0xcb, 0x00, 0x01, 0xde, 0xad, 0xbe, 0xef,
+        ]));
+        assert_eq!(
+            format!("{packet:?}"),
+            "RawPacket(8 bytes [81 cb 00 01 de ad be ef])"
+        );
+    }
 
     #[test]
     fn test_raw_packet_roundtrip() -> Result<(), Error> {
@@ -108,7 +138,7 @@ mod test {
             (
                 "valid",
                 RawPacket(Bytes::from_static(&[
-                    0x81, 0xcb, 0x00, 0x0c, // v=2, p=0, count=1, BYE, len=12
+                    0x81, 0xcb, 0x00, 0x02, // v=2, p=0, count=1, BYE, len=2
                     0x90, 0x2f, 0x9e, 0x2e, // ssrc=0x902f9e2e
                     0x03, 0x46, 0x4f, 0x4f, // len=3, text=FOO
                 ])),
@@ -165,4 +195,32 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_raw_packet_parse_header() -> Result<(), util::Error> {
+        let sr = SenderReport {
+            ssrc: 0x902f9e2e,
+            ntp_time: 0xda8bd1fcdddda05a,
+            rtp_time: 0xaaf4edd5,
+            packet_count: 1,
+            octet_count: 2,
+            ..Default::default()
+        };
+        let raw = RawPacket(sr.marshal()?);
+
+        let header = raw.parse_header()?;
+        assert_eq!(header.packet_type, PacketType::SenderReport);
+        assert_eq!(header.count, 0);
+        assert_eq!(header.packet_size(), raw.raw_size());
+        assert_eq!(header, sr.header());
+
+        let result = RawPacket(Bytes::from_static(&[0x80, 0xc8])).parse_header();
+        assert_eq!(Error::PacketTooShort, result.unwrap_err());
+
+        // v=0, p=0, count=0, SR, len=6
+        let result = RawPacket(Bytes::from_static(&[0x00, 0xc8, 0x00, 0x06])).parse_header();
+        assert_eq!(Error::BadVersion, result.unwrap_err());
+
+        Ok(())
+    }
 }
@@ -19,7 +19,7 @@ pub(super) const RR_SSRC_OFFSET: usize = HEADER_LENGTH;
 pub(super) const RR_REPORT_OFFSET: usize = RR_SSRC_OFFSET + SSRC_LENGTH;
 
 /// A ReceiverReport (RR) packet provides reception quality feedback for an RTP stream
-#[derive(Debug, PartialEq, Eq, Default, Clone)]
+#[derive(PartialEq, Eq, Default, Clone)]
 pub struct ReceiverReport {
     /// The synchronization source identifier for the originator of this RR packet.
     pub ssrc: u32,
@@ -33,6 +33,16 @@ pub struct ReceiverReport {
     pub profile_extensions: Bytes,
 }
 
+impl fmt::Debug for ReceiverReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReceiverReport")
+            .field("ssrc", &self.ssrc)
+            .field("reports", &self.reports)
+            .field("profile_extensions", &HexBytes(&self.profile_extensions))
+            .finish()
+    }
+}
+
 impl fmt::Display for ReceiverReport {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut out = format!("ReceiverReport from {}\n", self.ssrc);
@@ -66,11 +76,13 @@ impl Packet for ReceiverReport {
         self.reports.iter().map(|x| x.ssrc).collect()
     }
 
+    fn destination_ssrc_into(&self, out: &mut Vec<u32>) {
+        out.extend(self.reports.iter().map(|x| x.ssrc));
+    }
+
     fn raw_size(&self) -> usize {
-        let mut reps_length = 0;
-        for rep in &self.reports {
-            reps_length += rep.marshal_size();
-        }
+        // reception report blocks have a fixed size, no need to visit each of them
+        let reps_length = self.reports.len() * RECEPTION_REPORT_LENGTH;
 
         HEADER_LENGTH + SSRC_LENGTH + reps_length + self.profile_extensions.len()
     }
@@ -106,7 +118,10 @@ impl Marshal for ReceiverReport {
             return Err(Error::TooManyReports.into());
         }
 
-        if buf.remaining_mut() < self.marshal_size() {
+        // compute the sizes only once, rather than per report block
+        let raw_size = self.raw_size();
+        let marshal_size = raw_size + get_padding_size(raw_size);
+        if buf.remaining_mut() < marshal_size {
             return Err(Error::BufferTooShort.into());
         }
 
@@ -143,18 +158,16 @@ impl Marshal for ReceiverReport {
 
         buf.put_u32(self.ssrc);
 
-        for report in &self.reports {
-            let n = report.marshal_to(buf)?;
-            buf = &mut buf[n..];
-        }
+        let n = marshal_reports_to(buf, &self.reports)?;
+        buf = &mut buf[n..];
 
         buf.put(self.profile_extensions.clone());
 
         if h.padding {
-            put_padding(buf, self.raw_size());
+            put_padding(buf, raw_size);
         }
 
-        Ok(self.marshal_size())
+        Ok(marshal_size)
     }
 }
 
@@ -198,21 +211,24 @@ impl Unmarshal for ReceiverReport {
         }
 
         let header = Header::unmarshal(raw_packet)?;
+
+        header.check_packet_size(raw_packet_len)?;
         if header.packet_type != PacketType::ReceiverReport {
             return Err(Error::WrongType.into());
         }
 
         let ssrc = raw_packet.get_u32();
 
-        let mut offset = RR_REPORT_OFFSET;
+        // The declared length must hold as many report blocks as RC claims:
+        if header.packet_size() < RR_REPORT_OFFSET + header.count as usize * RECEPTION_REPORT_LENGTH
+        {
+            return Err(Error::InvalidReportCount.into());
+        }
+
         let mut reports = Vec::with_capacity(header.count as usize);
         for _ in 0..header.count {
-            if offset + RECEPTION_REPORT_LENGTH > raw_packet_len {
-                return Err(Error::PacketTooShort.into());
-            }
             let reception_report = ReceptionReport::unmarshal(raw_packet)?;
             reports.push(reception_report);
-            offset += RECEPTION_REPORT_LENGTH;
         }
         let profile_extensions = raw_packet.copy_to_bytes(raw_packet.remaining());
         /*
@@ -228,3 +244,59 @@ impl Unmarshal for ReceiverReport {
         })
     }
 }
+
+/// ReceiverReportBuilder assembles a [`ReceiverReport`] one reception report block at a time.
+///
+/// The RC field of the resulting header always matches the number of blocks added, and
+/// [`build`](ReceiverReportBuilder::build) fails once that number no longer fits in it.
+#[derive(Debug, Default, Clone)]
+pub struct ReceiverReportBuilder {
+    ssrc: u32,
+    reports: Vec<ReceptionReport>,
+    profile_extensions: Bytes,
+}
+
+impl ReceiverReportBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ssrc sets the synchronization source identifier of the report originator.
+    pub fn ssrc(mut self, ssrc: u32) -> Self {
+        self.ssrc = ssrc;
+        self
+    }
+
+    /// report appends a reception report block.
+    pub fn report(mut self, report: ReceptionReport) -> Self {
+        self.reports.push(report);
+        self
+    }
+
+    /// profile_extension sets the profile-specific extension data.
+    pub fn profile_extension(mut self, profile_extensions: Bytes) -> Self {
+        self.profile_extensions = profile_extensions;
+        self
+    }
+
+    /// build returns the ReceiverReport, or [`Error::TooManyReports`] if more than
+    /// 31 reception report blocks were added.
+    pub fn build(self) -> Result<ReceiverReport> {
+        if self.reports.len() > COUNT_MAX {
+            return Err(Error::TooManyReports.into());
+        }
+
+        Ok(ReceiverReport {
+            ssrc: self.ssrc,
+            reports: self.reports,
+            profile_extensions: self.profile_extensions,
+        })
+    }
+}
+
+impl ReceiverReport {
+    /// builder returns a [`ReceiverReportBuilder`].
+    pub fn builder() -> ReceiverReportBuilder {
+        ReceiverReportBuilder::new()
+    }
+}
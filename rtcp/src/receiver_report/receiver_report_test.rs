@@ -1,3 +1,5 @@
+use bytes::BytesMut;
+
 use super::*;
 
 #[test]
@@ -102,7 +104,25 @@ fn test_receiver_report_unmarshal() {
                 0x0, 0x2, 0x4a, 0x79, // delay=150137
             ]),
             ReceiverReport::default(),
-            Some(Error::PacketTooShort),
+            Some(Error::InvalidReportCount),
+        ),
+        (
+            "count exceeds length",
+            Bytes::from_static(&[
+                0x82, 0xc9, 0x0, 0x7, // v=2, p=0, count=2, RR, len=7
+                0x90, 0x2f, 0x9e, 0x2e, // ssrc=0x902f9e2e
+                0xbc, 0x5e, 0x9a, 0x40, // ssrc=0xbc5e9a40
+                0x0, 0x0, 0x0, 0x0, // fracLost=0, totalLost=0
+                0x0, 0x0, 0x46, 0xe1, // lastSeq=0x46e1
+                0x0, 0x0, 0x1, 0x11, // jitter=273
+                0x9, 0xf3, 0x64, 0x32, // lsr=0x9f36432
+                0x0, 0x2, 0x4a, 0x79, // delay=150137
+                // trailing bytes beyond the declared length:
+                0xbc, 0x5e, 0x9a, 0x41, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x46, 0xe1, 0x0, 0x0, 0x1,
+                0x11, 0x9, 0xf3, 0x64, 0x32, 0x0, 0x2, 0x4a, 0x79,
+            ]),
+            ReceiverReport::default(),
+            Some(Error::InvalidReportCount),
         ),
         (
             "nil",
@@ -240,3 +260,104 @@ fn test_receiver_report_roundtrip() {
         }
     }
 }
+
+#[test]
+fn test_receiver_report_builder() -> Result<()> {
+    let first = ReceptionReport {
+        ssrc: 0xbc5e9a40,
+        last_sequence_number: 0x46e1,
+        ..Default::default()
+    };
+    let second = ReceptionReport {
+        ssrc: 0xbc5e9a41,
+        fraction_lost: 1,
+        total_lost: 2,
+        ..Default::default()
+    };
+
+    let rr = ReceiverReport::builder()
+        .ssrc(0x902f9e2e)
+        .report(first.clone())
+        .report(second.clone())
+        .profile_extension(Bytes::from_static(&[0x54, 0x45, 0x53, 0x54]))
+        .build()?;
+
+    assert_eq!(
+        rr,
+        ReceiverReport {
+            ssrc: 0x902f9e2e,
+            reports: vec![first, second],
+            profile_extensions: Bytes::from_static(&[0x54, 0x45, 0x53, 0x54]),
+        }
+    );
+
+    let h = rr.header();
+    assert_eq!(h.count, 2);
+    assert_eq!(h.packet_type, PacketType::ReceiverReport);
+
+    let mut data = rr.marshal()?;
+    assert_eq!(data[0] & 0x1f, 2);
+    assert_eq!(ReceiverReport::unmarshal(&mut data)?, rr);
+
+    Ok(())
+}
+
+#[test]
+fn test_receiver_report_builder_too_many_reports() {
+    let mut builder = ReceiverReportBuilder::new().ssrc(1);
+    for i in 0..=COUNT_MAX {
+        builder = builder.report(ReceptionReport {
+            ssrc: i as u32,
+            ..Default::default()
+        });
+    }
+
+    let got = builder.build();
+    assert_eq!(Error::TooManyReports, got.unwrap_err());
+}
+
+#[test]
+fn test_receiver_report_marshal_many_reports() {
+    let rr = ReceiverReport {
+        ssrc: 0x902f9e2e,
+        reports: (0..COUNT_MAX as u32)
+            .map(|i| ReceptionReport {
+                ssrc: 0xbc5e9a40 + i,
+                fraction_lost: i as u8,
+                total_lost: 0xFF_FF00 + i,
+                last_sequence_number: 0x46e1 + i,
+                jitter: 273 + i,
+                last_sender_report: 0x9f36432 + i,
+                delay: 150137 + i,
+            })
+            .collect(),
+        profile_extensions: Bytes::from_static(&[0x81, 0xca]),
+    };
+
+    // encode field by field, one report block at a time:
+    let mut want = BytesMut::new();
+    want.extend_from_slice(&rr.header().marshal().unwrap());
+    want.put_u32(rr.ssrc);
+    for report in &rr.reports {
+        want.extend_from_slice(&report.marshal().unwrap());
+    }
+    want.extend_from_slice(&rr.profile_extensions);
+    want.put_u8(0);
+    want.put_u8(2);
+
+    let got = rr.marshal().expect("Marshal rr");
+
+    assert_eq!(got, want.freeze());
+}
+
+#[test]
+fn test_receiver_report_unmarshal_length_exceeds_buffer() {
+    let mut data = Bytes::from_static(&[
+        0x80, 0xc9, 0xff, 0xff, // v=2, p=0, count=0, RR, len=65535
+        0x90, 0x2f, 0x9e, 0x2e, // ssrc=0x902f9e2e
+    ]);
+
+    let got = ReceiverReport::unmarshal(&mut data);
+
+    assert_eq!(Error::PacketTooShort, got.unwrap_err());
+}
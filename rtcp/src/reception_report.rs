@@ -17,6 +17,9 @@ pub(crate) const JITTER_OFFSET: usize = 12;
 pub(crate) const LAST_SR_OFFSET: usize = 16;
 pub(crate) const DELAY_OFFSET: usize = 20;
 
+/// The largest total number of lost packets representable by the 24-bit field of a reception report.
+pub const TOTAL_LOST_MAX: u32 = 0xFF_FFFF;
+
 /// A ReceptionReport block conveys statistics on the reception of RTP packets
 /// from a single synchronization source.
 #[derive(Debug, PartialEq, Eq, Default, Clone)]
@@ -26,10 +29,12 @@ pub struct ReceptionReport {
     pub ssrc: u32,
     /// The fraction of RTP data packets from source SSRC lost since the
     /// previous SR or RR packet was sent, expressed as a fixed point
-    /// number with the binary point at the left edge of the field.
+    /// number with the binary point at the left edge of the field
+    /// (i.e. in units of 1/256).
     pub fraction_lost: u8,
     /// The total number of RTP data packets from source SSRC that have
     /// been lost since the beginning of reception.
+    /// Encoded in 24 bits, hence at most `TOTAL_LOST_MAX`.
     pub total_lost: u32,
     /// The least significant 16 bits contain the highest sequence number received
     /// in an RTP data packet from source SSRC, and the most significant 16 bits extend
@@ -40,8 +45,9 @@ pub struct ReceptionReport {
     /// unsigned integer.
     pub jitter: u32,
     /// The middle 32 bits out of 64 in the NTP timestamp received as part of
-    /// the most recent RTCP sender report (SR) packet from source SSRC. If no
-    /// SR has been received yet, the field is set to zero.
+    /// the most recent RTCP sender report (SR) packet from source SSRC
+    /// (i.e. in units of 1/65536 seconds). If no SR has been received yet,
+    /// the field is set to zero.
     pub last_sender_report: u32,
     /// The delay, expressed in units of 1/65536 seconds, between receiving the
     /// last SR packet from source SSRC and sending this reception report block.
@@ -55,6 +61,37 @@ impl fmt::Display for ReceptionReport {
     }
 }
 
+impl ReceptionReport {
+    /// new creates a ReceptionReport block from the given statistics,
+    /// whose units are documented on the respective fields
+    /// (e.g. `fraction_lost` in 1/256, `delay` in 1/65536 seconds).
+    ///
+    /// Fails if `total_lost` exceeds `TOTAL_LOST_MAX`, i.e. does not fit into 24 bits.
+    pub fn new(
+        ssrc: u32,
+        fraction_lost: u8,
+        total_lost: u32,
+        last_sequence_number: u32,
+        jitter: u32,
+        last_sender_report: u32,
+        delay: u32,
+    ) -> Result<Self, util::Error> {
+        if total_lost > TOTAL_LOST_MAX {
+            return Err(Error::InvalidTotalLost.into());
+        }
+
+        Ok(ReceptionReport {
+            ssrc,
+            fraction_lost,
+            total_lost,
+            last_sequence_number,
+            jitter,
+            last_sender_report,
+            delay,
+        })
+    }
+}
+
 impl Packet for ReceptionReport {
     fn header(&self) -> Header {
         Header::default()
@@ -116,23 +153,8 @@ impl Marshal for ReceptionReport {
             return Err(Error::BufferTooShort.into());
         }
 
-        buf.put_u32(self.ssrc);
-
-        buf.put_u8(self.fraction_lost);
-
-        // pack TotalLost into 24 bits
-        if self.total_lost >= (1 << 25) {
-            return Err(Error::InvalidTotalLost.into());
-        }
-
-        buf.put_u8(((self.total_lost >> 16) & 0xFF) as u8);
-        buf.put_u8(((self.total_lost >> 8) & 0xFF) as u8);
-        buf.put_u8((self.total_lost & 0xFF) as u8);
-
-        buf.put_u32(self.last_sequence_number);
-        buf.put_u32(self.jitter);
-        buf.put_u32(self.last_sender_report);
-        buf.put_u32(self.delay);
+        self.marshal_block(&mut buf[..RECEPTION_REPORT_LENGTH])?;
+        buf = &mut buf[RECEPTION_REPORT_LENGTH..];
 
         put_padding(buf, self.raw_size());
 
@@ -204,3 +226,89 @@ impl Unmarshal for ReceptionReport {
         })
     }
 }
+
+impl ReceptionReport {
+    /// Encodes the report into `block`, which must be exactly `RECEPTION_REPORT_LENGTH` bytes long.
+    fn marshal_block(&self, block: &mut [u8]) -> Result<(), util::Error> {
+        // pack TotalLost into 24 bits
+        if self.total_lost > TOTAL_LOST_MAX {
+            return Err(Error::InvalidTotalLost.into());
+        }
+
+        block[..FRACTION_LOST_OFFSET].copy_from_slice(&self.ssrc.to_be_bytes());
+        block[FRACTION_LOST_OFFSET..LAST_SEQ_OFFSET]
+            .copy_from_slice(&((self.fraction_lost as u32) << 24 | self.total_lost).to_be_bytes());
+        block[LAST_SEQ_OFFSET..JITTER_OFFSET]
+            .copy_from_slice(&self.last_sequence_number.to_be_bytes());
+        block[JITTER_OFFSET..LAST_SR_OFFSET].copy_from_slice(&self.jitter.to_be_bytes());
+        block[LAST_SR_OFFSET..DELAY_OFFSET].copy_from_slice(&self.last_sender_report.to_be_bytes());
+        block[DELAY_OFFSET..].copy_from_slice(&self.delay.to_be_bytes());
+
+        Ok(())
+    }
+}
+
+/// Encodes `reports` back to back into `buf`, returning the number of bytes written.
+///
+/// Equivalent to calling `ReceptionReport::marshal_to` for each report,
+/// but with a single bounds check for all of them, which makes it
+/// the preferred way of encoding the report blocks of SR and RR packets.
+pub(crate) fn marshal_reports_to(
+    buf: &mut [u8],
+    reports: &[ReceptionReport],
+) -> Result<usize, util::Error> {
+    let len = reports.len() * RECEPTION_REPORT_LENGTH;
+    if buf.len() < len {
+        return Err(Error::BufferTooShort.into());
+    }
+
+    for (report, block) in reports
+        .iter()
+        .zip(buf[..len].chunks_exact_mut(RECEPTION_REPORT_LENGTH))
+    {
+        report.marshal_block(block)?;
+    }
+
+    Ok(len)
+}
+
+#[cfg(test)]
+mod test {
+    use bytes::Bytes;
+
+    use super::*;
+
+    #[test]
+    fn test_reception_report_new() -> Result<(), util::Error> {
+        let report = ReceptionReport::new(
+            0xbc5e9a40,
+            0x40,
+            TOTAL_LOST_MAX,
+            0x46e1,
+            273,
+            0x9f36432,
+            150137,
+        )?;
+
+        let data = report.marshal()?;
+        assert_eq!(
+            data,
+            Bytes::from_static(&[
+                0xbc, 0x5e, 0x9a, 0x40, // ssrc=0xbc5e9a40
+                0x40, 0xff, 0xff, 0xff, // fracLost=0x40, totalLost=0xffffff
+                0x0, 0x0, 0x46, 0xe1, // lastSeq=0x46e1
+                0x0, 0x0, 0x1, 0x11, // jitter=273
+                0x9, 0xf3, 0x64, 0x32, // lsr=0x9f36432
+                0x0, 0x2, 0x4a, 0x79, // delay=150137
+            ])
+        );
+
+        let unmarshaled = ReceptionReport::unmarshal(&mut data.clone())?;
+        assert_eq!(unmarshaled, report);
+
+        let result = ReceptionReport::new(0, 0, TOTAL_LOST_MAX + 1, 0, 0, 0, 0);
+        assert_eq!(Error::InvalidTotalLost, result.unwrap_err());
+
+        Ok(())
+    }
+}
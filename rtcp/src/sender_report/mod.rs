@@ -29,7 +29,7 @@ pub(crate) const SR_OCTET_COUNT_OFFSET: usize = SR_PACKET_COUNT_OFFSET + SR_PACK
 pub(crate) const SR_OCTET_COUNT_LENGTH: usize = 4;
 
 /// A SenderReport (SR) packet provides reception quality feedback for an RTP stream
-#[derive(Debug, PartialEq, Eq, Default, Clone)]
+#[derive(PartialEq, Eq, Default, Clone)]
 pub struct SenderReport {
     /// The synchronization source identifier for the originator of this SR packet.
     pub ssrc: u32,
@@ -64,6 +64,93 @@ pub struct SenderReport {
     pub profile_extensions: Bytes,
 }
 
+impl fmt::Debug for SenderReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SenderReport")
+            .field("ssrc", &self.ssrc)
+            .field("ntp_time", &self.ntp_time)
+            .field("rtp_time", &self.rtp_time)
+            .field("packet_count", &self.packet_count)
+            .field("octet_count", &self.octet_count)
+            .field("reports", &self.reports)
+            .field("profile_extensions", &HexBytes(&self.profile_extensions))
+            .finish()
+    }
+}
+
+impl SenderReport {
+    /// with_reports creates a SenderReport carrying the given reception report blocks,
+    /// whose number determines the RC field of the header.
+    ///
+    /// Fails if there are more than 31 reports.
+    pub fn with_reports(
+        ssrc: u32,
+        ntp_time: u64,
+        rtp_time: u32,
+        packet_count: u32,
+        octet_count: u32,
+        reports: Vec<ReceptionReport>,
+    ) -> Result<Self> {
+        if reports.len() > COUNT_MAX {
+            return Err(Error::TooManyReports.into());
+        }
+
+        Ok(SenderReport {
+            ssrc,
+            ntp_time,
+            rtp_time,
+            packet_count,
+            octet_count,
+            reports,
+            profile_extensions: Bytes::new(),
+        })
+    }
+
+    /// push_report appends a reception report block.
+    ///
+    /// Fails, leaving the report unchanged, if it already carries 31 reports.
+    pub fn push_report(&mut self, report: ReceptionReport) -> Result<()> {
+        if self.reports.len() >= COUNT_MAX {
+            return Err(Error::TooManyReports.into());
+        }
+
+        self.reports.push(report);
+        Ok(())
+    }
+
+    /// Returns the time elapsed between `prev` and this report according to their
+    /// NTP timestamps, in seconds, if this report was sent after `prev` by the same sender.
+    fn seconds_since(&self, prev: &SenderReport) -> Option<f64> {
+        if self.ssrc != prev.ssrc || self.ntp_time <= prev.ntp_time {
+            return None;
+        }
+        // NTP timestamps are 32.32 fixed point numbers of seconds
+        Some((self.ntp_time - prev.ntp_time) as f64 / (1u64 << 32) as f64)
+    }
+
+    /// Returns the average rate in bits per second at which payload octets have been sent
+    /// between `prev` and this report.
+    ///
+    /// Returns `None` if the reports originate from different senders, if this report
+    /// was not sent after `prev`, or if the octet count has wrapped around in between.
+    pub fn bitrate_since(&self, prev: &SenderReport) -> Option<f64> {
+        let seconds = self.seconds_since(prev)?;
+        let octets = self.octet_count.checked_sub(prev.octet_count)?;
+        Some(octets as f64 * 8.0 / seconds)
+    }
+
+    /// Returns the average rate in packets per second at which RTP data packets have been sent
+    /// between `prev` and this report.
+    ///
+    /// Returns `None` if the reports originate from different senders, if this report
+    /// was not sent after `prev`, or if the packet count has wrapped around in between.
+    pub fn packet_rate_since(&self, prev: &SenderReport) -> Option<f64> {
+        let seconds = self.seconds_since(prev)?;
+        let packets = self.packet_count.checked_sub(prev.packet_count)?;
+        Some(packets as f64 / seconds)
+    }
+}
+
 impl fmt::Display for SenderReport {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut out = format!("SenderReport from {}\n", self.ssrc);
@@ -103,11 +190,14 @@ impl Packet for SenderReport {
         out
     }
 
+    fn destination_ssrc_into(&self, out: &mut Vec<u32>) {
+        out.extend(self.reports.iter().map(|x| x.ssrc));
+        out.push(self.ssrc);
+    }
+
     fn raw_size(&self) -> usize {
-        let mut reps_length = 0;
-        for rep in &self.reports {
-            reps_length += rep.marshal_size();
-        }
+        // reception report blocks have a fixed size, no need to visit each of them
+        let reps_length = self.reports.len() * RECEPTION_REPORT_LENGTH;
 
         HEADER_LENGTH + SR_HEADER_LENGTH + reps_length + self.profile_extensions.len()
     }
@@ -143,7 +233,10 @@ impl Marshal for SenderReport {
             return Err(Error::TooManyReports.into());
         }
 
-        if buf.remaining_mut() < self.marshal_size() {
+        // compute the sizes only once, rather than per report block
+        let raw_size = self.raw_size();
+        let marshal_size = raw_size + get_padding_size(raw_size);
+        if buf.remaining_mut() < marshal_size {
             return Err(Error::BufferTooShort.into());
         }
 
@@ -194,18 +287,16 @@ impl Marshal for SenderReport {
         buf.put_u32(self.packet_count);
         buf.put_u32(self.octet_count);
 
-        for report in &self.reports {
-            let n = report.marshal_to(buf)?;
-            buf = &mut buf[n..];
-        }
+        let n = marshal_reports_to(buf, &self.reports)?;
+        buf = &mut buf[n..];
 
         buf.put(self.profile_extensions.clone());
 
         if h.padding {
-            put_padding(buf, self.raw_size());
+            put_padding(buf, raw_size);
         }
 
-        Ok(self.marshal_size())
+        Ok(marshal_size)
     }
 }
 
@@ -259,6 +350,8 @@ impl Unmarshal for SenderReport {
         }
 
         let header = Header::unmarshal(raw_packet)?;
+
+        header.check_packet_size(raw_packet_len)?;
         if header.packet_type != PacketType::SenderReport {
             return Err(Error::WrongType.into());
         }
@@ -269,15 +362,16 @@ impl Unmarshal for SenderReport {
         let packet_count = raw_packet.get_u32();
         let octet_count = raw_packet.get_u32();
 
-        let mut offset = SR_REPORT_OFFSET;
+        // The declared length must hold as many report blocks as RC claims:
+        if header.packet_size() < SR_REPORT_OFFSET + header.count as usize * RECEPTION_REPORT_LENGTH
+        {
+            return Err(Error::InvalidReportCount.into());
+        }
+
         let mut reports = Vec::with_capacity(header.count as usize);
         for _ in 0..header.count {
-            if offset + RECEPTION_REPORT_LENGTH > raw_packet_len {
-                return Err(Error::PacketTooShort.into());
-            }
             let reception_report = ReceptionReport::unmarshal(raw_packet)?;
             reports.push(reception_report);
-            offset += RECEPTION_REPORT_LENGTH;
         }
         let profile_extensions = raw_packet.copy_to_bytes(raw_packet.remaining());
         /*
@@ -1,3 +1,5 @@
+use bytes::BytesMut;
+
 use super::*;
 
 #[test]
@@ -12,7 +14,7 @@ fn test_sender_report_unmarshal() {
         (
             "valid",
             Bytes::from_static(&[
-                0x81u8, 0xc8, 0x0, 0x7, // v=2, p=0, count=1, SR, len=7
+                0x81u8, 0xc8, 0x0, 0xc, // v=2, p=0, count=1, SR, len=12
                 0x90, 0x2f, 0x9e, 0x2e, // ssrc=0x902f9e2e
                 0xda, 0x8b, 0xd1, 0xfc, 0xdd, 0xdd, 0xa0, 0x5a, // ntp=0xda8bd1fcdddda05a
                 0xaa, 0xf4, 0xed, 0xd5, // rtp=0xaaf4edd5
@@ -66,7 +68,7 @@ fn test_sender_report_unmarshal() {
         (
             "bad count in header",
             Bytes::from_static(&[
-                0x82, 0xc8, 0x0, 0x7, // v=2, p=0, count=1, SR, len=7
+                0x82, 0xc8, 0x0, 0xc, // v=2, p=0, count=2, SR, len=12
                 0x90, 0x2f, 0x9e, 0x2e, // ssrc=0x902f9e2e
                 0xda, 0x8b, 0xd1, 0xfc, 0xdd, 0xdd, 0xa0, 0x5a, // ntp=0xda8bd1fcdddda05a
                 0xaa, 0xf4, 0xed, 0xd5, // rtp=0xaaf4edd5
@@ -80,7 +82,7 @@ fn test_sender_report_unmarshal() {
                 0x0, 0x2, 0x4a, 0x79, // delay=150137
             ]),
             SenderReport::default(),
-            Some(Error::PacketTooShort),
+            Some(Error::InvalidReportCount),
         ),
         (
             "with extension", // issue #447
@@ -250,3 +252,178 @@ fn test_sender_report_roundtrip() {
         }
     }
 }
+
+#[test]
+fn test_sender_report_marshal_many_reports() {
+    let sr = SenderReport {
+        ssrc: 0x902f9e2e,
+        ntp_time: 0xda8bd1fcdddda05a,
+        rtp_time: 0xaaf4edd5,
+        packet_count: 1,
+        octet_count: 2,
+        reports: (0..COUNT_MAX as u32)
+            .map(|i| ReceptionReport {
+                ssrc: 0xbc5e9a40 + i,
+                fraction_lost: i as u8,
+                total_lost: 0xFF_FF00 + i,
+                last_sequence_number: 0x46e1 + i,
+                jitter: 273 + i,
+                last_sender_report: 0x9f36432 + i,
+                delay: 150137 + i,
+            })
+            .collect(),
+        profile_extensions: Bytes::from_static(&[0x81, 0xca, 0x0]),
+    };
+
+    // encode field by field, one report block at a time:
+    let mut want = BytesMut::new();
+    want.extend_from_slice(&sr.header().marshal().unwrap());
+    want.put_u32(sr.ssrc);
+    want.put_u64(sr.ntp_time);
+    want.put_u32(sr.rtp_time);
+    want.put_u32(sr.packet_count);
+    want.put_u32(sr.octet_count);
+    for report in &sr.reports {
+        want.extend_from_slice(&report.marshal().unwrap());
+    }
+    want.extend_from_slice(&sr.profile_extensions);
+    want.put_u8(1);
+
+    let got = sr.marshal().expect("Marshal sr");
+
+    assert_eq!(got, want.freeze());
+}
+
+#[test]
+fn test_sender_report_unmarshal_length_exceeds_buffer() {
+    let mut data = Bytes::from_static(&[
+        0x80, 0xc8, 0xff, 0xff, // v=2, p=0, count=0, SR, len=65535
+        0x90, 0x2f, 0x9e, 0x2e, // ssrc=0x902f9e2e
+        0xda, 0x8b, 0xd1, 0xfc, // ntp=0xda8bd1fcdddda05a
+        0xdd, 0xdd, 0xa0, 0x5a, //
+        0xaa, 0xf4, 0xed, 0xd5, // rtp=0xaaf4edd5
+        0x00, 0x00, 0x00, 0x01, // packetCount=1
+        0x00, 0x00, 0x00, 0x02, // octetCount=2
+    ]);
+
+    let got = SenderReport::unmarshal(&mut data);
+
+    assert_eq!(Error::PacketTooShort, got.unwrap_err());
+}
+
+#[test]
+fn test_sender_report_rates_since() {
+    let prev = SenderReport {
+        ssrc: 0x902f9e2e,
+        ntp_time: 0xda8bd1fc_00000000,
+        packet_count: 100,
+        octet_count: 100_000,
+        ..Default::default()
+    };
+    // one second later
+    let sr = SenderReport {
+        ntp_time: 0xda8bd1fd_00000000,
+        packet_count: 150,
+        octet_count: 162_500,
+        ..prev.clone()
+    };
+
+    assert_eq!(sr.bitrate_since(&prev), Some(500_000.0));
+    assert_eq!(sr.packet_rate_since(&prev), Some(50.0));
+
+    // half a second later
+    let sr = SenderReport {
+        ntp_time: 0xda8bd1fc_80000000,
+        ..sr
+    };
+
+    assert_eq!(sr.bitrate_since(&prev), Some(1_000_000.0));
+    assert_eq!(sr.packet_rate_since(&prev), Some(100.0));
+
+    // zero interval
+    assert_eq!(prev.bitrate_since(&prev), None);
+    assert_eq!(prev.packet_rate_since(&prev), None);
+
+    // out of order
+    assert_eq!(prev.bitrate_since(&sr), None);
+
+    // wraparound
+    let wrapped = SenderReport {
+        ntp_time: 0xda8bd1fd_00000000,
+        packet_count: 10,
+        octet_count: 1_000,
+        ..prev.clone()
+    };
+    assert_eq!(wrapped.bitrate_since(&prev), None);
+    assert_eq!(wrapped.packet_rate_since(&prev), None);
+
+    // different sender
+    let other = SenderReport {
+        ssrc: 0x4bc4fcb4,
+        ..sr
+    };
+    assert_eq!(other.bitrate_since(&prev), None);
+}
+
+#[test]
+fn test_sender_report_with_reports() -> Result<()> {
+    let report = |ssrc| ReceptionReport {
+        ssrc,
+        fraction_lost: 0,
+        total_lost: 0,
+        last_sequence_number: 0x46e1,
+        jitter: 273,
+        last_sender_report: 0x9f36432,
+        delay: 150137,
+    };
+
+    let mut sr = SenderReport::with_reports(
+        0x902f9e2e,
+        0xda8bd1fcdddda05a,
+        0xaaf4edd5,
+        1,
+        2,
+        vec![report(0xbc5e9a40)],
+    )?;
+    sr.push_report(report(0xbc5e9a41))?;
+
+    let header = sr.header();
+    assert_eq!(header.count, 2);
+    assert_eq!(header.packet_type, PacketType::SenderReport);
+
+    let data = sr.marshal()?;
+    let decoded = SenderReport::unmarshal(&mut data.clone())?;
+    assert_eq!(decoded, sr);
+    assert_eq!(decoded.header(), header);
+
+    let result = SenderReport::with_reports(0, 0, 0, 0, 0, vec![report(0); COUNT_MAX + 1]);
+    assert_eq!(Error::TooManyReports, result.unwrap_err());
+
+    let mut sr = SenderReport::with_reports(0, 0, 0, 0, 0, vec![report(0); COUNT_MAX])?;
+    let result = sr.push_report(report(1));
+    assert_eq!(Error::TooManyReports, result.unwrap_err());
+    assert_eq!(sr.reports.len(), COUNT_MAX);
+
+    Ok(())
+}
+
+#[test]
+fn test_sender_report_debug() {
+    let sr = SenderReport {
+        ssrc: 0x902f9e2e,
+        profile_extensions: Bytes::from_static(&[
+            0xde, 0xad, 0xbe, 0xef, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+        ]),
+        ..Default::default()
+    };
+
+    let debug = format!("{sr:?}");
+    assert!(
+        debug.starts_with("SenderReport { ssrc: 2419039790,"),
+        "{debug}"
+    );
+    assert!(
+        debug.contains("profile_extensions: 12 bytes [de ad be ef 00 01 02 03 …]"),
+        "{debug}"
+    );
+}
@@ -2,6 +2,7 @@
 mod source_description_test;
 
 use std::any::Any;
+use std::collections::HashMap;
 use std::fmt;
 
 use bytes::{Buf, BufMut, Bytes};
@@ -21,22 +22,26 @@ const SDES_OCTET_COUNT_LEN: usize = 1;
 const SDES_OCTET_COUNT_OFFSET: usize = 1;
 const SDES_MAX_OCTET_COUNT: usize = (1 << 8) - 1;
 const SDES_TEXT_OFFSET: usize = 2;
+const SDES_PRIV_PREFIX_LEN_LEN: usize = 1;
 
 /// SDESType is the item type used in the RTCP SDES control packet.
 /// RTP SDES item types registered with IANA. See: https://www.iana.org/assignments/rtp-parameters/rtp-parameters.xhtml#rtp-parameters-5
+///
+/// Item types this crate doesn't know about are kept as `SdesUnknown` so that they survive
+/// an unmarshal/marshal round trip.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
-#[repr(u8)]
 pub enum SdesType {
     #[default]
-    SdesEnd = 0, // end of SDES list                RFC 3550, 6.5
-    SdesCname = 1,    // canonical name                  RFC 3550, 6.5.1
-    SdesName = 2,     // user name                       RFC 3550, 6.5.2
-    SdesEmail = 3,    // user's electronic mail address  RFC 3550, 6.5.3
-    SdesPhone = 4,    // user's phone number             RFC 3550, 6.5.4
-    SdesLocation = 5, // geographic user location        RFC 3550, 6.5.5
-    SdesTool = 6,     // name of application or tool     RFC 3550, 6.5.6
-    SdesNote = 7,     // notice about the source         RFC 3550, 6.5.7
-    SdesPrivate = 8,  // private extensions              RFC 3550, 6.5.8  (not implemented)
+    SdesEnd, // end of SDES list                RFC 3550, 6.5
+    SdesCname,       // canonical name                  RFC 3550, 6.5.1
+    SdesName,        // user name                       RFC 3550, 6.5.2
+    SdesEmail,       // user's electronic mail address  RFC 3550, 6.5.3
+    SdesPhone,       // user's phone number             RFC 3550, 6.5.4
+    SdesLocation,    // geographic user location        RFC 3550, 6.5.5
+    SdesTool,        // name of application or tool     RFC 3550, 6.5.6
+    SdesNote,        // notice about the source         RFC 3550, 6.5.7
+    SdesPrivate,     // private extensions              RFC 3550, 6.5.8
+    SdesUnknown(u8), // any other item type, preserved as is (known types fail to marshal)
 }
 
 impl fmt::Display for SdesType {
@@ -51,6 +56,7 @@ impl fmt::Display for SdesType {
             SdesType::SdesTool => "TOOL",
             SdesType::SdesNote => "NOTE",
             SdesType::SdesPrivate => "PRIV",
+            SdesType::SdesUnknown(t) => return write!(f, "UNKNOWN({t})"),
         };
         write!(f, "{s}")
     }
@@ -67,7 +73,25 @@ impl From<u8> for SdesType {
             6 => SdesType::SdesTool,
             7 => SdesType::SdesNote,
             8 => SdesType::SdesPrivate,
-            _ => SdesType::SdesEnd,
+            0 => SdesType::SdesEnd,
+            t => SdesType::SdesUnknown(t),
+        }
+    }
+}
+
+impl From<SdesType> for u8 {
+    fn from(t: SdesType) -> Self {
+        match t {
+            SdesType::SdesEnd => 0,
+            SdesType::SdesCname => 1,
+            SdesType::SdesName => 2,
+            SdesType::SdesEmail => 3,
+            SdesType::SdesPhone => 4,
+            SdesType::SdesLocation => 5,
+            SdesType::SdesTool => 6,
+            SdesType::SdesNote => 7,
+            SdesType::SdesPrivate => 8,
+            SdesType::SdesUnknown(t) => t,
         }
     }
 }
@@ -89,6 +113,14 @@ impl SourceDescriptionChunk {
         len += SDES_TYPE_LEN; // for terminating null octet
         len
     }
+
+    /// Returns the text of the first CNAME item of this chunk, if it is valid UTF-8.
+    pub(crate) fn cname(&self) -> Option<&str> {
+        self.items
+            .iter()
+            .find(|item| item.sdes_type == SdesType::SdesCname)
+            .and_then(SourceDescriptionItem::value_str)
+    }
 }
 
 impl MarshalSize for SourceDescriptionChunk {
@@ -122,7 +154,7 @@ impl Marshal for SourceDescriptionChunk {
         }
 
         // The list of items in each chunk MUST be terminated by one or more null octets
-        buf.put_u8(SdesType::SdesEnd as u8);
+        buf.put_u8(SdesType::SdesEnd.into());
 
         // additional null octets MUST be included if needed to pad until the next 32-bit boundary
         put_padding(buf, self.raw_size());
@@ -175,16 +207,102 @@ impl Unmarshal for SourceDescriptionChunk {
 }
 
 /// A SourceDescriptionItem is a part of a SourceDescription that describes a stream.
-#[derive(Debug, PartialEq, Eq, Default, Clone)]
+#[derive(PartialEq, Eq, Default, Clone)]
 pub struct SourceDescriptionItem {
     /// The type identifier for this item. eg, SDESCNAME for canonical name description.
     ///
     /// Type zero or SDESEnd is interpreted as the end of an item list and cannot be used.
     pub sdes_type: SdesType,
     /// Text is a unicode text blob associated with the item. Its meaning varies based on the item's Type.
+    ///
+    /// For PRIV items this holds the whole `prefix length || prefix || value` payload,
+    /// see [`SourceDescriptionItem::private`].
     pub text: Bytes,
 }
 
+impl fmt::Debug for SourceDescriptionItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("SourceDescriptionItem");
+        debug.field("sdes_type", &self.sdes_type);
+        // PRIV items carry binary data (their prefix length), unlike the textual other items:
+        if self.sdes_type == SdesType::SdesPrivate {
+            debug.field("text", &HexBytes(&self.text));
+        } else {
+            debug.field("text", &self.text);
+        }
+        debug.finish()
+    }
+}
+
+impl SourceDescriptionItem {
+    /// Returns the raw text of this item, which is usually, but not necessarily, valid UTF-8.
+    pub fn value_bytes(&self) -> &Bytes {
+        &self.text
+    }
+
+    /// Returns the text of this item, or `None` if it is not valid UTF-8.
+    pub fn value_str(&self) -> Option<&str> {
+        std::str::from_utf8(&self.text).ok()
+    }
+
+    /// Creates a PRIV item, laying out the prefix and value as described in RFC 3550, 6.5.8:
+    ///
+    /// ```text
+    ///   0                   1                   2                   3
+    ///   0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+    ///  +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+    ///  |     PRIV=8    |     length    | prefix length |prefix string...
+    ///  +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+    ///  ...             |                  value string               ...
+    ///  +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+    /// ```
+    pub fn private(prefix: &[u8], value: &[u8]) -> Result<Self> {
+        if SDES_PRIV_PREFIX_LEN_LEN + prefix.len() + value.len() > SDES_MAX_OCTET_COUNT {
+            return Err(Error::SdesTextTooLong.into());
+        }
+
+        let mut text = Vec::with_capacity(SDES_PRIV_PREFIX_LEN_LEN + prefix.len() + value.len());
+        text.push(prefix.len() as u8);
+        text.extend_from_slice(prefix);
+        text.extend_from_slice(value);
+
+        Ok(SourceDescriptionItem {
+            sdes_type: SdesType::SdesPrivate,
+            text: Bytes::from(text),
+        })
+    }
+
+    /// Returns the prefix of a PRIV item, or `None` for any other item type
+    /// or a malformed PRIV payload.
+    pub fn private_prefix(&self) -> Option<Bytes> {
+        let prefix_len = self.private_prefix_len()?;
+        Some(
+            self.text
+                .slice(SDES_PRIV_PREFIX_LEN_LEN..SDES_PRIV_PREFIX_LEN_LEN + prefix_len),
+        )
+    }
+
+    /// Returns the value of a PRIV item, or `None` for any other item type
+    /// or a malformed PRIV payload.
+    pub fn private_value(&self) -> Option<Bytes> {
+        let prefix_len = self.private_prefix_len()?;
+        Some(self.text.slice(SDES_PRIV_PREFIX_LEN_LEN + prefix_len..))
+    }
+
+    fn private_prefix_len(&self) -> Option<usize> {
+        if self.sdes_type != SdesType::SdesPrivate || self.text.is_empty() {
+            return None;
+        }
+
+        let prefix_len = self.text[0] as usize;
+        if SDES_PRIV_PREFIX_LEN_LEN + prefix_len > self.text.len() {
+            return None;
+        }
+
+        Some(prefix_len)
+    }
+}
+
 impl MarshalSize for SourceDescriptionItem {
     fn marshal_size(&self) -> usize {
         /*
@@ -217,11 +335,22 @@ impl Marshal for SourceDescriptionItem {
             return Err(Error::BufferTooShort.into());
         }
 
-        buf.put_u8(self.sdes_type as u8);
-
         if self.text.len() > SDES_MAX_OCTET_COUNT {
             return Err(Error::SdesTextTooLong.into());
         }
+
+        if self.sdes_type == SdesType::SdesPrivate && self.private_prefix_len().is_none() {
+            return Err(Error::SdesInvalidPrivatePrefix.into());
+        }
+
+        // a hand-built `SdesUnknown` of a known type would unmarshal as a different variant
+        if let SdesType::SdesUnknown(t) = self.sdes_type {
+            if SdesType::from(t) != self.sdes_type {
+                return Err(Error::SdesInvalidUnknownType.into());
+            }
+        }
+
+        buf.put_u8(self.sdes_type.into());
         buf.put_u8(self.text.len() as u8);
         buf.put(self.text.clone());
 
@@ -267,8 +396,13 @@ impl Unmarshal for SourceDescriptionItem {
         }
 
         let text = raw_packet.copy_to_bytes(octet_count);
+        let item = SourceDescriptionItem { sdes_type, text };
+
+        if sdes_type == SdesType::SdesPrivate && item.private_prefix_len().is_none() {
+            return Err(Error::SdesInvalidPrivatePrefix.into());
+        }
 
-        Ok(SourceDescriptionItem { sdes_type, text })
+        Ok(item)
     }
 }
 
@@ -291,6 +425,29 @@ impl fmt::Display for SourceDescription {
     }
 }
 
+impl SourceDescription {
+    /// cname returns the text of the CNAME item describing the source `ssrc`,
+    /// or `None` if there is no such item, or its text is not valid UTF-8.
+    pub fn cname(&self, ssrc: u32) -> Option<&str> {
+        self.chunks
+            .iter()
+            .filter(|chunk| chunk.source == ssrc)
+            .find_map(SourceDescriptionChunk::cname)
+    }
+
+    /// all_cnames returns the text of the CNAME items of all sources described by this packet,
+    /// omitting sources without a CNAME item, or whose CNAME is not valid UTF-8.
+    pub fn all_cnames(&self) -> HashMap<u32, &str> {
+        let mut cnames = HashMap::new();
+        for chunk in &self.chunks {
+            if let Some(cname) = chunk.cname() {
+                cnames.entry(chunk.source).or_insert(cname);
+            }
+        }
+        cnames
+    }
+}
+
 impl Packet for SourceDescription {
     /// Header returns the Header associated with this packet.
     fn header(&self) -> Header {
@@ -307,6 +464,10 @@ impl Packet for SourceDescription {
         self.chunks.iter().map(|x| x.source).collect()
     }
 
+    fn destination_ssrc_into(&self, out: &mut Vec<u32>) {
+        out.extend(self.chunks.iter().map(|x| x.source));
+    }
+
     fn raw_size(&self) -> usize {
         let mut chunks_length = 0;
         for c in &self.chunks {
@@ -413,6 +574,8 @@ impl Unmarshal for SourceDescription {
         let raw_packet_len = raw_packet.remaining();
 
         let h = Header::unmarshal(raw_packet)?;
+
+        h.check_packet_size(raw_packet_len)?;
         if h.packet_type != PacketType::SourceDescription {
             return Err(Error::WrongType.into());
         }
@@ -12,8 +12,8 @@ fn test_source_description_unmarshal() {
         (
             "no chunks",
             Bytes::from_static(&[
-                // v=2, p=0, count=1, SDES, len=8
-                0x80, 0xca, 0x00, 0x04,
+                // v=2, p=0, count=1, SDES, len=0
+                0x80, 0xca, 0x00, 0x00,
             ]),
             SourceDescription::default(),
             None,
@@ -21,8 +21,8 @@ fn test_source_description_unmarshal() {
         (
             "missing type",
             Bytes::from_static(&[
-                // v=2, p=0, count=1, SDES, len=8
-                0x81, 0xca, 0x00, 0x08, // ssrc=0x00000000
+                // v=2, p=0, count=1, SDES, len=1
+                0x81, 0xca, 0x00, 0x01, // ssrc=0x00000000
                 0x00, 0x00, 0x00, 0x00,
             ]),
             SourceDescription::default(),
@@ -31,8 +31,8 @@ fn test_source_description_unmarshal() {
         (
             "bad cname length",
             Bytes::from_static(&[
-                // v=2, p=0, count=1, SDES, len=10
-                0x81, 0xca, 0x00, 0x0a, // ssrc=0x00000000
+                // v=2, p=0, count=1, SDES, len=2
+                0x81, 0xca, 0x00, 0x02, // ssrc=0x00000000
                 0x00, 0x00, 0x00, 0x00, // CNAME, len = 1
                 0x01, 0x01,
             ]),
@@ -42,8 +42,8 @@ fn test_source_description_unmarshal() {
         (
             "short cname",
             Bytes::from_static(&[
-                // v=2, p=0, count=1, SDES, len=9
-                0x81, 0xca, 0x00, 0x09, // ssrc=0x00000000
+                // v=2, p=0, count=1, SDES, len=2
+                0x81, 0xca, 0x00, 0x02, // ssrc=0x00000000
                 0x00, 0x00, 0x00, 0x00, // CNAME, Missing length
                 0x01,
             ]),
@@ -53,8 +53,8 @@ fn test_source_description_unmarshal() {
         (
             "no end",
             Bytes::from_static(&[
-                // v=2, p=0, count=1, SDES, len=11
-                0x81, 0xca, 0x00, 0x0b, // ssrc=0x00000000
+                // v=2, p=0, count=1, SDES, len=2
+                0x81, 0xca, 0x00, 0x02, // ssrc=0x00000000
                 0x00, 0x00, 0x00, 0x00, // CNAME, len=1, content=A
                 0x01, 0x02, 0x41,
                 // Missing END
@@ -65,8 +65,8 @@ fn test_source_description_unmarshal() {
         (
             "bad octet count",
             Bytes::from_static(&[
-                // v=2, p=0, count=1, SDES, len=10
-                0x81, 0xca, 0x00, 0x0a, // ssrc=0x00000000
+                // v=2, p=0, count=1, SDES, len=2
+                0x81, 0xca, 0x00, 0x02, // ssrc=0x00000000
                 0x00, 0x00, 0x00, 0x00, // CNAME, len=1
                 0x01, 0x01,
             ]),
@@ -76,8 +76,8 @@ fn test_source_description_unmarshal() {
         (
             "zero item chunk",
             Bytes::from_static(&[
-                // v=2, p=0, count=1, SDES, len=12
-                0x81, 0xca, 0x00, 0x0c, // ssrc=0x01020304
+                // v=2, p=0, count=1, SDES, len=2
+                0x81, 0xca, 0x00, 0x02, // ssrc=0x01020304
                 0x01, 0x02, 0x03, 0x04, // END + padding
                 0x00, 0x00, 0x00, 0x00,
             ]),
@@ -92,8 +92,8 @@ fn test_source_description_unmarshal() {
         (
             "wrong type",
             Bytes::from_static(&[
-                // v=2, p=0, count=1, SR, len=12
-                0x81, 0xc8, 0x00, 0x0c, // ssrc=0x01020304
+                // v=2, p=0, count=1, SR, len=2
+                0x81, 0xc8, 0x00, 0x02, // ssrc=0x01020304
                 0x01, 0x02, 0x03, 0x04, // END + padding
                 0x00, 0x00, 0x00, 0x00,
             ]),
@@ -103,8 +103,8 @@ fn test_source_description_unmarshal() {
         (
             "bad count in header",
             Bytes::from_static(&[
-                // v=2, p=0, count=1, SDES, len=12
-                0x81, 0xca, 0x00, 0x0c,
+                // v=2, p=0, count=1, SDES, len=0
+                0x81, 0xca, 0x00, 0x00,
             ]),
             SourceDescription::default(),
             Some(Error::InvalidHeader),
@@ -112,8 +112,8 @@ fn test_source_description_unmarshal() {
         (
             "empty string",
             Bytes::from_static(&[
-                // v=2, p=0, count=1, SDES, len=12
-                0x81, 0xca, 0x00, 0x0c, // ssrc=0x01020304
+                // v=2, p=0, count=1, SDES, len=2
+                0x81, 0xca, 0x00, 0x02, // ssrc=0x01020304
                 0x01, 0x02, 0x03, 0x04, // CNAME, len=0
                 0x01, 0x00, // END + padding
                 0x00, 0x00,
@@ -132,8 +132,8 @@ fn test_source_description_unmarshal() {
         (
             "two items",
             Bytes::from_static(&[
-                // v=2, p=0, count=1, SDES, len=16
-                0x81, 0xca, 0x00, 0x10, // ssrc=0x10000000
+                // v=2, p=0, count=1, SDES, len=3
+                0x81, 0xca, 0x00, 0x03, // ssrc=0x10000000
                 0x10, 0x00, 0x00, 0x00, // CNAME, len=1, content=A
                 0x01, 0x01, 0x41, // PHONE, len=1, content=B
                 0x04, 0x01, 0x42, // END + padding
@@ -159,8 +159,8 @@ fn test_source_description_unmarshal() {
         (
             "two chunks",
             Bytes::from_static(&[
-                // v=2, p=0, count=2, SDES, len=24
-                0x82, 0xca, 0x00, 0x18, // ssrc=0x01020304
+                // v=2, p=0, count=2, SDES, len=5
+                0x82, 0xca, 0x00, 0x05, // ssrc=0x01020304
                 0x01, 0x02, 0x03, 0x04,
                 // Chunk 1
                 // CNAME, len=1, content=A
@@ -191,6 +191,38 @@ fn test_source_description_unmarshal() {
             },
             None,
         ),
+        (
+            "unknown item type",
+            Bytes::from_static(&[
+                // v=2, p=0, count=1, SDES, len=3
+                0x81, 0xca, 0x00, 0x03, // ssrc=0x01020304
+                0x01, 0x02, 0x03, 0x04, // type=10, len=2, content=xy
+                0x0a, 0x02, 0x78, 0x79, // END + padding
+                0x00, 0x00, 0x00, 0x00,
+            ]),
+            SourceDescription {
+                chunks: vec![SourceDescriptionChunk {
+                    source: 0x01020304,
+                    items: vec![SourceDescriptionItem {
+                        sdes_type: SdesType::SdesUnknown(10),
+                        text: Bytes::from_static(b"xy"),
+                    }],
+                }],
+            },
+            None,
+        ),
+        (
+            "private item with bad prefix length",
+            Bytes::from_static(&[
+                // v=2, p=0, count=1, SDES, len=3
+                0x81, 0xca, 0x00, 0x03, // ssrc=0x01020304
+                0x01, 0x02, 0x03, 0x04, // PRIV, len=2, prefix len=5, content=A
+                0x08, 0x02, 0x05, 0x41, // END + padding
+                0x00, 0x00, 0x00, 0x00,
+            ]),
+            SourceDescription::default(),
+            Some(Error::SdesInvalidPrivatePrefix),
+        ),
     ];
 
     for (name, mut data, want, want_error) in tests {
@@ -272,6 +304,68 @@ fn test_source_description_roundtrip() {
             },
             Some(Error::SdesMissingType),
         ),
+        (
+            "tool item",
+            SourceDescription {
+                chunks: vec![SourceDescriptionChunk {
+                    source: 1,
+                    items: vec![SourceDescriptionItem {
+                        sdes_type: SdesType::SdesTool,
+                        text: Bytes::from_static(b"webrtc-rs"),
+                    }],
+                }],
+            },
+            None,
+        ),
+        (
+            "private item",
+            SourceDescription {
+                chunks: vec![SourceDescriptionChunk {
+                    source: 1,
+                    items: vec![SourceDescriptionItem::private(b"x-app", b"some value").unwrap()],
+                }],
+            },
+            None,
+        ),
+        (
+            "private item without prefix length",
+            SourceDescription {
+                chunks: vec![SourceDescriptionChunk {
+                    source: 1,
+                    items: vec![SourceDescriptionItem {
+                        sdes_type: SdesType::SdesPrivate,
+                        text: Bytes::new(),
+                    }],
+                }],
+            },
+            Some(Error::SdesInvalidPrivatePrefix),
+        ),
+        (
+            "unknown item",
+            SourceDescription {
+                chunks: vec![SourceDescriptionChunk {
+                    source: 1,
+                    items: vec![SourceDescriptionItem {
+                        sdes_type: SdesType::SdesUnknown(42),
+                        text: Bytes::from_static(b"opaque"),
+                    }],
+                }],
+            },
+            None,
+        ),
+        (
+            "unknown item of known type",
+            SourceDescription {
+                chunks: vec![SourceDescriptionChunk {
+                    source: 1,
+                    items: vec![SourceDescriptionItem {
+                        sdes_type: SdesType::SdesUnknown(1),
+                        text: Bytes::from_static(b"cname"),
+                    }],
+                }],
+            },
+            Some(Error::SdesInvalidUnknownType),
+        ),
         (
             "zero items",
             SourceDescription {
@@ -357,3 +451,152 @@ fn test_source_description_roundtrip() {
         }
     }
 }
+
+#[test]
+fn test_source_description_private_item() -> Result<()> {
+    let item = SourceDescriptionItem::private(b"x-app", b"some value")?;
+    assert_eq!(item.sdes_type, SdesType::SdesPrivate);
+    assert_eq!(item.text, Bytes::from_static(b"\x05x-appsome value"));
+    assert_eq!(item.private_prefix(), Some(Bytes::from_static(b"x-app")));
+    assert_eq!(
+        item.private_value(),
+        Some(Bytes::from_static(b"some value"))
+    );
+
+    let data = item.marshal()?;
+    let got = SourceDescriptionItem::unmarshal(&mut data.clone())?;
+    assert_eq!(got, item);
+
+    let not_private = SourceDescriptionItem {
+        sdes_type: SdesType::SdesTool,
+        text: Bytes::from_static(b"\x05x-appsome value"),
+    };
+    assert_eq!(not_private.private_prefix(), None);
+    assert_eq!(not_private.private_value(), None);
+
+    let too_long = vec![0u8; SDES_MAX_OCTET_COUNT];
+    assert_eq!(
+        Error::SdesTextTooLong,
+        SourceDescriptionItem::private(b"x", &too_long).unwrap_err()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_source_description_item_debug() -> Result<()> {
+    let item = SourceDescriptionItem::private(b"x-app", b"some value")?;
+    assert_eq!(
+        format!("{item:?}"),
+        "SourceDescriptionItem { sdes_type: SdesPrivate, text: 16 bytes [05 78 2d 61 70 70 73 6f …] }"
+    );
+
+    // Textual items keep their text readable:
+    let item = SourceDescriptionItem {
+        sdes_type: SdesType::SdesCname,
+        text: Bytes::from_static(b"cname"),
+    };
+    assert_eq!(
+        format!("{item:?}"),
+        "SourceDescriptionItem { sdes_type: SdesCname, text: b\"cname\" }"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_source_description_cname() {
+    let sd = SourceDescription {
+        chunks: vec![
+            SourceDescriptionChunk {
+                source: 0x01020304,
+                items: vec![
+                    SourceDescriptionItem {
+                        sdes_type: SdesType::SdesName,
+                        text: Bytes::from_static(b"name"),
+                    },
+                    SourceDescriptionItem {
+                        sdes_type: SdesType::SdesCname,
+                        text: Bytes::from_static(b"first@example.com"),
+                    },
+                ],
+            },
+            SourceDescriptionChunk {
+                source: 0x05060708,
+                items: vec![SourceDescriptionItem {
+                    sdes_type: SdesType::SdesCname,
+                    text: Bytes::from_static(b"second@example.com"),
+                }],
+            },
+            SourceDescriptionChunk {
+                source: 0x090a0b0c,
+                items: vec![SourceDescriptionItem {
+                    sdes_type: SdesType::SdesTool,
+                    text: Bytes::from_static(b"tool"),
+                }],
+            },
+        ],
+    };
+
+    assert_eq!(sd.cname(0x01020304), Some("first@example.com"));
+    assert_eq!(sd.cname(0x05060708), Some("second@example.com"));
+    assert_eq!(sd.cname(0x090a0b0c), None, "chunk without CNAME");
+    assert_eq!(sd.cname(0xdeadbeef), None, "unknown SSRC");
+
+    let expected = HashMap::from([
+        (0x01020304, "first@example.com"),
+        (0x05060708, "second@example.com"),
+    ]);
+    assert_eq!(sd.all_cnames(), expected);
+}
+
+#[test]
+fn test_source_description_invalid_utf8() -> Result<()> {
+    let mut data = Bytes::from_static(&[
+        0x81, 0xca, 0x00, 0x03, // v=2, p=0, count=1, SDES, len=3
+        0x10, 0x00, 0x00, 0x00, // ssrc=0x10000000
+        0x01, 0x04, // CNAME, len=4
+        0x63, 0xff, 0x6e, 0xfe, // text="c\xffn\xfe"
+        0x00, 0x00, // END + padding
+    ]);
+
+    let sd = SourceDescription::unmarshal(&mut data)?;
+    let item = &sd.chunks[0].items[0];
+    assert_eq!(item.sdes_type, SdesType::SdesCname);
+    assert_eq!(
+        item.value_bytes(),
+        &Bytes::from_static(&[0x63, 0xff, 0x6e, 0xfe])
+    );
+    assert_eq!(item.value_str(), None);
+    assert_eq!(sd.cname(0x10000000), None);
+
+    let valid = SourceDescriptionItem {
+        sdes_type: SdesType::SdesCname,
+        text: Bytes::from_static("cnäme".as_bytes()),
+    };
+    assert_eq!(valid.value_str(), Some("cnäme"));
+
+    Ok(())
+}
+
+#[test]
+fn test_sdes_type_conversion() {
+    for b in 0..=u8::MAX {
+        assert_eq!(u8::from(SdesType::from(b)), b);
+    }
+    assert_eq!(SdesType::from(9), SdesType::SdesUnknown(9));
+    assert_eq!(SdesType::SdesUnknown(9).to_string(), "UNKNOWN(9)");
+}
+
+#[test]
+fn test_source_description_unmarshal_length_exceeds_buffer() {
+    let mut data = Bytes::from_static(&[
+        0x81, 0xca, 0xff, 0xff, // v=2, p=0, count=1, SDES, len=65535
+        0x01, 0x02, 0x03, 0x04, // ssrc=0x01020304
+        0x01, 0x01, 0x41, 0x00, // CNAME, len=1, content=A, END
+    ]);
+
+    let got = SourceDescription::unmarshal(&mut data);
+
+    assert_eq!(Error::PacketTooShort, got.unwrap_err());
+}
@@ -0,0 +1,156 @@
+use bytes::Bytes;
+
+use super::*;
+
+#[test]
+fn test_ecn_feedback_unmarshal() {
+    let tests = vec![
+        (
+            "valid",
+            Bytes::from_static(&[
+                0x88, 0xcd, 0x0, 0x7, // EcnFeedback, len=7
+                0x90, 0x2f, 0x9e, 0x2e, // sender=0x902f9e2e
+                0xbc, 0x5e, 0x9a, 0x40, // media=0xbc5e9a40
+                0x0, 0x1, 0x46, 0xe1, // extended highest seq=0x146e1
+                0x0, 0x0, 0x3, 0xe8, // ect0=1000
+                0x0, 0x0, 0x0, 0x2, // ect1=2
+                0x0, 0x5, 0x0, 0x7, // ce=5, not-ect=7
+                0x0, 0x3, 0x0, 0x1, // lost=3, dup=1
+            ]),
+            EcnFeedback {
+                sender_ssrc: 0x902f9e2e,
+                media_ssrc: 0xbc5e9a40,
+                extended_highest_seq: 0x146e1,
+                ect0_counter: 1000,
+                ect1_counter: 2,
+                ecn_ce_counter: 5,
+                not_ect_counter: 7,
+                lost_packets: 3,
+                duplication_counter: 1,
+            },
+            None,
+        ),
+        (
+            "short report",
+            Bytes::from_static(&[
+                0x88, 0xcd, 0x0, 0x7, // EcnFeedback, len=7
+                0x90, 0x2f, 0x9e, 0x2e, // sender=0x902f9e2e
+                0xbc, 0x5e, 0x9a,
+                0x40, // media=0xbc5e9a40
+                      // report ends early
+            ]),
+            EcnFeedback::default(),
+            Some(Error::PacketTooShort),
+        ),
+        (
+            "wrong type",
+            Bytes::from_static(&[
+                0x85, 0xcd, 0x0, 0x7, // RapidResynchronizationRequest FMT, len=7
+                0x90, 0x2f, 0x9e, 0x2e, //
+                0xbc, 0x5e, 0x9a, 0x40, //
+                0x0, 0x1, 0x46, 0xe1, //
+                0x0, 0x0, 0x3, 0xe8, //
+                0x0, 0x0, 0x0, 0x2, //
+                0x0, 0x5, 0x0, 0x7, //
+                0x0, 0x3, 0x0, 0x1, //
+            ]),
+            EcnFeedback::default(),
+            Some(Error::WrongType),
+        ),
+        (
+            "nil",
+            Bytes::from_static(&[]),
+            EcnFeedback::default(),
+            Some(Error::PacketTooShort),
+        ),
+    ];
+
+    for (name, mut data, want, want_error) in tests {
+        let got = EcnFeedback::unmarshal(&mut data);
+
+        assert_eq!(
+            got.is_err(),
+            want_error.is_some(),
+            "Unmarshal {name} ecn: err = {got:?}, want {want_error:?}"
+        );
+
+        if let Some(err) = want_error {
+            let got_err = got.err().unwrap();
+            assert_eq!(
+                err, got_err,
+                "Unmarshal {name} ecn: err = {got_err:?}, want {err:?}",
+            );
+        } else {
+            let actual = got.unwrap();
+            assert_eq!(
+                actual, want,
+                "Unmarshal {name} ecn: got {actual:?}, want {want:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_ecn_feedback_roundtrip() {
+    let tests: Vec<(&str, EcnFeedback, Option<Error>)> = vec![
+        (
+            "valid",
+            EcnFeedback {
+                sender_ssrc: 0x902f9e2e,
+                media_ssrc: 0xbc5e9a40,
+                extended_highest_seq: 0x0002_ffff,
+                ect0_counter: 123_456,
+                ect1_counter: 0,
+                ecn_ce_counter: 42,
+                not_ect_counter: 3,
+                lost_packets: 17,
+                duplication_counter: 2,
+            },
+            None,
+        ),
+        (
+            "saturated counters",
+            EcnFeedback {
+                sender_ssrc: 1,
+                media_ssrc: 2,
+                extended_highest_seq: u32::MAX,
+                ect0_counter: u32::MAX,
+                ect1_counter: u32::MAX,
+                ecn_ce_counter: u16::MAX,
+                not_ect_counter: u16::MAX,
+                lost_packets: u16::MAX,
+                duplication_counter: u16::MAX,
+            },
+            None,
+        ),
+    ];
+
+    for (name, want, want_error) in tests {
+        let got = want.marshal();
+
+        assert_eq!(
+            got.is_ok(),
+            want_error.is_none(),
+            "Marshal {name}: err = {got:?}, want {want_error:?}"
+        );
+
+        if let Some(err) = want_error {
+            let got_err = got.err().unwrap();
+            assert_eq!(
+                err, got_err,
+                "Unmarshal {name} ecn: err = {got_err:?}, want {err:?}",
+            );
+        } else {
+            let mut data = got.ok().unwrap();
+            assert_eq!(data.len(), 32, "{name}: marshal size");
+
+            let actual =
+                EcnFeedback::unmarshal(&mut data).unwrap_or_else(|_| panic!("Unmarshal {name}"));
+
+            assert_eq!(
+                actual, want,
+                "{name} round trip: got {actual:?}, want {want:?}"
+            )
+        }
+    }
+}
@@ -0,0 +1,194 @@
+#[cfg(test)]
+mod ecn_feedback_test;
+
+use std::any::Any;
+use std::fmt;
+
+use bytes::{Buf, BufMut};
+use util::marshal::{Marshal, MarshalSize, Unmarshal};
+
+use crate::error::Error;
+use crate::feedback::*;
+use crate::header::*;
+use crate::packet::*;
+use crate::util::*;
+
+type Result<T> = std::result::Result<T, util::Error>;
+
+const ECN_FCI_LENGTH: usize = 20;
+
+/// The EcnFeedback packet reports the ECN markings of received RTP packets, as defined in RFC 6679
+///
+///  0                   1                   2                   3
+///  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |V=2|P| FMT=8   |   PT=205      |          length=7             |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |                  SSRC of packet sender                        |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |                  SSRC of media source                         |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |            Extended Highest Sequence Number                   |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |                        ECT (0) Counter                        |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |                        ECT (1) Counter                        |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |         ECN-CE Counter        |     not-ECT Counter           |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |     Lost Packets Counter      |     Duplication Counter       |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+#[derive(Debug, PartialEq, Eq, Default, Clone)]
+pub struct EcnFeedback {
+    /// SSRC of sender
+    pub sender_ssrc: u32,
+    /// SSRC of the media source
+    pub media_ssrc: u32,
+    /// The highest sequence number received, extended with the number of sequence number cycles
+    pub extended_highest_seq: u32,
+    /// The number of packets received with the ECT(0) mark
+    pub ect0_counter: u32,
+    /// The number of packets received with the ECT(1) mark
+    pub ect1_counter: u32,
+    /// The number of packets received with the ECN-CE mark
+    pub ecn_ce_counter: u16,
+    /// The number of packets received without an ECN mark
+    pub not_ect_counter: u16,
+    /// The number of packets considered lost
+    pub lost_packets: u16,
+    /// The number of duplicate packets received
+    pub duplication_counter: u16,
+}
+
+impl fmt::Display for EcnFeedback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "EcnFeedback {:x} {:x} seq={} ect0={} ect1={} ce={} not-ect={} lost={} dup={}",
+            self.sender_ssrc,
+            self.media_ssrc,
+            self.extended_highest_seq,
+            self.ect0_counter,
+            self.ect1_counter,
+            self.ecn_ce_counter,
+            self.not_ect_counter,
+            self.lost_packets,
+            self.duplication_counter
+        )
+    }
+}
+
+impl EcnFeedback {
+    fn feedback_header(&self) -> FeedbackHeader {
+        FeedbackHeader::new::<Self>(self.sender_ssrc, self.media_ssrc, self.raw_size())
+    }
+}
+
+impl FeedbackMessage for EcnFeedback {
+    const PACKET_TYPE: PacketType = PacketType::TransportSpecificFeedback;
+    const FORMAT: u8 = FORMAT_ECN;
+}
+
+impl Packet for EcnFeedback {
+    /// Header returns the Header associated with this packet.
+    fn header(&self) -> Header {
+        self.feedback_header().header
+    }
+
+    /// Destination SSRC returns an array of SSRC values that this packet refers to.
+    fn destination_ssrc(&self) -> Vec<u32> {
+        vec![self.media_ssrc]
+    }
+
+    fn raw_size(&self) -> usize {
+        FEEDBACK_HEADER_LENGTH + ECN_FCI_LENGTH
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + Sync) {
+        self
+    }
+
+    fn equal(&self, other: &(dyn Packet + Send + Sync)) -> bool {
+        other.as_any().downcast_ref::<EcnFeedback>() == Some(self)
+    }
+
+    fn cloned(&self) -> Box<dyn Packet + Send + Sync> {
+        Box::new(self.clone())
+    }
+}
+
+impl MarshalSize for EcnFeedback {
+    fn marshal_size(&self) -> usize {
+        let l = self.raw_size();
+        // align to 32-bit boundary
+        l + get_padding_size(l)
+    }
+}
+
+impl Marshal for EcnFeedback {
+    /// Marshal encodes the EcnFeedback in binary
+    fn marshal_to(&self, mut buf: &mut [u8]) -> Result<usize> {
+        if buf.remaining_mut() < self.marshal_size() {
+            return Err(Error::BufferTooShort.into());
+        }
+
+        let h = self.feedback_header();
+        let n = h.marshal_to(buf)?;
+        buf = &mut buf[n..];
+
+        buf.put_u32(self.extended_highest_seq);
+        buf.put_u32(self.ect0_counter);
+        buf.put_u32(self.ect1_counter);
+        buf.put_u16(self.ecn_ce_counter);
+        buf.put_u16(self.not_ect_counter);
+        buf.put_u16(self.lost_packets);
+        buf.put_u16(self.duplication_counter);
+
+        if h.header.padding {
+            put_padding(buf, self.raw_size());
+        }
+
+        Ok(self.marshal_size())
+    }
+}
+
+impl Unmarshal for EcnFeedback {
+    /// Unmarshal decodes the EcnFeedback from binary
+    fn unmarshal<B>(raw_packet: &mut B) -> Result<Self>
+    where
+        Self: Sized,
+        B: Buf,
+    {
+        if raw_packet.remaining() < FEEDBACK_HEADER_LENGTH + ECN_FCI_LENGTH {
+            return Err(Error::PacketTooShort.into());
+        }
+
+        let h = FeedbackHeader::unmarshal::<Self, _>(raw_packet)?;
+
+        let extended_highest_seq = raw_packet.get_u32();
+        let ect0_counter = raw_packet.get_u32();
+        let ect1_counter = raw_packet.get_u32();
+        let ecn_ce_counter = raw_packet.get_u16();
+        let not_ect_counter = raw_packet.get_u16();
+        let lost_packets = raw_packet.get_u16();
+        let duplication_counter = raw_packet.get_u16();
+
+        if
+        /*h.header.padding &&*/
+        raw_packet.has_remaining() {
+            raw_packet.advance(raw_packet.remaining());
+        }
+
+        Ok(EcnFeedback {
+            sender_ssrc: h.sender_ssrc,
+            media_ssrc: h.media_ssrc,
+            extended_highest_seq,
+            ect0_counter,
+            ect1_counter,
+            ecn_ce_counter,
+            not_ect_counter,
+            lost_packets,
+            duplication_counter,
+        })
+    }
+}
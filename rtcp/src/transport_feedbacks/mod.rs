@@ -1,3 +1,5 @@
+pub mod ecn_feedback;
+pub mod rapid_acquisition;
 pub mod rapid_resynchronization_request;
 pub mod transport_layer_cc;
 pub mod transport_layer_nack;
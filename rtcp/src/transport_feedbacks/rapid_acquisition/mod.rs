@@ -0,0 +1,410 @@
+#[cfg(test)]
+mod rapid_acquisition_test;
+
+use std::any::Any;
+use std::fmt;
+
+use bytes::{Buf, BufMut, Bytes};
+use util::marshal::{Marshal, MarshalSize, Unmarshal};
+
+use crate::error::Error;
+use crate::feedback::*;
+use crate::header::*;
+use crate::packet::*;
+use crate::util::*;
+
+type Result<T> = std::result::Result<T, util::Error>;
+
+const RAMS_SFMT_LENGTH: usize = 4;
+const RAMS_TLV_HEADER_LENGTH: usize = 3;
+const RAMS_SFMT_OFFSET: usize = FEEDBACK_HEADER_LENGTH;
+/// The largest size of a RAMS message, whose length in 32-bit words minus one must fit the header
+const RAMS_MAX_LENGTH: usize = (u16::MAX as usize + 1) * 4;
+
+/// Sub-message type of a RAMS-R (request) message
+pub const SFMT_RAMS_REQUEST: u8 = 1;
+/// Sub-message type of a RAMS-I (information) message
+pub const SFMT_RAMS_INFORMATION: u8 = 2;
+
+/// RamsTlv is a type-length-value element carried by RAMS messages, as defined in RFC 6285
+///
+///  0                   1                   2                   3
+///  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |     Type      |           Length              |               :
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+               :
+/// :                             Value                             :
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+///
+/// Elements are kept as-is (including those of unknown type),
+/// leaving the interpretation of their values to the caller.
+#[derive(PartialEq, Eq, Default, Clone)]
+pub struct RamsTlv {
+    /// Type of the element
+    pub tlv_type: u8,
+    /// Value of the element
+    pub value: Bytes,
+}
+
+impl fmt::Debug for RamsTlv {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RamsTlv")
+            .field("tlv_type", &self.tlv_type)
+            .field("value", &HexBytes(&self.value))
+            .finish()
+    }
+}
+
+fn tlvs_size(tlvs: &[RamsTlv]) -> usize {
+    tlvs.iter()
+        .map(|tlv| RAMS_TLV_HEADER_LENGTH + tlv.value.len())
+        .sum()
+}
+
+/// Fails if a RAMS message of `raw_size` bytes, once padded, does not fit the header's length field
+fn check_rams_size(raw_size: usize) -> Result<()> {
+    if raw_size + get_padding_size(raw_size) > RAMS_MAX_LENGTH {
+        return Err(Error::RamsTooLong.into());
+    }
+    Ok(())
+}
+
+fn put_tlvs(mut buf: &mut [u8], tlvs: &[RamsTlv]) -> Result<()> {
+    for tlv in tlvs {
+        if tlv.value.len() > u16::MAX as usize {
+            return Err(Error::RamsTlvTooLong.into());
+        }
+        buf.put_u8(tlv.tlv_type);
+        buf.put_u16(tlv.value.len() as u16);
+        buf.put(tlv.value.clone());
+    }
+    Ok(())
+}
+
+fn parse_tlvs(mut data: Bytes) -> Result<Vec<RamsTlv>> {
+    let mut tlvs = vec![];
+    while data.has_remaining() {
+        if data.remaining() < RAMS_TLV_HEADER_LENGTH {
+            return Err(Error::PacketTooShort.into());
+        }
+        let tlv_type = data.get_u8();
+        let length = data.get_u16() as usize;
+        if data.remaining() < length {
+            return Err(Error::PacketTooShort.into());
+        }
+        tlvs.push(RamsTlv {
+            tlv_type,
+            value: data.copy_to_bytes(length),
+        });
+    }
+    Ok(tlvs)
+}
+
+/// Decodes the parts common to all RAMS messages, returning the feedback header,
+/// the 24 bits following the sub-message type and the TLV elements
+fn unmarshal_rams<F, B>(raw_packet: &mut B, sfmt: u8) -> Result<(FeedbackHeader, u32, Vec<RamsTlv>)>
+where
+    F: FeedbackMessage,
+    B: Buf,
+{
+    if raw_packet.remaining() < FEEDBACK_HEADER_LENGTH + RAMS_SFMT_LENGTH {
+        return Err(Error::PacketTooShort.into());
+    }
+
+    let h = FeedbackHeader::unmarshal::<F, _>(raw_packet)?;
+
+    let fci_len = h.fci_length();
+    if fci_len < RAMS_SFMT_LENGTH {
+        return Err(Error::PacketTooShort.into());
+    }
+
+    let word = raw_packet.get_u32();
+    if (word >> 24) as u8 != sfmt {
+        return Err(Error::WrongFeedbackType.into());
+    }
+
+    let mut tlvs_len = fci_len - RAMS_SFMT_LENGTH;
+    let mut data = raw_packet.copy_to_bytes(tlvs_len);
+    if h.header.padding {
+        let padding_len = data.last().copied().unwrap_or(0) as usize;
+        if padding_len == 0 || padding_len > tlvs_len {
+            return Err(Error::WrongPadding.into());
+        }
+        tlvs_len -= padding_len;
+        data.truncate(tlvs_len);
+    }
+
+    if raw_packet.has_remaining() {
+        raw_packet.advance(raw_packet.remaining());
+    }
+
+    Ok((h, word & 0x00ff_ffff, parse_tlvs(data)?))
+}
+
+/// Returns the sub-message type of the RAMS message in `raw_packet`, if it is long enough to carry one
+pub(crate) fn peek_rams_sfmt(raw_packet: &[u8]) -> Option<u8> {
+    raw_packet.get(RAMS_SFMT_OFFSET).copied()
+}
+
+/// The RapidAcquisitionRequest packet (RAMS-R) requests rapid acquisition of a multicast stream, as defined in RFC 6285
+///
+///  0                   1                   2                   3
+///  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |V=2|P| FMT=6   |   PT=205      |          length               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |                  SSRC of packet sender                        |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |                  SSRC of media source                         |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |     SFMT=1    |                    Reserved                   |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// :              Optional TLV-encoded Fields (and Padding, if needed)
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+#[derive(Debug, PartialEq, Eq, Default, Clone)]
+pub struct RapidAcquisitionRequest {
+    /// SSRC of sender
+    pub sender_ssrc: u32,
+    /// SSRC of the media source
+    pub media_ssrc: u32,
+    /// TLV-encoded fields
+    pub tlvs: Vec<RamsTlv>,
+}
+
+impl fmt::Display for RapidAcquisitionRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "RapidAcquisitionRequest {:x} {:x} tlvs={}",
+            self.sender_ssrc,
+            self.media_ssrc,
+            self.tlvs.len()
+        )
+    }
+}
+
+impl RapidAcquisitionRequest {
+    fn feedback_header(&self) -> FeedbackHeader {
+        FeedbackHeader::new::<Self>(self.sender_ssrc, self.media_ssrc, self.raw_size())
+    }
+}
+
+impl FeedbackMessage for RapidAcquisitionRequest {
+    const PACKET_TYPE: PacketType = PacketType::TransportSpecificFeedback;
+    const FORMAT: u8 = FORMAT_RAMS;
+}
+
+impl Packet for RapidAcquisitionRequest {
+    /// Header returns the Header associated with this packet.
+    fn header(&self) -> Header {
+        self.feedback_header().header
+    }
+
+    /// Destination SSRC returns an array of SSRC values that this packet refers to.
+    fn destination_ssrc(&self) -> Vec<u32> {
+        vec![self.media_ssrc]
+    }
+
+    fn raw_size(&self) -> usize {
+        FEEDBACK_HEADER_LENGTH + RAMS_SFMT_LENGTH + tlvs_size(&self.tlvs)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + Sync) {
+        self
+    }
+
+    fn equal(&self, other: &(dyn Packet + Send + Sync)) -> bool {
+        other.as_any().downcast_ref::<RapidAcquisitionRequest>() == Some(self)
+    }
+
+    fn cloned(&self) -> Box<dyn Packet + Send + Sync> {
+        Box::new(self.clone())
+    }
+}
+
+impl MarshalSize for RapidAcquisitionRequest {
+    fn marshal_size(&self) -> usize {
+        let l = self.raw_size();
+        // align to 32-bit boundary
+        l + get_padding_size(l)
+    }
+}
+
+impl Marshal for RapidAcquisitionRequest {
+    /// Marshal encodes the RapidAcquisitionRequest in binary
+    fn marshal_to(&self, mut buf: &mut [u8]) -> Result<usize> {
+        check_rams_size(self.raw_size())?;
+        if buf.remaining_mut() < self.marshal_size() {
+            return Err(Error::BufferTooShort.into());
+        }
+
+        let h = self.feedback_header();
+        let n = h.marshal_to(buf)?;
+        buf = &mut buf[n..];
+
+        buf.put_u32((SFMT_RAMS_REQUEST as u32) << 24);
+
+        put_tlvs(buf, &self.tlvs)?;
+        buf = &mut buf[tlvs_size(&self.tlvs)..];
+
+        if h.header.padding {
+            put_padding(buf, self.raw_size());
+        }
+
+        Ok(self.marshal_size())
+    }
+}
+
+impl Unmarshal for RapidAcquisitionRequest {
+    /// Unmarshal decodes the RapidAcquisitionRequest from binary
+    fn unmarshal<B>(raw_packet: &mut B) -> Result<Self>
+    where
+        Self: Sized,
+        B: Buf,
+    {
+        let (h, _reserved, tlvs) = unmarshal_rams::<Self, _>(raw_packet, SFMT_RAMS_REQUEST)?;
+
+        Ok(RapidAcquisitionRequest {
+            sender_ssrc: h.sender_ssrc,
+            media_ssrc: h.media_ssrc,
+            tlvs,
+        })
+    }
+}
+
+/// The RapidAcquisitionInformation packet (RAMS-I) informs about the rapid acquisition
+/// of a multicast stream in response to a RAMS-R, as defined in RFC 6285
+///
+///  0                   1                   2                   3
+///  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |V=2|P| FMT=6   |   PT=205      |          length               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |                  SSRC of packet sender                        |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |                  SSRC of media source                         |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |     SFMT=2    |      MSN      |          Response             |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// :              Optional TLV-encoded Fields (and Padding, if needed)
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+#[derive(Debug, PartialEq, Eq, Default, Clone)]
+pub struct RapidAcquisitionInformation {
+    /// SSRC of sender
+    pub sender_ssrc: u32,
+    /// SSRC of the media source
+    pub media_ssrc: u32,
+    /// Message sequence number, incremented with each updated RAMS-I
+    pub msn: u8,
+    /// Response code for the RAMS-R
+    pub response: u16,
+    /// TLV-encoded fields
+    pub tlvs: Vec<RamsTlv>,
+}
+
+impl fmt::Display for RapidAcquisitionInformation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "RapidAcquisitionInformation {:x} {:x} msn={} response={} tlvs={}",
+            self.sender_ssrc,
+            self.media_ssrc,
+            self.msn,
+            self.response,
+            self.tlvs.len()
+        )
+    }
+}
+
+impl RapidAcquisitionInformation {
+    fn feedback_header(&self) -> FeedbackHeader {
+        FeedbackHeader::new::<Self>(self.sender_ssrc, self.media_ssrc, self.raw_size())
+    }
+}
+
+impl FeedbackMessage for RapidAcquisitionInformation {
+    const PACKET_TYPE: PacketType = PacketType::TransportSpecificFeedback;
+    const FORMAT: u8 = FORMAT_RAMS;
+}
+
+impl Packet for RapidAcquisitionInformation {
+    /// Header returns the Header associated with this packet.
+    fn header(&self) -> Header {
+        self.feedback_header().header
+    }
+
+    /// Destination SSRC returns an array of SSRC values that this packet refers to.
+    fn destination_ssrc(&self) -> Vec<u32> {
+        vec![self.media_ssrc]
+    }
+
+    fn raw_size(&self) -> usize {
+        FEEDBACK_HEADER_LENGTH + RAMS_SFMT_LENGTH + tlvs_size(&self.tlvs)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + Sync) {
+        self
+    }
+
+    fn equal(&self, other: &(dyn Packet + Send + Sync)) -> bool {
+        other.as_any().downcast_ref::<RapidAcquisitionInformation>() == Some(self)
+    }
+
+    fn cloned(&self) -> Box<dyn Packet + Send + Sync> {
+        Box::new(self.clone())
+    }
+}
+
+impl MarshalSize for RapidAcquisitionInformation {
+    fn marshal_size(&self) -> usize {
+        let l = self.raw_size();
+        // align to 32-bit boundary
+        l + get_padding_size(l)
+    }
+}
+
+impl Marshal for RapidAcquisitionInformation {
+    /// Marshal encodes the RapidAcquisitionInformation in binary
+    fn marshal_to(&self, mut buf: &mut [u8]) -> Result<usize> {
+        check_rams_size(self.raw_size())?;
+        if buf.remaining_mut() < self.marshal_size() {
+            return Err(Error::BufferTooShort.into());
+        }
+
+        let h = self.feedback_header();
+        let n = h.marshal_to(buf)?;
+        buf = &mut buf[n..];
+
+        buf.put_u8(SFMT_RAMS_INFORMATION);
+        buf.put_u8(self.msn);
+        buf.put_u16(self.response);
+
+        put_tlvs(buf, &self.tlvs)?;
+        buf = &mut buf[tlvs_size(&self.tlvs)..];
+
+        if h.header.padding {
+            put_padding(buf, self.raw_size());
+        }
+
+        Ok(self.marshal_size())
+    }
+}
+
+impl Unmarshal for RapidAcquisitionInformation {
+    /// Unmarshal decodes the RapidAcquisitionInformation from binary
+    fn unmarshal<B>(raw_packet: &mut B) -> Result<Self>
+    where
+        Self: Sized,
+        B: Buf,
+    {
+        let (h, word, tlvs) = unmarshal_rams::<Self, _>(raw_packet, SFMT_RAMS_INFORMATION)?;
+
+        Ok(RapidAcquisitionInformation {
+            sender_ssrc: h.sender_ssrc,
+            media_ssrc: h.media_ssrc,
+            msn: (word >> 16) as u8,
+            response: word as u16,
+            tlvs,
+        })
+    }
+}
@@ -0,0 +1,251 @@
+use bytes::Bytes;
+
+use super::*;
+
+#[test]
+fn test_rapid_acquisition_request_unmarshal() {
+    let tests = vec![
+        (
+            "valid",
+            Bytes::from_static(&[
+                0xa6, 0xcd, 0x0, 0x5, // RapidAcquisitionRequest, p=1, len=5
+                0x90, 0x2f, 0x9e, 0x2e, // sender=0x902f9e2e
+                0x4b, 0xc4, 0xfc, 0xb4, // media=0x4bc4fcb4
+                0x01, 0x0, 0x0, 0x0, // SFMT=1
+                0x01, 0x0, 0x4, 0xc0, // type=1, len=4, value=0xc0a80001...
+                0xa8, 0x0, 0x01, 0x01, // ...value cont'd, padding=1
+            ]),
+            RapidAcquisitionRequest {
+                sender_ssrc: 0x902f9e2e,
+                media_ssrc: 0x4bc4fcb4,
+                tlvs: vec![RamsTlv {
+                    tlv_type: 1,
+                    value: Bytes::from_static(&[0xc0, 0xa8, 0x0, 0x01]),
+                }],
+            },
+            None,
+        ),
+        (
+            "unknown tlv",
+            Bytes::from_static(&[
+                0x86, 0xcd, 0x0, 0x4, // RapidAcquisitionRequest, len=4
+                0x90, 0x2f, 0x9e, 0x2e, // sender=0x902f9e2e
+                0x4b, 0xc4, 0xfc, 0xb4, // media=0x4bc4fcb4
+                0x01, 0x0, 0x0, 0x0, // SFMT=1
+                0xfe, 0x0, 0x1, 0x2a, // type=254, len=1, value=0x2a
+            ]),
+            RapidAcquisitionRequest {
+                sender_ssrc: 0x902f9e2e,
+                media_ssrc: 0x4bc4fcb4,
+                tlvs: vec![RamsTlv {
+                    tlv_type: 0xfe,
+                    value: Bytes::from_static(&[0x2a]),
+                }],
+            },
+            None,
+        ),
+        (
+            "truncated tlv",
+            Bytes::from_static(&[
+                0x86, 0xcd, 0x0, 0x4, // RapidAcquisitionRequest, len=4
+                0x90, 0x2f, 0x9e, 0x2e, // sender=0x902f9e2e
+                0x4b, 0xc4, 0xfc, 0xb4, // media=0x4bc4fcb4
+                0x01, 0x0, 0x0, 0x0, // SFMT=1
+                0x01, 0x0, 0x8, 0x2a, // type=1, len=8, value ends early
+            ]),
+            RapidAcquisitionRequest::default(),
+            Some(Error::PacketTooShort),
+        ),
+        (
+            "wrong sub-message type",
+            Bytes::from_static(&[
+                0x86, 0xcd, 0x0, 0x3, // RapidAcquisitionRequest, len=3
+                0x90, 0x2f, 0x9e, 0x2e, // sender=0x902f9e2e
+                0x4b, 0xc4, 0xfc, 0xb4, // media=0x4bc4fcb4
+                0x02, 0x0, 0x0, 0x0, // SFMT=2
+            ]),
+            RapidAcquisitionRequest::default(),
+            Some(Error::WrongFeedbackType),
+        ),
+        (
+            "wrong type",
+            Bytes::from_static(&[
+                0x85, 0xcd, 0x0, 0x3, // RapidResynchronizationRequest, len=3
+                0x90, 0x2f, 0x9e, 0x2e, // sender=0x902f9e2e
+                0x4b, 0xc4, 0xfc, 0xb4, // media=0x4bc4fcb4
+                0x01, 0x0, 0x0, 0x0,
+            ]),
+            RapidAcquisitionRequest::default(),
+            Some(Error::WrongType),
+        ),
+        (
+            "nil",
+            Bytes::from_static(&[]),
+            RapidAcquisitionRequest::default(),
+            Some(Error::PacketTooShort),
+        ),
+    ];
+
+    for (name, mut data, want, want_error) in tests {
+        let got = RapidAcquisitionRequest::unmarshal(&mut data);
+
+        assert_eq!(
+            got.is_err(),
+            want_error.is_some(),
+            "Unmarshal {name} rams-r: err = {got:?}, want {want_error:?}"
+        );
+
+        if let Some(err) = want_error {
+            let got_err = got.err().unwrap();
+            assert_eq!(
+                err, got_err,
+                "Unmarshal {name} rams-r: err = {got_err:?}, want {err:?}",
+            );
+        } else {
+            let actual = got.unwrap();
+            assert_eq!(
+                actual, want,
+                "Unmarshal {name} rams-r: got {actual:?}, want {want:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_rapid_acquisition_request_roundtrip() {
+    let tests: Vec<(&str, RapidAcquisitionRequest, Option<Error>)> = vec![
+        (
+            "no tlvs",
+            RapidAcquisitionRequest {
+                sender_ssrc: 0x902f9e2e,
+                media_ssrc: 0x4bc4fcb4,
+                tlvs: vec![],
+            },
+            None,
+        ),
+        (
+            "multiple tlvs",
+            RapidAcquisitionRequest {
+                sender_ssrc: 0x902f9e2e,
+                media_ssrc: 0x4bc4fcb4,
+                tlvs: vec![
+                    RamsTlv {
+                        tlv_type: 1,
+                        value: Bytes::from_static(&[0x0, 0x0, 0x1, 0xf4]),
+                    },
+                    RamsTlv {
+                        tlv_type: 0xfe,
+                        value: Bytes::from_static(&[0x2a, 0x2b, 0x2c]),
+                    },
+                ],
+            },
+            None,
+        ),
+        (
+            "tlv too long",
+            RapidAcquisitionRequest {
+                sender_ssrc: 0x902f9e2e,
+                media_ssrc: 0x4bc4fcb4,
+                tlvs: vec![RamsTlv {
+                    tlv_type: 1,
+                    value: Bytes::from(vec![0; 1 << 16]),
+                }],
+            },
+            Some(Error::RamsTlvTooLong),
+        ),
+        (
+            "message too long",
+            RapidAcquisitionRequest {
+                sender_ssrc: 0x902f9e2e,
+                media_ssrc: 0x4bc4fcb4,
+                tlvs: vec![
+                    RamsTlv {
+                        tlv_type: 1,
+                        value: Bytes::from(vec![0; 60000]),
+                    };
+                    5
+                ],
+            },
+            Some(Error::RamsTooLong),
+        ),
+    ];
+
+    for (name, want, want_error) in tests {
+        let got = want.marshal();
+
+        assert_eq!(
+            got.is_ok(),
+            want_error.is_none(),
+            "Marshal {name}: err = {got:?}, want {want_error:?}"
+        );
+
+        if let Some(err) = want_error {
+            let got_err = got.err().unwrap();
+            assert_eq!(
+                err, got_err,
+                "Unmarshal {name} rams-r: err = {got_err:?}, want {err:?}",
+            );
+        } else {
+            let mut data = got.ok().unwrap();
+            let actual = RapidAcquisitionRequest::unmarshal(&mut data)
+                .unwrap_or_else(|_| panic!("Unmarshal {name}"));
+
+            assert_eq!(
+                actual, want,
+                "{name} round trip: got {actual:?}, want {want:?}"
+            )
+        }
+    }
+}
+
+#[test]
+fn test_rapid_acquisition_information_roundtrip() {
+    let want = RapidAcquisitionInformation {
+        sender_ssrc: 0x902f9e2e,
+        media_ssrc: 0x4bc4fcb4,
+        msn: 3,
+        response: 1000,
+        tlvs: vec![RamsTlv {
+            tlv_type: 4,
+            value: Bytes::from_static(&[0x0, 0x0, 0x3, 0xe8]),
+        }],
+    };
+
+    let mut data = want.marshal().expect("Marshal rams-i");
+    assert_eq!(data[12..16], [0x02, 0x03, 0x03, 0xe8]);
+
+    let actual = RapidAcquisitionInformation::unmarshal(&mut data).expect("Unmarshal rams-i");
+
+    assert_eq!(actual, want, "round trip: got {actual:?}, want {want:?}");
+}
+
+#[test]
+fn test_rapid_acquisition_packet_unmarshal() {
+    let request = RapidAcquisitionRequest {
+        sender_ssrc: 0x902f9e2e,
+        media_ssrc: 0x4bc4fcb4,
+        ..Default::default()
+    };
+    let information = RapidAcquisitionInformation {
+        sender_ssrc: 0x4bc4fcb4,
+        media_ssrc: 0x902f9e2e,
+        ..Default::default()
+    };
+
+    let data = [request.marshal().unwrap(), information.marshal().unwrap()].concat();
+    let packets = unmarshal(&mut Bytes::from(data)).expect("Unmarshal packets");
+
+    assert_eq!(packets.len(), 2);
+    assert_eq!(
+        packets[0]
+            .as_any()
+            .downcast_ref::<RapidAcquisitionRequest>(),
+        Some(&request)
+    );
+    assert_eq!(
+        packets[1]
+            .as_any()
+            .downcast_ref::<RapidAcquisitionInformation>(),
+        Some(&information)
+    );
+}
@@ -575,6 +575,8 @@ impl Unmarshal for TransportLayerCc {
 
         let h = Header::unmarshal(raw_packet)?;
 
+        h.check_packet_size(raw_packet_len)?;
+
         // https://tools.ietf.org/html/rfc4585#page-33
         // header's length + payload's length
         let total_length = 4 * (h.length + 1) as usize;
@@ -3,6 +3,7 @@ mod transport_layer_nack_test;
 
 use std::any::Any;
 use std::fmt;
+use std::time::{Duration, Instant};
 
 use bytes::{Buf, BufMut};
 use util::marshal::{Marshal, MarshalSize, Unmarshal};
@@ -114,6 +115,54 @@ pub struct TransportLayerNack {
     pub nacks: Vec<NackPair>,
 }
 
+/// A RetransmissionRequest is a request for retransmitting a single lost RTP packet,
+/// as derived from a received TransportLayerNack.
+///
+/// It is not part of the wire format, but a local companion
+/// meant to be fed into a retransmission queue.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct RetransmissionRequest {
+    /// SSRC of the media source the lost packet belongs to
+    pub media_ssrc: u32,
+    /// Sequence number of the lost packet
+    pub sequence_number: u16,
+    /// Point in time after which retransmitting the packet is of no use,
+    /// as the receiver will have given up on it, or NACKed it once more
+    pub deadline: Instant,
+    /// Priority of the request, with lower values being more urgent.
+    /// Requests are prioritized in the order in which the packets were NACKed.
+    pub priority: usize,
+}
+
+impl TransportLayerNack {
+    /// normalize rewrites the NACK pairs so that each lost sequence number
+    /// is represented exactly once, using the fewest pairs possible.
+    ///
+    /// Duplicate sequence numbers, as well as those covered by overlapping pairs,
+    /// get merged. As a side-effect the pairs get ordered by sequence number
+    /// (taking wraparound into account), which also changes the order of `to_requests`.
+    pub fn normalize(&mut self) {
+        self.nacks = normalize_nack_pairs(&self.nacks);
+    }
+
+    /// Returns a retransmission request for each sequence number NACKed by this packet,
+    /// given that it has been received at `now`, and the round-trip time to the receiver is `rtt`.
+    pub fn to_requests(&self, now: Instant, rtt: Duration) -> Vec<RetransmissionRequest> {
+        let deadline = now + rtt;
+        self.nacks
+            .iter()
+            .flat_map(|nack| nack.into_iter())
+            .enumerate()
+            .map(|(priority, sequence_number)| RetransmissionRequest {
+                media_ssrc: self.media_ssrc,
+                sequence_number,
+                deadline,
+                priority,
+            })
+            .collect()
+    }
+}
+
 impl fmt::Display for TransportLayerNack {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut out = format!("TransportLayerNack from {:x}\n", self.sender_ssrc);
@@ -214,7 +263,8 @@ impl Unmarshal for TransportLayerNack {
 
         let h = Header::unmarshal(raw_packet)?;
 
-        if raw_packet_len < (HEADER_LENGTH + (4 * h.length) as usize) {
+        let length = h.length as usize * 4;
+        if raw_packet_len < HEADER_LENGTH + length || length < NACK_OFFSET {
             return Err(Error::PacketTooShort.into());
         }
 
@@ -226,7 +276,7 @@ impl Unmarshal for TransportLayerNack {
         let media_ssrc = raw_packet.get_u32();
 
         let mut nacks = vec![];
-        for _i in 0..(h.length as i32 - NACK_OFFSET as i32 / 4) {
+        for _i in 0..(length - NACK_OFFSET) / 4 {
             nacks.push(NackPair {
                 packet_id: raw_packet.get_u16(),
                 lost_packets: raw_packet.get_u16(),
@@ -273,3 +323,48 @@ pub fn nack_pairs_from_sequence_numbers(seq_nos: &[u16]) -> Vec<NackPair> {
 
     pairs
 }
+
+/// normalize_nack_pairs returns the fewest NACK pairs covering every sequence number
+/// covered by `nacks`, each exactly once, ordered by sequence number.
+///
+/// Sequence numbers are ordered taking wraparound into account,
+/// i.e. assuming that the largest gap between lost sequence numbers is the one wrapping around.
+pub fn normalize_nack_pairs(nacks: &[NackPair]) -> Vec<NackPair> {
+    let mut seq_nos: Vec<u16> = nacks.iter().flat_map(|nack| nack.into_iter()).collect();
+    seq_nos.sort_unstable();
+    seq_nos.dedup();
+
+    if seq_nos.is_empty() {
+        return vec![];
+    }
+
+    // Start right after the largest gap, which is the wraparound from the last to the first
+    // sequence number, unless there is a larger gap in between (e.g. from 65535 around to 0):
+    let mut start = 0;
+    let mut largest_gap = seq_nos[0].wrapping_sub(seq_nos[seq_nos.len() - 1]);
+    for i in 1..seq_nos.len() {
+        let gap = seq_nos[i] - seq_nos[i - 1];
+        if gap > largest_gap {
+            largest_gap = gap;
+            start = i;
+        }
+    }
+    seq_nos.rotate_left(start);
+
+    // Packing greedily yields the fewest pairs, as each pair covers a fixed window of 17:
+    let mut pairs = vec![];
+    let mut nack_pair = NackPair::new(seq_nos[0]);
+    for &seq in seq_nos.iter().skip(1) {
+        let offset = seq.wrapping_sub(nack_pair.packet_id);
+        if offset > 16 {
+            pairs.push(nack_pair);
+            nack_pair = NackPair::new(seq);
+            continue;
+        }
+
+        nack_pair.lost_packets |= 1 << (offset - 1);
+    }
+    pairs.push(nack_pair);
+
+    pairs
+}
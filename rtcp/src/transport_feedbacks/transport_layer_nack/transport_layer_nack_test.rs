@@ -359,3 +359,105 @@ fn test_lost_packets_is_reset_when_crossing_16_bit_boundary() {
         ],
     )
 }
+
+#[test]
+fn test_transport_layer_nack_to_requests() {
+    let nack = TransportLayerNack {
+        sender_ssrc: 0x902f9e2e,
+        media_ssrc: 0x4bc4fcb4,
+        nacks: vec![
+            NackPair {
+                packet_id: 42,
+                lost_packets: 0b0000_0000_0000_0101,
+            },
+            NackPair {
+                packet_id: 65535,
+                lost_packets: 0b0000_0000_0000_0001,
+            },
+        ],
+    };
+
+    let now = Instant::now();
+    let rtt = Duration::from_millis(120);
+
+    let requests = nack.to_requests(now, rtt);
+
+    let sequence_numbers: Vec<u16> = requests.iter().map(|r| r.sequence_number).collect();
+    assert_eq!(sequence_numbers, vec![42, 43, 45, 65535, 0]);
+
+    for (i, request) in requests.iter().enumerate() {
+        assert_eq!(request.media_ssrc, 0x4bc4fcb4);
+        assert_eq!(request.deadline, now + rtt);
+        assert_eq!(request.priority, i);
+    }
+
+    assert!(TransportLayerNack::default()
+        .to_requests(now, rtt)
+        .is_empty());
+}
+
+#[test]
+fn test_transport_layer_nack_normalize() {
+    let mut nack = TransportLayerNack {
+        sender_ssrc: 0x902f9e2e,
+        media_ssrc: 0x4bc4fcb4,
+        nacks: vec![
+            // 100, 101 and 103
+            NackPair {
+                packet_id: 100,
+                lost_packets: 0b0000_0000_0000_0101,
+            },
+            // Duplicate of 103
+            NackPair::new(103),
+            // 102, overlapping with the first pair's window
+            NackPair {
+                packet_id: 101,
+                lost_packets: 0b0000_0000_0000_0001,
+            },
+            // 116, still within the first pair's window, and 117
+            NackPair {
+                packet_id: 116,
+                lost_packets: 0b0000_0000_0000_0001,
+            },
+            // 65535 and 0, wrapping around
+            NackPair {
+                packet_id: 65535,
+                lost_packets: 0b0000_0000_0000_0001,
+            },
+            // Duplicate of 0
+            NackPair::new(0),
+        ],
+    };
+    let lost: Vec<u16> = {
+        let mut lost: Vec<u16> = nack.nacks.iter().flat_map(|n| n.packet_list()).collect();
+        lost.sort_unstable();
+        lost.dedup();
+        lost
+    };
+
+    nack.normalize();
+
+    assert_eq!(
+        nack.nacks,
+        vec![
+            NackPair {
+                packet_id: 65535,
+                lost_packets: 0b0000_0000_0000_0001,
+            },
+            NackPair {
+                packet_id: 100,
+                lost_packets: 0b1000_0000_0000_0111,
+            },
+            NackPair::new(117),
+        ]
+    );
+
+    let mut normalized: Vec<u16> = nack.nacks.iter().flat_map(|n| n.packet_list()).collect();
+    let count = normalized.len();
+    normalized.sort_unstable();
+    normalized.dedup();
+    assert_eq!(count, normalized.len(), "sequence numbers must not repeat");
+    assert_eq!(normalized, lost);
+
+    assert!(normalize_nack_pairs(&[]).is_empty());
+}
@@ -1,3 +1,5 @@
+use std::fmt;
+
 use bytes::BufMut;
 
 use crate::error::{Error, Result};
@@ -56,10 +58,47 @@ pub(crate) fn get_24bits_from_bytes(b: &[u8]) -> u32 {
     ((b[0] as u32) << 16) + ((b[1] as u32) << 8) + (b[2] as u32)
 }
 
+/// HEX_BYTES_MAX is the number of leading bytes printed by `HexBytes`.
+const HEX_BYTES_MAX: usize = 8;
+
+/// HexBytes formats binary data for `Debug` output as its length and its leading bytes in hex,
+/// e.g. `12 bytes [de ad be ef 00 01 02 03 …]`, rather than as a (potentially long) byte string.
+pub(crate) struct HexBytes<'a>(pub(crate) &'a [u8]);
+
+impl fmt::Debug for HexBytes<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} bytes [", self.0.len())?;
+        for (i, byte) in self.0.iter().take(HEX_BYTES_MAX).enumerate() {
+            let separator = if i == 0 { "" } else { " " };
+            write!(f, "{separator}{byte:02x}")?;
+        }
+        if self.0.len() > HEX_BYTES_MAX {
+            write!(f, " …")?;
+        }
+        write!(f, "]")
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn test_hex_bytes() {
+        assert_eq!(format!("{:?}", HexBytes(&[])), "0 bytes []");
+        assert_eq!(
+            format!("{:?}", HexBytes(&[0xde, 0xad, 0xbe, 0xef])),
+            "4 bytes [de ad be ef]"
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                HexBytes(&[0xde, 0xad, 0xbe, 0xef, 0, 1, 2, 3, 4, 5, 6, 7])
+            ),
+            "12 bytes [de ad be ef 00 01 02 03 …]"
+        );
+    }
+
     #[test]
     fn test_get_padding() -> Result<()> {
         let tests = vec![(0, 0), (1, 3), (2, 2), (3, 1), (4, 0), (100, 0), (500, 0)];
@@ -0,0 +1,210 @@
+use crate::compound_packet::CompoundPacket;
+use crate::extended_report::ExtendedReport;
+use crate::goodbye::Goodbye;
+use crate::packet::Packet;
+use crate::payload_feedbacks::full_intra_request::FullIntraRequest;
+use crate::payload_feedbacks::picture_loss_indication::PictureLossIndication;
+use crate::payload_feedbacks::receiver_estimated_maximum_bitrate::ReceiverEstimatedMaximumBitrate;
+use crate::payload_feedbacks::slice_loss_indication::SliceLossIndication;
+use crate::raw_packet::RawPacket;
+use crate::receiver_report::ReceiverReport;
+use crate::sender_report::SenderReport;
+use crate::source_description::SourceDescription;
+use crate::transport_feedbacks::ecn_feedback::EcnFeedback;
+use crate::transport_feedbacks::rapid_acquisition::{
+    RapidAcquisitionInformation, RapidAcquisitionRequest,
+};
+use crate::transport_feedbacks::rapid_resynchronization_request::RapidResynchronizationRequest;
+use crate::transport_feedbacks::transport_layer_cc::TransportLayerCc;
+use crate::transport_feedbacks::transport_layer_nack::TransportLayerNack;
+
+/// PacketVisitor is visited by packets of the concrete type they are,
+/// which saves consumers of mixed packets from downcasting them via `Packet::as_any`.
+///
+/// All methods do nothing by default, so visitors only need to implement
+/// those for the packet types they are interested in.
+#[allow(unused_variables)]
+pub trait PacketVisitor {
+    fn visit_sender_report(&mut self, packet: &SenderReport) {}
+    fn visit_receiver_report(&mut self, packet: &ReceiverReport) {}
+    fn visit_source_description(&mut self, packet: &SourceDescription) {}
+    fn visit_goodbye(&mut self, packet: &Goodbye) {}
+    fn visit_extended_report(&mut self, packet: &ExtendedReport) {}
+    fn visit_picture_loss_indication(&mut self, packet: &PictureLossIndication) {}
+    fn visit_slice_loss_indication(&mut self, packet: &SliceLossIndication) {}
+    fn visit_full_intra_request(&mut self, packet: &FullIntraRequest) {}
+    fn visit_receiver_estimated_maximum_bitrate(
+        &mut self,
+        packet: &ReceiverEstimatedMaximumBitrate,
+    ) {
+    }
+    fn visit_transport_layer_nack(&mut self, packet: &TransportLayerNack) {}
+    fn visit_transport_layer_cc(&mut self, packet: &TransportLayerCc) {}
+    fn visit_rapid_resynchronization_request(&mut self, packet: &RapidResynchronizationRequest) {}
+    fn visit_ecn_feedback(&mut self, packet: &EcnFeedback) {}
+    fn visit_rapid_acquisition_request(&mut self, packet: &RapidAcquisitionRequest) {}
+    fn visit_rapid_acquisition_information(&mut self, packet: &RapidAcquisitionInformation) {}
+    fn visit_raw_packet(&mut self, packet: &RawPacket) {}
+    /// visit_compound_packet is visited before the packets contained in the compound packet.
+    fn visit_compound_packet(&mut self, packet: &CompoundPacket) {}
+    /// visit_other is visited by packets of any other type,
+    /// e.g. packet types implemented outside of this crate.
+    fn visit_other(&mut self, packet: &(dyn Packet + Send + Sync)) {}
+}
+
+impl dyn Packet + Send + Sync {
+    /// accept calls the method of `visitor` corresponding to the concrete type of this packet.
+    ///
+    /// For a CompoundPacket, the visitor subsequently gets accepted by each contained packet.
+    pub fn accept(&self, visitor: &mut dyn PacketVisitor) {
+        let any = self.as_any();
+
+        if let Some(packet) = any.downcast_ref::<SenderReport>() {
+            visitor.visit_sender_report(packet);
+        } else if let Some(packet) = any.downcast_ref::<ReceiverReport>() {
+            visitor.visit_receiver_report(packet);
+        } else if let Some(packet) = any.downcast_ref::<SourceDescription>() {
+            visitor.visit_source_description(packet);
+        } else if let Some(packet) = any.downcast_ref::<Goodbye>() {
+            visitor.visit_goodbye(packet);
+        } else if let Some(packet) = any.downcast_ref::<ExtendedReport>() {
+            visitor.visit_extended_report(packet);
+        } else if let Some(packet) = any.downcast_ref::<PictureLossIndication>() {
+            visitor.visit_picture_loss_indication(packet);
+        } else if let Some(packet) = any.downcast_ref::<SliceLossIndication>() {
+            visitor.visit_slice_loss_indication(packet);
+        } else if let Some(packet) = any.downcast_ref::<FullIntraRequest>() {
+            visitor.visit_full_intra_request(packet);
+        } else if let Some(packet) = any.downcast_ref::<ReceiverEstimatedMaximumBitrate>() {
+            visitor.visit_receiver_estimated_maximum_bitrate(packet);
+        } else if let Some(packet) = any.downcast_ref::<TransportLayerNack>() {
+            visitor.visit_transport_layer_nack(packet);
+        } else if let Some(packet) = any.downcast_ref::<TransportLayerCc>() {
+            visitor.visit_transport_layer_cc(packet);
+        } else if let Some(packet) = any.downcast_ref::<RapidResynchronizationRequest>() {
+            visitor.visit_rapid_resynchronization_request(packet);
+        } else if let Some(packet) = any.downcast_ref::<EcnFeedback>() {
+            visitor.visit_ecn_feedback(packet);
+        } else if let Some(packet) = any.downcast_ref::<RapidAcquisitionRequest>() {
+            visitor.visit_rapid_acquisition_request(packet);
+        } else if let Some(packet) = any.downcast_ref::<RapidAcquisitionInformation>() {
+            visitor.visit_rapid_acquisition_information(packet);
+        } else if let Some(packet) = any.downcast_ref::<RawPacket>() {
+            visitor.visit_raw_packet(packet);
+        } else if let Some(packet) = any.downcast_ref::<CompoundPacket>() {
+            visitor.visit_compound_packet(packet);
+            for packet in &packet.0 {
+                packet.accept(visitor);
+            }
+        } else {
+            visitor.visit_other(self);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use bytes::Bytes;
+
+    use super::*;
+    use crate::reception_report::ReceptionReport;
+    use crate::source_description::*;
+
+    #[derive(Default)]
+    struct PacketCounter(HashMap<&'static str, usize>);
+
+    impl PacketCounter {
+        fn count(&mut self, name: &'static str) {
+            *self.0.entry(name).or_default() += 1;
+        }
+    }
+
+    impl PacketVisitor for PacketCounter {
+        fn visit_sender_report(&mut self, _: &SenderReport) {
+            self.count("SR");
+        }
+        fn visit_receiver_report(&mut self, _: &ReceiverReport) {
+            self.count("RR");
+        }
+        fn visit_source_description(&mut self, _: &SourceDescription) {
+            self.count("SDES");
+        }
+        fn visit_goodbye(&mut self, _: &Goodbye) {
+            self.count("BYE");
+        }
+        fn visit_picture_loss_indication(&mut self, _: &PictureLossIndication) {
+            self.count("PLI");
+        }
+        fn visit_transport_layer_nack(&mut self, _: &TransportLayerNack) {
+            self.count("NACK");
+        }
+        fn visit_compound_packet(&mut self, _: &CompoundPacket) {
+            self.count("compound");
+        }
+        fn visit_other(&mut self, _: &(dyn Packet + Send + Sync)) {
+            self.count("other");
+        }
+    }
+
+    #[test]
+    fn test_packet_visitor_counts_compound() {
+        let compound: Box<dyn Packet + Send + Sync> = Box::new(CompoundPacket(vec![
+            Box::<ReceiverReport>::default(),
+            Box::new(SourceDescription {
+                chunks: vec![SourceDescriptionChunk {
+                    source: 1234,
+                    items: vec![SourceDescriptionItem {
+                        sdes_type: SdesType::SdesCname,
+                        text: Bytes::from_static(b"cname"),
+                    }],
+                }],
+            }),
+            Box::<PictureLossIndication>::default(),
+            Box::<TransportLayerNack>::default(),
+            Box::<PictureLossIndication>::default(),
+            Box::<Goodbye>::default(),
+        ]));
+
+        let mut counter = PacketCounter::default();
+        compound.accept(&mut counter);
+
+        let expected = HashMap::from([
+            ("compound", 1),
+            ("RR", 1),
+            ("SDES", 1),
+            ("PLI", 2),
+            ("NACK", 1),
+            ("BYE", 1),
+        ]);
+        assert_eq!(counter.0, expected);
+    }
+
+    #[test]
+    fn test_packet_visitor_unmarshaled() -> Result<(), util::Error> {
+        let mut data = Bytes::from_static(&[
+            // Picture Loss Indication
+            0x81, 0xce, 0x00, 0x02, // v=2, p=0, FMT=1, PSFB, len=2
+            0x90, 0x2f, 0x9e, 0x2e, // sender=0x902f9e2e
+            0x90, 0x2f, 0x9e, 0x2e, // media=0x902f9e2e
+            // Goodbye
+            0x81, 0xcb, 0x00, 0x01, // v=2, p=0, count=1, BYE, len=1
+            0x90, 0x2f, 0x9e, 0x2e, // source=0x902f9e2e
+        ]);
+        let packets = crate::packet::unmarshal(&mut data)?;
+
+        let mut counter = PacketCounter::default();
+        for packet in &packets {
+            packet.accept(&mut counter);
+        }
+        // Reception reports implement Packet, but have no dedicated visitor method:
+        let report: Box<dyn Packet + Send + Sync> = Box::<ReceptionReport>::default();
+        report.accept(&mut counter);
+
+        let expected = HashMap::from([("PLI", 1), ("BYE", 1), ("other", 1)]);
+        assert_eq!(counter.0, expected);
+
+        Ok(())
+    }
+}